@@ -40,6 +40,13 @@ pub enum AppEvent {
     GeocodeResolved(GeocodeResolution),
     FetchSucceeded(ForecastBundle),
     FetchFailed(String),
+    /// A background forecast for a dashboard card, a location tab or the
+    /// comparison view; never changes the main view.
+    DashboardFetched(ForecastBundle),
+    DashboardFetchFailed(Location, String),
+    /// The geocoded second location for the comparison view.
+    CompareResolved(GeocodeResolution),
+    CompareLookupFailed(String),
     UpdateCheckFinished(UpdateStatus),
     Demo(DemoAction),
     Quit,
@@ -0,0 +1,148 @@
+#![allow(clippy::missing_errors_doc)]
+
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::app::settings::write_private_file;
+use crate::domain::weather::{ForecastBundle, Location};
+
+const STORE_DIR_NAME: &str = "forecast-cache";
+const STORE_VERSION: u32 = 1;
+const MAX_ENTRY_BYTES: u64 = 4 * 1024 * 1024;
+const MAX_ENTRIES: usize = 24;
+const MAX_ENTRY_AGE_DAYS: i64 = 7;
+
+/// On-disk forecast cache stored beside `settings.json`, one JSON file per location.
+///
+/// Entries outlive the process so the last known forecast can be shown immediately on
+/// the next launch while a fresh fetch runs in the background.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForecastStore {
+    dir: PathBuf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredEntry {
+    version: u32,
+    bundle: ForecastBundle,
+}
+
+impl ForecastStore {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The store that lives next to the given `settings.json` path.
+    #[must_use]
+    pub fn beside_settings(settings_path: &Path) -> Option<Self> {
+        settings_path
+            .parent()
+            .map(|parent| Self::new(parent.join(STORE_DIR_NAME)))
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Loads the cached bundle for `location`, dropping entries that are unreadable,
+    /// from an older format, or older than a week.
+    #[must_use]
+    pub fn load(&self, location: &Location) -> Option<ForecastBundle> {
+        self.load_at(location, Utc::now())
+    }
+
+    /// Like [`Self::load`] but without the age limit, for `--offline` where any
+    /// cached data beats none.
+    #[must_use]
+    pub fn load_any_age(&self, location: &Location) -> Option<ForecastBundle> {
+        self.load_entry(location).map(|entry| entry.bundle)
+    }
+
+    fn load_at(&self, location: &Location, now: DateTime<Utc>) -> Option<ForecastBundle> {
+        let entry = self.load_entry(location)?;
+        let age = now - entry.bundle.fetched_at;
+        (age <= Duration::days(MAX_ENTRY_AGE_DAYS)).then_some(entry.bundle)
+    }
+
+    fn load_entry(&self, location: &Location) -> Option<StoredEntry> {
+        read_entry(&self.entry_path(location)).filter(|entry| entry.version == STORE_VERSION)
+    }
+
+    pub fn save(&self, bundle: &ForecastBundle) -> anyhow::Result<()> {
+        let entry = StoredEntry {
+            version: STORE_VERSION,
+            bundle: bundle.clone(),
+        };
+        let payload = serde_json::to_vec(&entry).context("serializing forecast cache failed")?;
+        write_private_file(
+            &self.entry_path(&bundle.location),
+            &payload,
+            "forecast cache",
+        )?;
+        self.prune();
+        Ok(())
+    }
+
+    fn entry_path(&self, location: &Location) -> PathBuf {
+        self.dir.join(entry_file_name(location))
+    }
+
+    /// Keeps the newest [`MAX_ENTRIES`] files so the cache cannot grow without bound.
+    fn prune(&self) {
+        let Ok(read_dir) = fs::read_dir(&self.dir) else {
+            return;
+        };
+        let mut entries = read_dir
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+            .collect::<Vec<_>>();
+        if entries.len() <= MAX_ENTRIES {
+            return;
+        }
+        entries.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+        for (_, path) in entries.into_iter().skip(MAX_ENTRIES) {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn entry_file_name(location: &Location) -> String {
+    format!("{:.4}_{:.4}.json", location.latitude, location.longitude)
+}
+
+fn read_entry(path: &Path) -> Option<StoredEntry> {
+    let file = fs::File::open(path).ok()?;
+    let mut content = Vec::new();
+    file.take(MAX_ENTRY_BYTES).read_to_end(&mut content).ok()?;
+    serde_json::from_slice(&content).ok()
+}
+
+/// Drops hourly and daily rows that have already passed since `fetched_at`, so a
+/// restored bundle starts at the current hour like a freshly fetched one.
+#[must_use]
+pub fn rebase_to_now(mut bundle: ForecastBundle, now: DateTime<Utc>) -> ForecastBundle {
+    let elapsed_hours = usize::try_from((now - bundle.fetched_at).num_hours()).unwrap_or(0);
+    if elapsed_hours == 0 || bundle.hourly.is_empty() {
+        return bundle;
+    }
+    let drop = elapsed_hours.min(bundle.hourly.len() - 1);
+    bundle.hourly.drain(..drop);
+    let today = bundle.hourly[0].time.date();
+    if bundle.daily.iter().any(|day| day.date == today) {
+        bundle.daily.retain(|day| day.date >= today);
+    }
+    bundle
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,91 @@
+use super::*;
+use crate::test_support::{sample_bundle, sample_daily, sample_hourly, stockholm_location};
+
+fn hourly_bundle(hours: i64) -> ForecastBundle {
+    let mut bundle = sample_bundle();
+    let start = sample_hourly().time;
+    bundle.hourly = (0..hours)
+        .map(|offset| {
+            let mut hour = sample_hourly();
+            hour.time = start + Duration::hours(offset);
+            hour
+        })
+        .collect();
+    let mut tomorrow = sample_daily();
+    tomorrow.date = tomorrow.date.succ_opt().expect("next day");
+    bundle.daily = vec![sample_daily(), tomorrow];
+    bundle
+}
+
+#[test]
+fn save_then_load_roundtrips_bundle() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let store = ForecastStore::new(dir.path().join(STORE_DIR_NAME));
+    let bundle = sample_bundle();
+
+    store.save(&bundle).expect("save bundle");
+    let restored = store.load(&stockholm_location()).expect("restored bundle");
+
+    assert_eq!(restored.fetched_at, bundle.fetched_at);
+    assert_eq!(restored.location.name, "Stockholm");
+    assert_eq!(restored.hourly.len(), bundle.hourly.len());
+}
+
+#[test]
+fn load_ignores_missing_expired_and_foreign_entries() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let store = ForecastStore::new(dir.path());
+    assert!(store.load(&stockholm_location()).is_none());
+
+    let bundle = sample_bundle();
+    store.save(&bundle).expect("save bundle");
+    let later = bundle.fetched_at + Duration::days(MAX_ENTRY_AGE_DAYS + 1);
+    assert!(store.load_at(&stockholm_location(), later).is_none());
+
+    let path = store.entry_path(&stockholm_location());
+    std::fs::write(&path, r#"{"version":0,"bundle":null}"#).expect("overwrite entry");
+    assert!(store.load(&stockholm_location()).is_none());
+}
+
+#[test]
+fn save_prunes_oldest_entries() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let store = ForecastStore::new(dir.path());
+    let mut bundle = sample_bundle();
+    for idx in 0..=MAX_ENTRIES {
+        bundle.location.latitude = f64::from(u32::try_from(idx).expect("small index"));
+        store.save(&bundle).expect("save bundle");
+    }
+
+    let count = std::fs::read_dir(dir.path()).expect("read dir").count();
+    assert_eq!(count, MAX_ENTRIES);
+}
+
+#[test]
+fn store_lives_beside_settings_file() {
+    let store =
+        ForecastStore::beside_settings(Path::new("/tmp/tw/settings.json")).expect("store path");
+    assert_eq!(store.dir(), Path::new("/tmp/tw/forecast-cache"));
+}
+
+#[test]
+fn rebase_drops_elapsed_hours_and_past_days() {
+    let bundle = hourly_bundle(30);
+    let now = bundle.fetched_at + Duration::hours(25);
+
+    let rebased = rebase_to_now(bundle, now);
+
+    assert_eq!(rebased.hourly.len(), 5);
+    assert_eq!(rebased.daily.len(), 1);
+    assert_eq!(rebased.daily[0].date, rebased.hourly[0].time.date());
+}
+
+#[test]
+fn rebase_keeps_last_hour_when_everything_elapsed() {
+    let bundle = hourly_bundle(3);
+    let now = bundle.fetched_at + Duration::hours(10);
+
+    let rebased = rebase_to_now(bundle, now);
+
+    assert_eq!(rebased.hourly.len(), 1);
+}
@@ -1,3 +1,5 @@
 pub mod events;
+pub mod forecast_store;
+pub mod offline;
 pub mod settings;
 pub mod state;
@@ -0,0 +1,169 @@
+#![allow(clippy::missing_errors_doc)]
+
+use anyhow::Result;
+
+use crate::app::{
+    forecast_store::ForecastStore,
+    settings::{RecentLocation, RuntimeSettings},
+};
+use crate::cli::Cli;
+use crate::domain::weather::{ForecastBundle, Location};
+
+const COORD_MATCH_EPSILON: f64 = 1e-4;
+
+/// Resolves the location for `--offline` without geocoding: coordinates map to a
+/// matching recent or pinned entry (or a bare coordinate location), a city name
+/// must match a pinned or recent location, and with neither the most recent
+/// location is used.
+#[must_use]
+pub fn resolve_offline_location(cli: &Cli, settings: &RuntimeSettings) -> Option<Location> {
+    if let (Some(lat), Some(lon)) = (cli.lat, cli.lon) {
+        return Some(
+            settings
+                .recent_locations
+                .iter()
+                .chain(settings.pinned_locations.iter().map(|pinned| &pinned.place))
+                .find(|recent| same_coords(recent, lat, lon))
+                .map_or_else(
+                    || Location::from_coords(lat, lon),
+                    RecentLocation::to_location,
+                ),
+        );
+    }
+    match cli.city.as_deref() {
+        Some(city) => find_saved_location(settings, city),
+        None => settings
+            .recent_locations
+            .first()
+            .map(RecentLocation::to_location),
+    }
+}
+
+/// Finds a pinned location by alias or name, or failing that a recent location
+/// whose name or display name matches `query`, ignoring case.
+#[must_use]
+pub fn find_saved_location(settings: &RuntimeSettings, query: &str) -> Option<Location> {
+    if let Some(pinned) = settings.find_pinned(query) {
+        return Some(pinned.place.to_location());
+    }
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    settings
+        .recent_locations
+        .iter()
+        .find(|recent| {
+            recent.name.to_lowercase() == query
+                || recent.display_name().to_lowercase().starts_with(&query)
+        })
+        .map(RecentLocation::to_location)
+}
+
+/// Loads the persisted forecast for `location` regardless of its age.
+pub fn load_offline_forecast(
+    store: Option<&ForecastStore>,
+    location: &Location,
+) -> Result<ForecastBundle> {
+    store
+        .and_then(|store| store.load_any_age(location))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No cached forecast for {} (offline mode)",
+                location.display_name()
+            )
+        })
+}
+
+/// Status text for when `--offline` has no location to show.
+#[must_use]
+pub fn missing_location_message(cli: &Cli) -> String {
+    cli.city.as_deref().map_or_else(
+        || "Offline mode: no recent locations to show".to_string(),
+        |city| format!("Offline mode: no pinned or recent location matches '{city}'"),
+    )
+}
+
+fn same_coords(recent: &RecentLocation, lat: f64, lon: f64) -> bool {
+    (recent.latitude - lat).abs() < COORD_MATCH_EPSILON
+        && (recent.longitude - lon).abs() < COORD_MATCH_EPSILON
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{sample_bundle, state_test_cli, stockholm_location};
+
+    fn settings_with_recents() -> RuntimeSettings {
+        let mut settings = RuntimeSettings::default();
+        settings
+            .recent_locations
+            .push(RecentLocation::from_location(&stockholm_location()));
+        settings
+            .recent_locations
+            .push(RecentLocation::from_location(&Location::from_coords(
+                48.8566, 2.3522,
+            )));
+        settings
+    }
+
+    #[test]
+    fn resolves_most_recent_location_without_flags() {
+        let location = resolve_offline_location(&state_test_cli(), &settings_with_recents())
+            .expect("recent location");
+        assert_eq!(location.name, "Stockholm");
+    }
+
+    #[test]
+    fn resolves_city_only_from_recents() {
+        let mut cli = state_test_cli();
+        cli.city = Some("stockholm".to_string());
+        let settings = settings_with_recents();
+        assert!(resolve_offline_location(&cli, &settings).is_some());
+
+        cli.city = Some("Berlin".to_string());
+        assert!(resolve_offline_location(&cli, &settings).is_none());
+        assert!(missing_location_message(&cli).contains("'Berlin'"));
+    }
+
+    #[test]
+    fn resolves_city_from_pinned_alias_without_recents() {
+        let mut settings = RuntimeSettings::default();
+        settings.pin_location(&stockholm_location(), Some("Home".to_string()));
+        let mut cli = state_test_cli();
+        cli.city = Some("home".to_string());
+
+        let location = resolve_offline_location(&cli, &settings).expect("pinned location");
+        assert_eq!(location.name, "Stockholm");
+    }
+
+    #[test]
+    fn resolves_coordinates_to_matching_recent_or_raw_coords() {
+        let mut cli = state_test_cli();
+        cli.lat = Some(59.3293);
+        cli.lon = Some(18.0686);
+        let settings = settings_with_recents();
+        let location = resolve_offline_location(&cli, &settings).expect("coords location");
+        assert_eq!(location.name, "Stockholm");
+
+        cli.lat = Some(10.0);
+        cli.lon = Some(10.0);
+        let location = resolve_offline_location(&cli, &settings).expect("coords location");
+        assert_eq!(location.name, "10.0000, 10.0000");
+    }
+
+    #[test]
+    fn load_offline_forecast_reports_missing_cache() {
+        let err = load_offline_forecast(None, &stockholm_location()).expect_err("no store");
+        assert!(err.to_string().contains("No cached forecast"));
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let store = ForecastStore::new(dir.path());
+        let mut bundle = sample_bundle();
+        bundle.fetched_at -= chrono::Duration::days(30);
+        store.save(&bundle).expect("save bundle");
+        let loaded =
+            load_offline_forecast(Some(&store), &stockholm_location()).expect("cached bundle");
+        assert_eq!(loaded.fetched_at, bundle.fetched_at);
+    }
+}
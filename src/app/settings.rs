@@ -10,15 +10,34 @@ use anyhow::Context;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    cli::{Cli, HeroVisualArg, HourlyViewArg, IconMode, ThemeArg, UnitsArg},
-    domain::weather::{HourlyViewMode, Location, Units},
+    cli::{Cli, HeroVisualArg, HourlyViewArg, IconMode, ProviderArg, ThemeArg, UnitsArg},
+    data::forecast::{DEFAULT_FORECAST_DAYS, MAX_FORECAST_DAYS},
+    domain::alerts::AlertRule,
+    domain::weather::{
+        DistanceUnit, HourlyViewMode, Location, MeasureUnits, PrecipUnit, PressureUnit, Units,
+        WindUnit,
+    },
     ui::animation::MotionMode,
 };
 
+/// How many recent locations are kept, most recent first.
+pub const RECENT_LOCATIONS_MAX: usize = 12;
+
+/// How many locations can be pinned to the dashboard, one per number key.
+pub const PINNED_LOCATIONS_MAX: usize = 9;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RuntimeSettings {
     pub units: Units,
+    #[serde(default)]
+    pub wind_unit: WindUnit,
+    #[serde(default)]
+    pub pressure_unit: PressureUnit,
+    #[serde(default)]
+    pub precip_unit: PrecipUnit,
+    #[serde(default)]
+    pub distance_unit: DistanceUnit,
     pub theme: ThemeArg,
     pub motion_mode: MotionMode,
     pub no_flash: bool,
@@ -32,10 +51,23 @@ pub struct RuntimeSettings {
     pub hero_visual: HeroVisualArg,
     pub refresh_interval_secs: u64,
     #[serde(default)]
+    pub provider: ProviderArg,
+    /// Days of forecast fetched, `1..=MAX_FORECAST_DAYS`.
+    #[serde(default = "default_forecast_days")]
+    pub forecast_days: u8,
+    #[serde(default)]
     pub last_update_check_unix: Option<i64>,
     #[serde(default)]
     pub last_seen_latest_version: Option<String>,
     pub recent_locations: Vec<RecentLocation>,
+    /// Favorites shown on the dashboard and atop the city picker, in the order
+    /// they were pinned. Unlike `recent_locations` they never roll off.
+    #[serde(default)]
+    pub pinned_locations: Vec<PinnedLocation>,
+    /// Extra alerts checked after the built-in ones, written like
+    /// `gust > 40 km/h within 12h -> warning`.
+    #[serde(default, deserialize_with = "deserialize_alert_rules")]
+    pub alert_rules: Vec<AlertRule>,
 }
 
 impl RuntimeSettings {
@@ -57,6 +89,10 @@ impl RuntimeSettings {
 
         Self {
             units,
+            wind_unit: cli.wind_unit.unwrap_or_default(),
+            pressure_unit: cli.pressure_unit.unwrap_or_default(),
+            precip_unit: cli.precip_unit.unwrap_or_default(),
+            distance_unit: cli.distance_unit.unwrap_or_default(),
             theme: cli.theme,
             motion_mode: cli.effective_motion_mode(),
             no_flash: cli.no_flash,
@@ -66,10 +102,85 @@ impl RuntimeSettings {
             command_bar_enabled: true,
             hero_visual: cli.hero_visual,
             refresh_interval_secs: cli.refresh_interval,
+            provider: cli.provider.unwrap_or_default(),
+            forecast_days: cli.forecast_days.unwrap_or(DEFAULT_FORECAST_DAYS),
             last_update_check_unix: None,
             last_seen_latest_version: None,
             recent_locations: Vec::new(),
+            pinned_locations: Vec::new(),
+            alert_rules: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn measure_units(&self) -> MeasureUnits {
+        MeasureUnits {
+            wind: self.wind_unit,
+            pressure: self.pressure_unit,
+            precip: self.precip_unit,
+            distance: self.distance_unit,
+        }
+    }
+
+    /// Moves `location` to the front of the recent list, replacing an earlier
+    /// entry for the same place and dropping the oldest beyond the cap.
+    pub fn remember_location(&mut self, location: &Location) {
+        let entry = RecentLocation::from_location(location);
+        self.recent_locations
+            .retain(|existing| !existing.same_place(&entry));
+        self.recent_locations.insert(0, entry);
+        self.recent_locations.truncate(RECENT_LOCATIONS_MAX);
+    }
+
+    #[must_use]
+    pub fn is_pinned(&self, location: &Location) -> bool {
+        let entry = RecentLocation::from_location(location);
+        self.pinned_locations
+            .iter()
+            .any(|existing| existing.place.same_place(&entry))
+    }
+
+    /// Appends `location` to the pinned list under an optional alias. Returns
+    /// `false` when it is already pinned or the list is full.
+    pub fn pin_location(&mut self, location: &Location, alias: Option<String>) -> bool {
+        if self.is_pinned(location) || self.pinned_locations.len() >= PINNED_LOCATIONS_MAX {
+            return false;
         }
+        self.pinned_locations.push(PinnedLocation {
+            alias,
+            place: RecentLocation::from_location(location),
+        });
+        true
+    }
+
+    /// Removes `location` from the pinned list. Returns `false` when it was not
+    /// pinned.
+    pub fn unpin_location(&mut self, location: &Location) -> bool {
+        let entry = RecentLocation::from_location(location);
+        let before = self.pinned_locations.len();
+        self.pinned_locations
+            .retain(|existing| !existing.place.same_place(&entry));
+        self.pinned_locations.len() != before
+    }
+
+    /// The pinned location whose alias, or failing that whose name, matches
+    /// `query` ignoring case.
+    #[must_use]
+    pub fn find_pinned(&self, query: &str) -> Option<&PinnedLocation> {
+        let query = query.trim();
+        self.pinned_locations
+            .iter()
+            .find(|pinned| {
+                pinned
+                    .alias
+                    .as_deref()
+                    .is_some_and(|alias| unicode_case_eq(alias, query))
+            })
+            .or_else(|| {
+                self.pinned_locations
+                    .iter()
+                    .find(|pinned| unicode_case_eq(&pinned.place.name, query))
+            })
     }
 }
 
@@ -77,6 +188,10 @@ impl Default for RuntimeSettings {
     fn default() -> Self {
         Self {
             units: Units::Celsius,
+            wind_unit: WindUnit::default(),
+            pressure_unit: PressureUnit::default(),
+            precip_unit: PrecipUnit::default(),
+            distance_unit: DistanceUnit::default(),
             theme: ThemeArg::Auto,
             motion_mode: MotionMode::Cinematic,
             no_flash: false,
@@ -86,9 +201,13 @@ impl Default for RuntimeSettings {
             command_bar_enabled: true,
             hero_visual: HeroVisualArg::AtmosCanvas,
             refresh_interval_secs: 600,
+            provider: ProviderArg::OpenMeteo,
+            forecast_days: DEFAULT_FORECAST_DAYS,
             last_update_check_unix: None,
             last_seen_latest_version: None,
             recent_locations: Vec::new(),
+            pinned_locations: Vec::new(),
+            alert_rules: Vec::new(),
         }
     }
 }
@@ -103,6 +222,27 @@ pub struct RecentLocation {
     pub timezone: Option<String>,
 }
 
+/// A favorite location, optionally named with a short alias such as "home"
+/// that `--location` and `locations unpin` accept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedLocation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(flatten)]
+    pub place: RecentLocation,
+}
+
+impl PinnedLocation {
+    /// `alias · display name`, or just the display name without an alias.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match &self.alias {
+            Some(alias) => format!("{alias} · {}", self.place.display_name()),
+            None => self.place.display_name(),
+        }
+    }
+}
+
 impl RecentLocation {
     #[must_use]
     pub fn from_location(location: &Location) -> Self {
@@ -171,31 +311,76 @@ pub fn load_runtime_settings(cli: &Cli, enable_disk: bool) -> (RuntimeSettings,
         return (settings, None);
     };
 
-    if let Ok(file) = std::fs::File::open(&path) {
-        use std::io::Read;
-        let mut content = String::new();
-        if file.take(64 * 1024).read_to_string(&mut content).is_ok()
-            && let Ok(saved) = serde_json::from_str::<RuntimeSettings>(&content)
-        {
-            settings = saved;
-        }
+    if let Some(saved) = read_saved_settings(&path) {
+        settings = saved;
     }
     apply_cli_overrides(&mut settings, cli);
 
     // Prevent DoS panics from maliciously large refresh intervals (e.g. u64::MAX)
     settings.refresh_interval_secs = settings.refresh_interval_secs.clamp(10, 86400);
+    settings.forecast_days = settings.forecast_days.clamp(1, MAX_FORECAST_DAYS);
 
     (settings, Some(path))
 }
 
+/// Reads `alert_rules` one entry at a time, skipping rules that no longer parse
+/// with a warning, so a single bad line cannot discard the rest of the file.
+fn deserialize_alert_rules<'de, D>(deserializer: D) -> Result<Vec<AlertRule>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let entries = Vec::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| {
+            AlertRule::deserialize(&entry)
+                .map_err(|err| {
+                    eprintln!("terminal-weather: skipping saved alert rule {entry}: {err}");
+                })
+                .ok()
+        })
+        .collect())
+}
+
+/// Reads the settings file as saved, without CLI overrides. `None` when the
+/// file is missing or unreadable.
+#[must_use]
+pub fn read_saved_settings(path: &Path) -> Option<RuntimeSettings> {
+    use std::io::Read;
+    let file = std::fs::File::open(path).ok()?;
+    let mut content = String::new();
+    file.take(64 * 1024).read_to_string(&mut content).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Swaps `--location <alias>` for the pinned location's coordinates, so every
+/// mode then resolves it exactly like `--lat/--lon`.
+pub fn apply_location_alias(cli: &mut Cli, settings: &RuntimeSettings) -> anyhow::Result<()> {
+    let Some(query) = cli.location.take() else {
+        return Ok(());
+    };
+    if cli.city.is_some() || cli.lat.is_some() || cli.lon.is_some() {
+        anyhow::bail!("--location cannot be combined with a city or --lat/--lon");
+    }
+    let pinned = settings.find_pinned(&query).with_context(|| {
+        format!("No pinned location named '{query}'; see `terminal-weather locations list`")
+    })?;
+    cli.lat = Some(pinned.place.latitude);
+    cli.lon = Some(pinned.place.longitude);
+    Ok(())
+}
+
 fn apply_cli_overrides(settings: &mut RuntimeSettings, cli: &Cli) {
     override_units(settings, cli);
+    override_measure_units(settings, cli);
     override_theme(settings, cli);
     override_motion(settings, cli);
     override_flash(settings, cli);
     override_icon_mode(settings, cli);
     override_hero_visual(settings, cli);
     override_refresh_interval(settings, cli);
+    override_provider(settings, cli);
+    override_forecast_days(settings, cli);
 }
 
 fn override_units(settings: &mut RuntimeSettings, cli: &Cli) {
@@ -204,6 +389,21 @@ fn override_units(settings: &mut RuntimeSettings, cli: &Cli) {
     }
 }
 
+fn override_measure_units(settings: &mut RuntimeSettings, cli: &Cli) {
+    if let Some(unit) = cli.wind_unit {
+        settings.wind_unit = unit;
+    }
+    if let Some(unit) = cli.pressure_unit {
+        settings.pressure_unit = unit;
+    }
+    if let Some(unit) = cli.precip_unit {
+        settings.precip_unit = unit;
+    }
+    if let Some(unit) = cli.distance_unit {
+        settings.distance_unit = unit;
+    }
+}
+
 fn override_theme(settings: &mut RuntimeSettings, cli: &Cli) {
     if cli.theme != ThemeArg::Auto {
         settings.theme = cli.theme;
@@ -245,6 +445,22 @@ fn override_refresh_interval(settings: &mut RuntimeSettings, cli: &Cli) {
     }
 }
 
+fn override_provider(settings: &mut RuntimeSettings, cli: &Cli) {
+    if let Some(provider) = cli.provider {
+        settings.provider = provider;
+    }
+}
+
+fn override_forecast_days(settings: &mut RuntimeSettings, cli: &Cli) {
+    if let Some(days) = cli.forecast_days {
+        settings.forecast_days = days;
+    }
+}
+
+const fn default_forecast_days() -> u8 {
+    DEFAULT_FORECAST_DAYS
+}
+
 const fn default_inline_hints() -> bool {
     true
 }
@@ -263,6 +479,14 @@ pub fn hourly_view_from_cli(arg: HourlyViewArg) -> HourlyViewMode {
 }
 
 pub fn save_runtime_settings(path: &Path, settings: &RuntimeSettings) -> anyhow::Result<()> {
+    let payload =
+        serde_json::to_string_pretty(&settings).context("serializing settings payload failed")?;
+    write_private_file(path, payload.as_bytes(), "settings")
+}
+
+/// Writes `payload` to `path`, creating the parent directory as owner-only (0700)
+/// and the file as owner read/write (0600) on unix. `what` names the file in errors.
+pub(crate) fn write_private_file(path: &Path, payload: &[u8], what: &str) -> anyhow::Result<()> {
     if let Some(parent) = path.parent() {
         #[cfg(unix)]
         {
@@ -271,15 +495,14 @@ pub fn save_runtime_settings(path: &Path, settings: &RuntimeSettings) -> anyhow:
                 .recursive(true)
                 .mode(0o700)
                 .create(parent)
-                .context("creating settings directory failed")?;
+                .with_context(|| format!("creating {what} directory failed"))?;
         }
         #[cfg(not(unix))]
         {
-            fs::create_dir_all(parent).context("creating settings directory failed")?;
+            fs::create_dir_all(parent)
+                .with_context(|| format!("creating {what} directory failed"))?;
         }
     }
-    let payload =
-        serde_json::to_string_pretty(&settings).context("serializing settings payload failed")?;
 
     #[cfg(unix)]
     {
@@ -292,14 +515,14 @@ pub fn save_runtime_settings(path: &Path, settings: &RuntimeSettings) -> anyhow:
             .truncate(true)
             .mode(0o600)
             .open(path)
-            .context("opening settings file with strict permissions failed")?
-            .write_all(payload.as_bytes())
-            .context("writing settings file failed")
+            .with_context(|| format!("opening {what} file with strict permissions failed"))?
+            .write_all(payload)
+            .with_context(|| format!("writing {what} file failed"))
     }
 
     #[cfg(not(unix))]
     {
-        fs::write(path, payload).context("writing settings file failed")
+        fs::write(path, payload).with_context(|| format!("writing {what} file failed"))
     }
 }
 
@@ -311,7 +534,7 @@ pub fn clear_runtime_settings(path: &Path) -> anyhow::Result<()> {
     }
 }
 
-fn settings_path() -> Option<PathBuf> {
+pub(crate) fn settings_path() -> Option<PathBuf> {
     #[cfg(test)]
     if let Some(path) = test_settings_path_override() {
         return Some(path);
@@ -122,6 +122,59 @@ fn override_refresh_interval_non_default_updates() {
     assert_eq!(settings.refresh_interval_secs, 300);
 }
 
+#[test]
+fn override_provider_replaces_saved_provider_only_when_flag_set() {
+    let mut settings = RuntimeSettings {
+        provider: crate::cli::ProviderArg::MetNorway,
+        ..RuntimeSettings::default()
+    };
+    super::super::apply_cli_overrides(&mut settings, &default_cli());
+    assert_eq!(settings.provider, crate::cli::ProviderArg::MetNorway);
+
+    let mut cli = default_cli();
+    cli.provider = Some(crate::cli::ProviderArg::OpenMeteo);
+    super::super::apply_cli_overrides(&mut settings, &cli);
+    assert_eq!(settings.provider, crate::cli::ProviderArg::OpenMeteo);
+}
+
+#[test]
+fn override_forecast_days_replaces_saved_horizon_only_when_flag_set() {
+    let mut settings = RuntimeSettings {
+        forecast_days: 14,
+        ..RuntimeSettings::default()
+    };
+    super::super::apply_cli_overrides(&mut settings, &default_cli());
+    assert_eq!(settings.forecast_days, 14);
+
+    let mut cli = default_cli();
+    cli.forecast_days = Some(3);
+    super::super::apply_cli_overrides(&mut settings, &cli);
+    assert_eq!(settings.forecast_days, 3);
+    assert_eq!(RuntimeSettings::default().forecast_days, 7);
+}
+
+#[test]
+fn override_measure_units_replaces_only_flagged_units() {
+    let mut settings = RuntimeSettings {
+        wind_unit: crate::domain::weather::WindUnit::Knots,
+        precip_unit: crate::domain::weather::PrecipUnit::In,
+        ..RuntimeSettings::default()
+    };
+    let mut cli = default_cli();
+    cli.wind_unit = Some(crate::domain::weather::WindUnit::Mph);
+    cli.pressure_unit = Some(crate::domain::weather::PressureUnit::InHg);
+    super::super::apply_cli_overrides(&mut settings, &cli);
+
+    let measures = settings.measure_units();
+    assert_eq!(measures.wind, crate::domain::weather::WindUnit::Mph);
+    assert_eq!(
+        measures.pressure,
+        crate::domain::weather::PressureUnit::InHg
+    );
+    assert_eq!(measures.precip, crate::domain::weather::PrecipUnit::In);
+    assert_eq!(measures.distance, crate::domain::weather::DistanceUnit::Km);
+}
+
 #[test]
 
 fn test_hourly_view_from_cli() {
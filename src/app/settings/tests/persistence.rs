@@ -1,4 +1,32 @@
 use super::*;
+use crate::domain::alerts::AlertRule;
+use crate::domain::weather::{Location, WindUnit};
+
+#[test]
+fn unparsable_alert_rule_keeps_the_rest_of_the_settings() {
+    let file = NamedTempFile::new().expect("create temp settings file");
+    let mut settings = RuntimeSettings {
+        wind_unit: WindUnit::Kmh,
+        alert_rules: vec!["gust > 40 km/h -> warning".parse().expect("rule")],
+        ..RuntimeSettings::default()
+    };
+    settings.pin_location(
+        &Location::from_coords(59.91, 10.75),
+        Some("Home".to_string()),
+    );
+    save_runtime_settings(file.path(), &settings).expect("save settings");
+
+    let content = std::fs::read_to_string(file.path()).expect("read settings");
+    let edited = content.replace(r#""alert_rules": ["#, r#""alert_rules": ["gust >> fast", "#);
+    assert_ne!(edited, content);
+    assert!("gust >> fast".parse::<AlertRule>().is_err());
+    std::fs::write(file.path(), edited).expect("write settings");
+
+    let restored = super::super::read_saved_settings(file.path()).expect("settings still load");
+    assert_eq!(restored.wind_unit, WindUnit::Kmh);
+    assert_eq!(restored.pinned_locations.len(), 1);
+    assert_eq!(restored.alert_rules, settings.alert_rules);
+}
 
 #[test]
 fn runtime_settings_roundtrip_preserves_hourly_view() {
@@ -199,3 +199,93 @@ fn same_place_country_mismatch_returns_false() {
     };
     assert!(!a.same_place(&b));
 }
+
+#[test]
+fn pin_location_skips_duplicates_and_caps_list() {
+    let mut settings = RuntimeSettings::default();
+    let stockholm = stockholm_recent_location().to_location();
+    assert!(settings.pin_location(&stockholm, None));
+    assert!(!settings.pin_location(&stockholm, None));
+    assert!(settings.is_pinned(&stockholm));
+
+    for index in 1..super::super::PINNED_LOCATIONS_MAX {
+        let mut other = stockholm.clone();
+        other.name = format!("City {index}");
+        assert!(settings.pin_location(&other, None));
+    }
+    let mut overflow = stockholm.clone();
+    overflow.name = "Overflow".to_string();
+    assert!(!settings.pin_location(&overflow, None));
+
+    assert!(settings.unpin_location(&stockholm));
+    assert!(!settings.unpin_location(&stockholm));
+    assert_eq!(settings.pinned_locations[0].place.name, "City 1");
+}
+
+#[test]
+fn find_pinned_prefers_alias_then_name() {
+    let mut settings = RuntimeSettings::default();
+    let stockholm = stockholm_recent_location().to_location();
+    settings.pin_location(&stockholm, Some("Home".to_string()));
+    let mut office = stockholm.clone();
+    office.name = "home".to_string();
+    office.latitude = 1.0;
+    settings.pin_location(&office, None);
+
+    let found = settings.find_pinned("HOME").expect("by alias");
+    assert_eq!(found.place.name, "Stockholm");
+    assert_eq!(found.label(), "Home · Stockholm, Sweden");
+    assert_eq!(
+        settings
+            .find_pinned(" stockholm ")
+            .expect("by name")
+            .alias
+            .as_deref(),
+        Some("Home")
+    );
+    assert!(settings.find_pinned("cabin").is_none());
+}
+
+#[test]
+fn pinned_location_reads_entries_saved_without_alias() {
+    let json = serde_json::json!({
+        "pinned_locations": [{
+            "name": "Oslo",
+            "latitude": 59.91,
+            "longitude": 10.75,
+            "country": "Norway",
+            "admin1": null,
+            "timezone": null
+        }]
+    });
+    let settings: RuntimeSettings = serde_json::from_value(json).expect("settings");
+    assert_eq!(settings.pinned_locations[0].alias, None);
+    assert_eq!(settings.pinned_locations[0].place.name, "Oslo");
+
+    let saved = serde_json::to_string(&settings.pinned_locations[0]).expect("serialize");
+    assert!(saved.starts_with(r#"{"name":"Oslo""#));
+}
+
+#[test]
+fn apply_location_alias_swaps_in_pinned_coordinates() {
+    let mut settings = RuntimeSettings::default();
+    settings.pin_location(
+        &stockholm_recent_location().to_location(),
+        Some("home".to_string()),
+    );
+
+    let mut cli = crate::test_support::state_test_cli();
+    cli.location = Some("home".to_string());
+    super::super::apply_location_alias(&mut cli, &settings).expect("alias resolves");
+    assert_eq!((cli.lat, cli.lon), (Some(59.33), Some(18.07)));
+    assert!(cli.location.is_none());
+
+    cli.location = Some("cabin".to_string());
+    cli.lat = None;
+    cli.lon = None;
+    assert!(super::super::apply_location_alias(&mut cli, &settings).is_err());
+
+    cli.location = Some("home".to_string());
+    cli.city = Some("Oslo".to_string());
+    assert!(super::super::apply_location_alias(&mut cli, &settings).is_err());
+}
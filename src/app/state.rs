@@ -1,6 +1,7 @@
 #![allow(clippy::missing_errors_doc)]
 
 use std::{
+    collections::{HashMap, HashSet},
     io::IsTerminal,
     num::NonZeroUsize,
     path::PathBuf,
@@ -19,13 +20,17 @@ use crate::{
             AppEvent, DemoAction, schedule_retry, start_demo_task, start_frame_task,
             start_refresh_task,
         },
+        forecast_store::ForecastStore,
         settings::{
             RecentLocation, RuntimeSettings, clear_runtime_settings, hourly_view_from_cli,
             load_runtime_settings,
         },
     },
     cli::{Cli, ColorArg, HeroVisualArg, ThemeArg},
-    data::{forecast::ForecastClient, geocode::GeocodeClient},
+    data::{
+        geocode::GeocodeClient,
+        provider::{ForecastProvider, WeatherProvider},
+    },
     domain::weather::{
         ForecastBundle, GeocodeResolution, HourlyViewMode, Location, RefreshMetadata, Units,
         evaluate_freshness,
@@ -89,7 +94,7 @@ impl PanelFocus {
         match self {
             Self::Hero => "Hero",
             Self::Hourly => "Hourly",
-            Self::Daily => "7-Day",
+            Self::Daily => "Daily",
         }
     }
 }
@@ -118,6 +123,27 @@ impl CommandBarState {
     }
 }
 
+/// The pinned-locations grid. Forecasts live in `AppState::forecast_cache`;
+/// this only tracks the selection and the status of background fetches, which
+/// location-tab prefetches share.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardState {
+    pub open: bool,
+    pub selected: usize,
+    pub loading: HashSet<LocationKey>,
+    pub errors: HashMap<LocationKey, String>,
+}
+
+/// The side-by-side view. The main location is the left side; the second
+/// location's forecast lives in `forecast_cache` like a dashboard card.
+#[derive(Debug, Clone, Default)]
+pub struct CompareState {
+    pub open: bool,
+    pub location: Option<Location>,
+    /// Lookup progress or failure, shown until the second forecast arrives.
+    pub status: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct SettingsEntry {
     pub label: &'static str,
@@ -171,6 +197,10 @@ pub struct AppState {
     pub units: Units,
     pub hourly_offset: usize,
     pub hourly_cursor: usize,
+    /// Selected row of the daily panel, highlighted while it has focus.
+    pub daily_cursor: usize,
+    /// Drill-down into the day under `daily_cursor`.
+    pub day_detail_open: bool,
     pub particles: ParticleEngine,
     pub backoff: Backoff,
     pub fetch_in_flight: bool,
@@ -183,7 +213,9 @@ pub struct AppState {
     pub weather_motion_profile: Option<WeatherMotionProfile>,
     pub animate_ui: bool,
     pub viewport_width: u16,
+    pub viewport_height: u16,
     pub demo_mode: bool,
+    pub offline: bool,
     pub settings: RuntimeSettings,
     pub settings_open: bool,
     pub help_open: bool,
@@ -197,10 +229,18 @@ pub struct AppState {
     pub panel_focus: PanelFocus,
     pub update_status: UpdateStatus,
     pub command_bar: CommandBarState,
+    pub dashboard: DashboardState,
+    pub compare: CompareState,
+    /// Pinned then recent locations in tab-bar order; see `sync_location_tabs`.
+    pub location_tabs: Vec<RecentLocation>,
     pub refresh_interval_secs_runtime: Arc<AtomicU64>,
+    /// Set while a bundle restored from disk is shown and its revalidating fetch
+    /// has not completed yet.
+    pub cache_revalidating: bool,
     forecast_url_override: Option<String>,
     air_quality_url_override: Option<String>,
     settings_path: Option<PathBuf>,
+    forecast_store: Option<ForecastStore>,
 }
 
 impl AppState {
@@ -236,6 +276,8 @@ impl AppState {
             units: settings.units,
             hourly_offset: 0,
             hourly_cursor: 0,
+            daily_cursor: 0,
+            day_detail_open: false,
             particles: ParticleEngine::new(settings.motion_mode, settings.no_flash),
             backoff: Backoff::new(10, 300),
             fetch_in_flight: false,
@@ -248,7 +290,9 @@ impl AppState {
             weather_motion_profile: None,
             animate_ui: settings.motion_mode.allows_animation(),
             viewport_width: 80,
+            viewport_height: 24,
             demo_mode: false,
+            offline: false,
             settings: settings.clone(),
             settings_open: false,
             help_open: false,
@@ -262,10 +306,15 @@ impl AppState {
             panel_focus: PanelFocus::Hourly,
             update_status: UpdateStatus::Unknown,
             command_bar: CommandBarState::default(),
+            dashboard: DashboardState::default(),
+            compare: CompareState::default(),
+            location_tabs: Vec::new(),
             refresh_interval_secs_runtime,
+            cache_revalidating: false,
             forecast_url_override: None,
             air_quality_url_override: None,
             settings_path: None,
+            forecast_store: None,
         }
     }
 
@@ -277,7 +326,14 @@ impl AppState {
         runtime_hourly_view: HourlyViewMode,
     ) {
         self.demo_mode = cli.demo;
+        self.offline = cli.offline;
+        self.dashboard.open = cli.dashboard;
         self.settings = settings;
+        self.sync_location_tabs();
+        self.forecast_store = settings_path
+            .as_deref()
+            .filter(|_| !cli.demo)
+            .and_then(ForecastStore::beside_settings);
         self.settings_path = settings_path;
         self.color_mode = cli.effective_color_mode();
         self.hourly_view_mode = runtime_hourly_view;
@@ -1,6 +1,9 @@
 use super::*;
 
 pub(crate) fn initial_selected_location(cli: &Cli, settings: &RuntimeSettings) -> Option<Location> {
+    if cli.offline {
+        return crate::app::offline::resolve_offline_location(cli, settings);
+    }
     if cli.city.is_some() || cli.lat.is_some() || cli.lon.is_some() || cli.demo {
         return None;
     }
@@ -3,6 +3,7 @@ use super::*;
 mod command_bar;
 mod command_parse;
 mod lifecycle;
+mod mouse;
 use command_parse::{KeyCommand, command_from_char};
 
 #[cfg(test)]
@@ -79,8 +80,10 @@ impl AppState {
             Event::Key(key) if key.kind == KeyEventKind::Press => {
                 self.handle_key_press(key, tx, cli).await?;
             }
-            Event::Resize(width, _) => {
+            Event::Mouse(mouse) => self.handle_mouse(mouse, tx, cli).await?,
+            Event::Resize(width, height) => {
                 self.viewport_width = width;
+                self.viewport_height = height;
                 self.particles.reset();
             }
             _ => {}
@@ -105,6 +108,12 @@ impl AppState {
         if self.handle_modal_key_press(key, tx, cli).await? {
             return Ok(());
         }
+        if self.dashboard.open && self.handle_dashboard_key(key.code, tx) {
+            return Ok(());
+        }
+        if self.compare.open && self.handle_compare_key(key.code, tx) {
+            return Ok(());
+        }
         self.handle_main_key_press(key, tx, cli).await
     }
 
@@ -153,6 +162,10 @@ impl AppState {
             self.handle_help_key(key, tx).await?;
             return Ok(true);
         }
+        if self.day_detail_open {
+            self.handle_day_detail_key(key.code);
+            return Ok(true);
+        }
         Ok(false)
     }
 
@@ -190,6 +203,7 @@ impl AppState {
         }
         let handled = self.handle_panel_focus_key(code)
             || self.handle_hourly_navigation_key(code)
+            || self.handle_daily_navigation_key(code)
             || self.try_select_pending_location(code, tx);
         Ok(handled)
     }
@@ -238,6 +252,21 @@ impl AppState {
         }
     }
 
+    /// `↑`/`↓` move the selected day while the daily panel has focus; `Enter`
+    /// opens its detail.
+    pub(crate) fn handle_daily_navigation_key(&mut self, code: KeyCode) -> bool {
+        if self.panel_focus != PanelFocus::Daily {
+            return false;
+        }
+        match code {
+            KeyCode::Up => self.move_daily_cursor(-1),
+            KeyCode::Down => self.move_daily_cursor(1),
+            KeyCode::Enter => self.open_day_detail(),
+            _ => return false,
+        }
+        true
+    }
+
     pub(crate) fn try_select_pending_location(
         &mut self,
         code: KeyCode,
@@ -276,6 +305,9 @@ impl AppState {
         match action {
             KeyCommand::Quit => self.command_quit(tx).await?,
             KeyCommand::Refresh => self.command_refresh(tx, cli).await?,
+            KeyCommand::OpenDashboard => self.open_dashboard(tx),
+            KeyCommand::PreviousLocationTab => self.cycle_location_tab(tx, -1),
+            KeyCommand::NextLocationTab => self.cycle_location_tab(tx, 1),
             _ => self.execute_sync_key_command(action),
         }
         Ok(true)
@@ -288,7 +320,12 @@ impl AppState {
             KeyCommand::SetFahrenheit => self.set_units(Units::Fahrenheit),
             KeyCommand::SetCelsius => self.set_units(Units::Celsius),
             KeyCommand::CycleHourlyView => self.command_cycle_hourly_view(),
-            KeyCommand::Quit | KeyCommand::Refresh => {}
+            KeyCommand::TogglePin => self.toggle_pin_current_location(),
+            KeyCommand::Quit
+            | KeyCommand::Refresh
+            | KeyCommand::OpenDashboard
+            | KeyCommand::PreviousLocationTab
+            | KeyCommand::NextLocationTab => {}
         }
     }
 
@@ -382,6 +419,14 @@ impl AppState {
         }
     }
 
+    pub(crate) fn move_daily_cursor(&mut self, step: isize) {
+        let last = self
+            .weather
+            .as_ref()
+            .map_or(0, |bundle| bundle.daily.len().saturating_sub(1));
+        self.daily_cursor = self.daily_cursor.saturating_add_signed(step).min(last);
+    }
+
     pub(crate) fn handle_panel_focus_key(&mut self, code: KeyCode) -> bool {
         match code {
             KeyCode::Tab => {
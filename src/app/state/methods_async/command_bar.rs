@@ -100,6 +100,10 @@ impl AppState {
             CommandAction::City(query) => {
                 self.start_city_search(tx, query, cli.country_code.clone());
             }
+            CommandAction::Compare(Some(query)) => {
+                self.open_compare(tx, &query, cli.country_code.clone());
+            }
+            CommandAction::Compare(None) => self.close_compare(),
             CommandAction::Refresh | CommandAction::Quit => {}
         }
     }
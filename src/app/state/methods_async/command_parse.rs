@@ -14,6 +14,10 @@ pub(super) enum KeyCommand {
     SetFahrenheit,
     SetCelsius,
     CycleHourlyView,
+    OpenDashboard,
+    TogglePin,
+    PreviousLocationTab,
+    NextLocationTab,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -24,10 +28,12 @@ pub(super) enum CommandAction {
     View(HourlyViewMode),
     Theme(ThemeArg),
     City(String),
+    /// `:compare <city>` opens the comparison view; bare `:compare` closes it.
+    Compare(Option<String>),
 }
 
 pub(super) fn command_from_char(cmd: char) -> Option<KeyCommand> {
-    const KEY_COMMANDS: [(char, KeyCommand); 7] = [
+    const KEY_COMMANDS: [(char, KeyCommand); 11] = [
         ('q', KeyCommand::Quit),
         ('s', KeyCommand::OpenSettings),
         ('l', KeyCommand::OpenCityPicker),
@@ -35,6 +41,10 @@ pub(super) fn command_from_char(cmd: char) -> Option<KeyCommand> {
         ('f', KeyCommand::SetFahrenheit),
         ('c', KeyCommand::SetCelsius),
         ('v', KeyCommand::CycleHourlyView),
+        ('d', KeyCommand::OpenDashboard),
+        ('p', KeyCommand::TogglePin),
+        ('[', KeyCommand::PreviousLocationTab),
+        (']', KeyCommand::NextLocationTab),
     ];
 
     KEY_COMMANDS
@@ -77,6 +87,9 @@ pub(super) fn parse_command_action(command: &str) -> std::result::Result<Command
         "view" => cmd_view(&rest),
         "theme" => cmd_theme(&rest),
         "city" => cmd_city(&rest),
+        "compare" => Ok(CommandAction::Compare(
+            (!rest.is_empty()).then(|| rest.join(" ")),
+        )),
         _ => Err(format!("unknown command: {verb}")),
     }
 }
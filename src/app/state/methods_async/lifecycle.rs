@@ -1,4 +1,5 @@
 use super::*;
+use crate::resilience::freshness::FreshnessState;
 
 impl AppState {
     pub(crate) fn handle_sync_event(&mut self, event: AppEvent, tx: &mpsc::Sender<AppEvent>) {
@@ -14,8 +15,21 @@ impl AppState {
             AppEvent::TickFrame => self.handle_tick_frame(),
             AppEvent::FetchStarted => self.handle_fetch_started(),
             AppEvent::GeocodeResolved(resolution) => self.handle_geocode_resolved(tx, resolution),
-            AppEvent::FetchSucceeded(bundle) => self.handle_fetch_succeeded(bundle),
+            AppEvent::FetchSucceeded(bundle) if self.offline => {
+                self.show_cached_forecast(bundle, FreshnessState::Offline);
+            }
+            AppEvent::FetchSucceeded(bundle) => {
+                self.persist_fetched_forecast(&bundle);
+                self.handle_fetch_succeeded(bundle);
+                self.prefetch_tab_neighbors(tx);
+            }
             AppEvent::FetchFailed(err) => self.handle_fetch_failed(tx, err),
+            AppEvent::DashboardFetched(bundle) => self.handle_dashboard_fetched(bundle),
+            AppEvent::DashboardFetchFailed(location, err) => {
+                self.handle_dashboard_fetch_failed(&location, err);
+            }
+            AppEvent::CompareResolved(resolution) => self.handle_compare_resolved(tx, resolution),
+            AppEvent::CompareLookupFailed(err) => self.handle_compare_lookup_failed(err),
             AppEvent::Bootstrap
             | AppEvent::TickRefresh
             | AppEvent::ForceRedraw
@@ -40,6 +54,30 @@ impl AppState {
             start_demo_task(tx.clone());
         }
         self.start_update_check_if_needed(tx, cli);
+        if let Some(location) = self.selected_location.clone() {
+            self.restore_stored_forecast(&location);
+        }
+        if self.dashboard.open {
+            self.refresh_dashboard(tx, false);
+        }
+        if let Some(query) = &cli.compare {
+            self.open_compare(tx, query, cli.country_code.clone());
+        }
+        self.start_fetch(tx, cli).await
+    }
+
+    /// Bootstrap for `--serve`: the refresh loop, retries and disk cache without
+    /// frame ticks, the demo script or update checks.
+    pub(crate) async fn handle_headless_bootstrap(
+        &mut self,
+        tx: &mpsc::Sender<AppEvent>,
+        cli: &Cli,
+    ) -> Result<()> {
+        cli.validate()?;
+        start_refresh_task(tx.clone(), self.refresh_interval_secs_runtime.clone());
+        if let Some(location) = self.selected_location.clone() {
+            self.restore_stored_forecast(&location);
+        }
         self.start_fetch(tx, cli).await
     }
 
@@ -58,10 +96,7 @@ impl AppState {
         }
 
         let previous_freshness = self.refresh_meta.state;
-        self.refresh_meta.state = evaluate_freshness(
-            self.refresh_meta.last_success,
-            self.refresh_meta.consecutive_failures,
-        );
+        self.refresh_meta.state = self.current_freshness();
         if previous_freshness != self.refresh_meta.state && self.active_transition.is_none() {
             self.begin_transition(crate::ui::animation::SceneTransitionState::freshness_pulse(
                 self.motion_mode,
@@ -82,12 +117,20 @@ impl AppState {
         tx: &mpsc::Sender<AppEvent>,
         cli: &Cli,
     ) -> Result<()> {
-        if matches!(
-            self.mode,
-            AppMode::Ready | AppMode::Error | AppMode::Loading
-        ) {
+        if !self.offline
+            && matches!(
+                self.mode,
+                AppMode::Ready | AppMode::Error | AppMode::Loading
+            )
+        {
             self.start_fetch(tx, cli).await?;
         }
+        if self.dashboard.open && !self.offline {
+            self.refresh_dashboard(tx, false);
+        }
+        if self.compare.open && !self.offline {
+            self.refresh_compare(tx, false);
+        }
         Ok(())
     }
 
@@ -145,7 +188,9 @@ impl AppState {
         self.backoff.reset();
         self.hourly_offset = 0;
         self.hourly_cursor = 0;
+        self.move_daily_cursor(0);
         self.push_recent_location(&location);
+        self.sync_location_tabs();
         self.persist_settings();
         self.city_status = None;
         self.city_picker_open = false;
@@ -169,10 +214,33 @@ impl AppState {
         self.last_render_signature = Some(next_signature);
     }
 
+    /// Freshness from the refresh metadata, except that `--offline` is always
+    /// `Offline` and a bundle restored from disk stays `Stale` (never `Offline`)
+    /// until its first revalidation attempt ends.
+    pub(crate) fn current_freshness(&self) -> FreshnessState {
+        if self.offline {
+            return FreshnessState::Offline;
+        }
+        if self.cache_revalidating {
+            return FreshnessState::Stale;
+        }
+        evaluate_freshness(
+            self.refresh_meta.last_success,
+            self.refresh_meta.consecutive_failures,
+        )
+    }
+
     pub(crate) fn handle_fetch_failed(&mut self, tx: &mpsc::Sender<AppEvent>, err: String) {
         self.fetch_in_flight = false;
+        self.cache_revalidating = false;
         self.last_error = Some(err);
         self.mode = AppMode::Error;
+        if self.offline {
+            self.city_status = self.last_error.clone();
+            self.refresh_meta.state = FreshnessState::Offline;
+            self.last_render_signature = Some(self.render_signature());
+            return;
+        }
         self.city_status =
             Some("Failed to fetch weather; keeping last successful data".to_string());
         self.refresh_meta.mark_failure();
@@ -229,5 +297,9 @@ pub(super) fn should_schedule_update_check(
     last_check_unix: Option<i64>,
     now_unix: i64,
 ) -> bool {
-    !cli.demo && !cli.one_shot && crate::update::should_check(now_unix, last_check_unix)
+    !cli.demo
+        && !cli.one_shot
+        && !cli.inline
+        && !cli.offline
+        && crate::update::should_check(now_unix, last_check_unix)
 }
@@ -0,0 +1,162 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+
+use super::*;
+use crate::ui::{self, ModalOverlay, widgets};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MouseAction {
+    Click,
+    ScrollBack,
+    ScrollForward,
+}
+
+impl MouseAction {
+    const fn from_kind(kind: MouseEventKind) -> Option<Self> {
+        match kind {
+            MouseEventKind::Down(MouseButton::Left) => Some(Self::Click),
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollLeft => Some(Self::ScrollBack),
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollRight => Some(Self::ScrollForward),
+            _ => None,
+        }
+    }
+}
+
+impl AppState {
+    /// Hit-tests against the layout of the frame on screen, so a click lands on
+    /// what was drawn before it changes focus or selection. A click on the
+    /// selected entry of an overlay activates it, like `Enter`.
+    pub(crate) async fn handle_mouse(
+        &mut self,
+        mouse: MouseEvent,
+        tx: &mpsc::Sender<AppEvent>,
+        cli: &Cli,
+    ) -> Result<()> {
+        let Some(action) = MouseAction::from_kind(mouse.kind) else {
+            return Ok(());
+        };
+        if self.command_bar.open {
+            return Ok(());
+        }
+        let area = Rect::new(0, 0, self.viewport_width, self.viewport_height);
+        let position = Position::new(mouse.column, mouse.row);
+        match ui::modal_overlay(area, self) {
+            Some(ModalOverlay::Settings(popup)) => {
+                self.handle_settings_mouse(popup, position, action, tx, cli)
+                    .await?;
+            }
+            Some(ModalOverlay::CityPicker(popup)) => {
+                self.handle_city_picker_mouse(popup, position, action, tx, cli);
+            }
+            Some(ModalOverlay::DayDetail(_)) => self.handle_day_detail_mouse(action),
+            Some(ModalOverlay::Selector(_) | ModalOverlay::Help(_)) => {}
+            None => self.handle_panel_mouse(area, position, action),
+        }
+        Ok(())
+    }
+
+    async fn handle_settings_mouse(
+        &mut self,
+        popup: Rect,
+        position: Position,
+        action: MouseAction,
+        tx: &mpsc::Sender<AppEvent>,
+        cli: &Cli,
+    ) -> Result<()> {
+        match action {
+            MouseAction::Click => {
+                let Some(selection) = widgets::settings::selection_at(popup, self, position.y)
+                    .filter(|_| popup.contains(position))
+                else {
+                    return Ok(());
+                };
+                if selection == self.settings_selected {
+                    self.handle_settings_enter(tx, cli).await?;
+                } else {
+                    self.settings_selected = selection;
+                }
+            }
+            MouseAction::ScrollBack => self.settings_selected = self.settings_selected.prev(),
+            MouseAction::ScrollForward => self.settings_selected = self.settings_selected.next(),
+        }
+        Ok(())
+    }
+
+    fn handle_city_picker_mouse(
+        &mut self,
+        popup: Rect,
+        position: Position,
+        action: MouseAction,
+        tx: &mpsc::Sender<AppEvent>,
+        cli: &Cli,
+    ) {
+        self.city_history_selected = self.city_history_selected.min(self.city_picker_max_index());
+        let scroll_key = match action {
+            MouseAction::ScrollBack => KeyCode::Up,
+            MouseAction::ScrollForward => KeyCode::Down,
+            MouseAction::Click => KeyCode::Null,
+        };
+        if self.handle_city_picker_nav_key(scroll_key, tx, cli) {
+            return;
+        }
+        let Some(index) = widgets::city_picker::entry_at(popup, self, position.y)
+            .filter(|_| popup.contains(position))
+        else {
+            return;
+        };
+        if index != self.city_history_selected {
+            self.city_history_selected = index;
+        } else if Some(index) == self.city_picker_action_index() {
+            self.clear_recent_locations();
+        } else {
+            self.select_picker_city_by_index(tx, index);
+        }
+    }
+
+    fn handle_day_detail_mouse(&mut self, action: MouseAction) {
+        match action {
+            MouseAction::ScrollBack => self.move_daily_cursor(-1),
+            MouseAction::ScrollForward => self.move_daily_cursor(1),
+            MouseAction::Click => {}
+        }
+    }
+
+    /// Clicks focus the panel under the pointer; a click on a daily row also
+    /// selects that day, and a second click on the selected day opens its
+    /// detail. The wheel steps the daily cursor over the daily panel and the
+    /// hourly cursor everywhere else.
+    fn handle_panel_mouse(&mut self, area: Rect, position: Position, action: MouseAction) {
+        let Some(panels) = ui::screen_panel_areas(area, self) else {
+            return;
+        };
+        let over_daily = panels.daily.contains(position);
+        match action {
+            MouseAction::Click if panels.hero.contains(position) => {
+                self.panel_focus = PanelFocus::Hero;
+            }
+            MouseAction::Click if panels.hourly.contains(position) => {
+                self.panel_focus = PanelFocus::Hourly;
+            }
+            MouseAction::Click if over_daily => {
+                if let Some(day) = widgets::daily::day_at(panels.daily, self, position.y) {
+                    if day == self.daily_cursor && self.panel_focus == PanelFocus::Daily {
+                        self.open_day_detail();
+                    }
+                    self.daily_cursor = day;
+                }
+                self.panel_focus = PanelFocus::Daily;
+            }
+            MouseAction::Click => {}
+            MouseAction::ScrollBack | MouseAction::ScrollForward if over_daily => {
+                self.panel_focus = PanelFocus::Daily;
+                self.move_daily_cursor(if action == MouseAction::ScrollBack {
+                    -1
+                } else {
+                    1
+                });
+            }
+            MouseAction::ScrollBack => self.move_hourly_cursor_left(),
+            MouseAction::ScrollForward => self.move_hourly_cursor_right(),
+        }
+    }
+}
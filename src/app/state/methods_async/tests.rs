@@ -1,4 +1,5 @@
 use super::*;
+use crate::resilience::freshness::FreshnessState;
 use chrono::Duration;
 use tempfile::tempdir;
 use tokio::sync::mpsc;
@@ -32,6 +33,13 @@ fn command_from_char_maps_known_commands() {
     assert_eq!(command_from_char('f'), Some(KeyCommand::SetFahrenheit));
     assert_eq!(command_from_char('c'), Some(KeyCommand::SetCelsius));
     assert_eq!(command_from_char('v'), Some(KeyCommand::CycleHourlyView));
+    assert_eq!(command_from_char('d'), Some(KeyCommand::OpenDashboard));
+    assert_eq!(command_from_char('p'), Some(KeyCommand::TogglePin));
+    assert_eq!(
+        command_from_char('['),
+        Some(KeyCommand::PreviousLocationTab)
+    );
+    assert_eq!(command_from_char(']'), Some(KeyCommand::NextLocationTab));
     assert_eq!(command_from_char('x'), None);
 }
 
@@ -312,3 +320,271 @@ fn fetch_succeeded_resets_state_and_caches_bundle() {
     assert_eq!(state.hourly_cursor, 0);
     assert_eq!(state.refresh_meta.consecutive_failures, 0);
 }
+
+#[test]
+fn stored_forecast_restores_as_stale_until_revalidated() {
+    let mut state = state();
+    let dir = tempdir().expect("create tempdir");
+    let store = crate::app::forecast_store::ForecastStore::new(dir.path());
+    let mut bundle = crate::test_support::sample_bundle();
+    bundle.fetched_at = chrono::Utc::now() - Duration::hours(2);
+    store.save(&bundle).expect("save bundle");
+    state.forecast_store = Some(store);
+
+    assert!(state.restore_stored_forecast(&crate::test_support::stockholm_location()));
+    assert_eq!(state.mode, AppMode::Ready);
+    assert!(state.cache_revalidating);
+    assert_eq!(state.refresh_meta.last_success, Some(bundle.fetched_at));
+    state.handle_tick_frame();
+    assert_eq!(state.refresh_meta.state, FreshnessState::Stale);
+
+    let (tx, _rx) = mpsc::channel(2);
+    state.handle_sync_event(
+        AppEvent::FetchSucceeded(crate::test_support::sample_bundle()),
+        &tx,
+    );
+    assert!(!state.cache_revalidating);
+    assert_eq!(state.refresh_meta.state, FreshnessState::Fresh);
+}
+
+#[test]
+fn fetch_succeeded_event_persists_bundle_to_store() {
+    let mut state = state();
+    let dir = tempdir().expect("create tempdir");
+    state.forecast_store = Some(crate::app::forecast_store::ForecastStore::new(dir.path()));
+    let (tx, _rx) = mpsc::channel(2);
+
+    state.handle_sync_event(
+        AppEvent::FetchSucceeded(crate::test_support::sample_bundle()),
+        &tx,
+    );
+
+    let mut fresh = self::state();
+    fresh.forecast_store = state.forecast_store.clone();
+    assert!(fresh.restore_stored_forecast(&crate::test_support::stockholm_location()));
+}
+
+#[test]
+fn restore_without_store_is_a_miss() {
+    let mut state = state();
+    assert!(!state.restore_stored_forecast(&crate::test_support::stockholm_location()));
+    assert!(state.weather.is_none());
+    assert!(!state.cache_revalidating);
+}
+
+#[tokio::test]
+async fn offline_mode_shows_cached_forecast_without_network() {
+    let mut cli = crate::test_support::state_test_cli();
+    cli.offline = true;
+    let mut state = AppState::new(&cli);
+    let dir = tempdir().expect("create tempdir");
+    let store = crate::app::forecast_store::ForecastStore::new(dir.path());
+    let mut bundle = crate::test_support::sample_bundle();
+    bundle.fetched_at = chrono::Utc::now() - Duration::days(9);
+    store.save(&bundle).expect("save bundle");
+    state.forecast_store = Some(store);
+    state.selected_location = Some(crate::test_support::stockholm_location());
+    let (tx, mut rx) = mpsc::channel(4);
+
+    state.start_fetch(&tx, &cli).await.expect("offline fetch");
+    assert!(matches!(rx.recv().await, Some(AppEvent::FetchStarted)));
+    state.handle_sync_event(AppEvent::FetchStarted, &tx);
+    let event = rx.recv().await.expect("cached bundle event");
+    state.handle_sync_event(event, &tx);
+
+    assert_eq!(state.mode, AppMode::Ready);
+    assert_eq!(state.refresh_meta.state, FreshnessState::Offline);
+    assert_eq!(state.refresh_meta.last_success, Some(bundle.fetched_at));
+    state.handle_tick_frame();
+    assert_eq!(state.refresh_meta.state, FreshnessState::Offline);
+}
+
+#[tokio::test]
+async fn offline_mode_failure_does_not_schedule_retry() {
+    let mut cli = crate::test_support::state_test_cli();
+    cli.offline = true;
+    let mut state = AppState::new(&cli);
+    let (tx, mut rx) = mpsc::channel(4);
+
+    state.start_fetch(&tx, &cli).await.expect("offline fetch");
+    assert!(matches!(rx.recv().await, Some(AppEvent::FetchStarted)));
+    let Some(AppEvent::FetchFailed(message)) = rx.recv().await else {
+        panic!("expected missing-location failure");
+    };
+    assert!(message.contains("Offline mode"));
+    state.handle_sync_event(AppEvent::FetchFailed(message), &tx);
+
+    assert_eq!(state.mode, AppMode::Error);
+    assert!(state.refresh_meta.next_retry_at.is_none());
+    assert_eq!(state.refresh_meta.consecutive_failures, 0);
+}
+
+fn screen_rows(state: &AppState) -> Vec<String> {
+    use ratatui::{Terminal, backend::TestBackend};
+    let cli = crate::test_support::state_test_cli();
+    let backend = TestBackend::new(state.viewport_width, state.viewport_height);
+    let mut terminal = Terminal::new(backend).expect("test terminal");
+    terminal
+        .draw(|frame| crate::ui::render(frame, state, &cli))
+        .expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+fn row_containing(state: &AppState, needle: &str) -> u16 {
+    let row = screen_rows(state)
+        .iter()
+        .position(|line| line.contains(needle))
+        .unwrap_or_else(|| panic!("no row contains {needle:?}"));
+    u16::try_from(row).expect("row fits u16")
+}
+
+fn mouse(kind: crossterm::event::MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(crossterm::event::MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn click(column: u16, row: u16) -> Event {
+    mouse(
+        crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Left),
+        column,
+        row,
+    )
+}
+
+fn mouse_state() -> AppState {
+    let mut state = weather_state_with_hours(24);
+    if let Some(bundle) = state.weather.as_mut() {
+        let first = bundle.daily[0].clone();
+        bundle.daily = (0..7)
+            .map(|offset| {
+                let mut day = first.clone();
+                day.date = first.date + Duration::days(offset);
+                day
+            })
+            .collect();
+    }
+    state.viewport_width = 100;
+    state.viewport_height = 40;
+    state
+}
+
+#[tokio::test]
+async fn clicking_a_daily_row_focuses_daily_and_selects_that_day() {
+    let mut state = mouse_state();
+    let cli = crate::test_support::state_test_cli();
+    let (tx, _rx) = mpsc::channel(8);
+    let weekday = state.weather.as_ref().expect("weather").daily[2]
+        .date
+        .format("%a")
+        .to_string();
+    let row = screen_rows(&state)
+        .iter()
+        .rposition(|line| line.contains(&weekday))
+        .expect("daily row on screen");
+
+    state
+        .handle_input(click(4, u16::try_from(row).expect("row")), &tx, &cli)
+        .await
+        .expect("click");
+    assert_eq!(state.panel_focus, PanelFocus::Daily);
+    assert_eq!(state.daily_cursor, 2);
+
+    state
+        .handle_input(Event::Key(KeyEvent::from(KeyCode::Down)), &tx, &cli)
+        .await
+        .expect("down");
+    assert_eq!(state.daily_cursor, 3);
+}
+
+#[tokio::test]
+async fn wheel_outside_daily_moves_hourly_cursor() {
+    let mut state = mouse_state();
+    let cli = crate::test_support::state_test_cli();
+    let (tx, _rx) = mpsc::channel(8);
+    let hourly = crate::ui::screen_panel_areas(ratatui::layout::Rect::new(0, 0, 100, 40), &state)
+        .expect("main view")
+        .hourly;
+
+    for _ in 0..3 {
+        state
+            .handle_input(
+                mouse(
+                    crossterm::event::MouseEventKind::ScrollDown,
+                    hourly.x + 2,
+                    hourly.y + 1,
+                ),
+                &tx,
+                &cli,
+            )
+            .await
+            .expect("scroll");
+    }
+    assert_eq!(state.hourly_cursor, 3);
+    assert_eq!(state.panel_focus, PanelFocus::Hourly);
+}
+
+#[tokio::test]
+async fn clicking_a_setting_selects_it_then_changes_it() {
+    let mut state = mouse_state();
+    let cli = crate::test_support::state_test_cli();
+    let (tx, _rx) = mpsc::channel(8);
+    state.open_settings_panel();
+    let row = row_containing(&state, "Units ");
+
+    state
+        .handle_input(click(30, row), &tx, &cli)
+        .await
+        .expect("select");
+    assert_eq!(state.settings_selected, SettingsSelection::Units);
+    assert_eq!(state.units, Units::Celsius);
+
+    state
+        .handle_input(click(30, row), &tx, &cli)
+        .await
+        .expect("change");
+    assert_eq!(state.units, Units::Fahrenheit);
+}
+
+#[tokio::test]
+async fn clicking_a_picker_entry_selects_it_then_switches() {
+    let mut state = mouse_state();
+    state.offline = true;
+    let cli = crate::test_support::state_test_cli();
+    let (tx, _rx) = mpsc::channel(8);
+    let mut oslo = crate::test_support::stockholm_location();
+    oslo.name = "Oslo".to_string();
+    oslo.latitude = 59.91;
+    state.push_recent_location(&crate::test_support::stockholm_location());
+    state.push_recent_location(&oslo);
+    state.open_city_picker();
+    let row = row_containing(&state, "2. Stockholm");
+
+    state
+        .handle_input(click(20, row), &tx, &cli)
+        .await
+        .expect("select");
+    assert_eq!(state.city_history_selected, 1);
+
+    state
+        .handle_input(click(20, row), &tx, &cli)
+        .await
+        .expect("switch");
+    assert_eq!(
+        state
+            .selected_location
+            .as_ref()
+            .map(|loc| loc.name.as_str()),
+        Some("Stockholm")
+    );
+}
@@ -1,4 +1,6 @@
 use super::*;
+use crate::app::{forecast_store::rebase_to_now, offline::load_offline_forecast};
+use crate::resilience::freshness::FreshnessState;
 use anyhow::Context;
 
 impl AppState {
@@ -11,6 +13,9 @@ impl AppState {
             return Ok(());
         }
         tx.send(AppEvent::FetchStarted).await?;
+        if self.offline {
+            return self.start_offline_fetch(tx, cli).await;
+        }
         if self.try_fetch_existing_location(tx).await {
             return Ok(());
         }
@@ -25,6 +30,17 @@ impl AppState {
         Ok(())
     }
 
+    async fn start_offline_fetch(&self, tx: &mpsc::Sender<AppEvent>, cli: &Cli) -> Result<()> {
+        match self.selected_location.clone() {
+            Some(location) => self.fetch_forecast(tx, location),
+            None => {
+                let message = crate::app::offline::missing_location_message(cli);
+                tx.send(AppEvent::FetchFailed(message)).await?;
+            }
+        }
+        Ok(())
+    }
+
     fn fetch_blocked(&self) -> bool {
         self.fetch_in_flight || self.mode == AppMode::SelectingLocation
     }
@@ -107,6 +123,10 @@ impl AppState {
     }
 
     pub(crate) fn fetch_forecast(&self, tx: &mpsc::Sender<AppEvent>, location: Location) {
+        if self.offline {
+            self.load_offline_forecast(tx, &location);
+            return;
+        }
         let client_result = self.build_forecast_client();
         let tx2 = tx.clone();
         tokio::spawn(async move {
@@ -128,17 +148,101 @@ impl AppState {
         });
     }
 
-    fn build_forecast_client(&self) -> Result<ForecastClient> {
-        match (&self.forecast_url_override, &self.air_quality_url_override) {
-            (Some(forecast_url), Some(air_quality_url)) => {
-                ForecastClient::with_urls(forecast_url.clone(), air_quality_url.clone())
-            }
-            (Some(forecast_url), None) => ForecastClient::with_base_url(forecast_url.clone()),
-            (None, Some(air_quality_url)) => {
-                Ok(ForecastClient::new()?.with_air_quality_url(air_quality_url.clone()))
-            }
-            (None, None) => ForecastClient::new(),
+    /// Fetches a pinned location for the dashboard in its own task, so every
+    /// card loads concurrently. Offline, the card is read from the disk cache.
+    pub(crate) fn fetch_dashboard_forecast(&self, tx: &mpsc::Sender<AppEvent>, location: Location) {
+        let tx2 = tx.clone();
+        if self.offline {
+            let event = match load_offline_forecast(self.forecast_store.as_ref(), &location) {
+                Ok(bundle) => AppEvent::DashboardFetched(bundle),
+                Err(err) => AppEvent::DashboardFetchFailed(location, err.to_string()),
+            };
+            tokio::spawn(async move {
+                let _ = tx2.send(event).await;
+            });
+            return;
+        }
+        let client_result = self.build_forecast_client();
+        tokio::spawn(async move {
+            let event = match client_result {
+                Ok(client) => match client.fetch(location.clone()).await {
+                    Ok(bundle) => AppEvent::DashboardFetched(bundle),
+                    Err(err) => AppEvent::DashboardFetchFailed(location, err.to_string()),
+                },
+                Err(err) => AppEvent::DashboardFetchFailed(location, err.to_string()),
+            };
+            let _ = tx2.send(event).await;
+        });
+    }
+
+    pub(crate) fn handle_dashboard_fetched(&mut self, bundle: ForecastBundle) {
+        let key: LocationKey = (&bundle.location).into();
+        self.dashboard.loading.remove(&key);
+        self.dashboard.errors.remove(&key);
+        if !self.offline
+            && let Some(store) = &self.forecast_store
+        {
+            let _ = store.save(&bundle);
         }
+        self.forecast_cache.put(key, bundle);
+    }
+
+    pub(crate) fn handle_dashboard_fetch_failed(&mut self, location: &Location, err: String) {
+        let key: LocationKey = location.into();
+        self.dashboard.loading.remove(&key);
+        self.dashboard.errors.insert(key, err);
+    }
+
+    fn load_offline_forecast(&self, tx: &mpsc::Sender<AppEvent>, location: &Location) {
+        let event = match load_offline_forecast(self.forecast_store.as_ref(), location) {
+            Ok(bundle) => AppEvent::FetchSucceeded(bundle),
+            Err(err) => AppEvent::FetchFailed(err.to_string()),
+        };
+        let tx2 = tx.clone();
+        tokio::spawn(async move {
+            let _ = tx2.send(event).await;
+        });
+    }
+
+    /// Shows the last bundle persisted for `location`, marked stale until the
+    /// revalidating fetch lands. Returns `false` when nothing usable is on disk.
+    pub(crate) fn restore_stored_forecast(&mut self, location: &Location) -> bool {
+        let Some(bundle) = self
+            .forecast_store
+            .as_ref()
+            .and_then(|store| store.load(location))
+        else {
+            return false;
+        };
+        self.show_cached_forecast(bundle, FreshnessState::Stale);
+        self.cache_revalidating = true;
+        true
+    }
+
+    /// Displays a bundle that came from disk rather than the network, keeping its
+    /// real `fetched_at` as the data age.
+    pub(crate) fn show_cached_forecast(&mut self, bundle: ForecastBundle, state: FreshnessState) {
+        let fetched_at = bundle.fetched_at;
+        self.handle_fetch_succeeded(rebase_to_now(bundle, chrono::Utc::now()));
+        self.refresh_meta.last_success = Some(fetched_at);
+        self.refresh_meta.state = state;
+        self.last_render_signature = Some(self.render_signature());
+    }
+
+    pub(crate) fn persist_fetched_forecast(&mut self, bundle: &ForecastBundle) {
+        self.cache_revalidating = false;
+        if let Some(store) = &self.forecast_store {
+            let _ = store.save(bundle);
+        }
+    }
+
+    fn build_forecast_client(&self) -> Result<ForecastProvider> {
+        ForecastProvider::build(
+            self.settings.provider,
+            self.forecast_url_override.as_deref(),
+            self.air_quality_url_override.as_deref(),
+        )
+        .map(|provider| provider.with_forecast_days(self.settings.forecast_days))
     }
 
     pub(crate) async fn handle_demo_action(
@@ -1,5 +1,9 @@
 mod city_picker;
+mod compare;
+mod dashboard;
+mod day_detail;
 mod settings;
+mod tabs;
 
 #[cfg(test)]
 mod tests {
@@ -155,7 +159,7 @@ mod tests {
     #[test]
     fn city_picker_index_helpers_track_visible_rows() {
         let mut state = state();
-        assert_eq!(state.visible_recent_count(), 0);
+        assert_eq!(state.visible_picker_count(), 0);
         assert_eq!(state.city_picker_action_index(), None);
         assert_eq!(state.city_picker_max_index(), 0);
 
@@ -165,7 +169,7 @@ mod tests {
             .push(RecentLocation::from_location(
                 &test_support::stockholm_location(),
             ));
-        assert_eq!(state.visible_recent_count(), 1);
+        assert_eq!(state.visible_picker_count(), 1);
         assert_eq!(state.city_picker_action_index(), Some(1));
         assert_eq!(state.city_picker_max_index(), 1);
     }
@@ -217,14 +221,14 @@ mod tests {
         let (tx, _rx) = mpsc::channel(2);
 
         // Ensure cache miss (cache is empty by default)
-        state.select_recent_city_by_index(&tx, 0);
+        state.select_picker_city_by_index(&tx, 0);
 
         assert!(state.city_picker_open);
         assert!(state.city_status.as_deref().unwrap().contains("Switching"));
     }
 
     #[tokio::test]
-    async fn select_recent_city_by_index_switches_to_selected_location() {
+    async fn select_picker_city_by_index_switches_to_selected_location() {
         let mut state = state();
         let mut berlin = test_support::stockholm_location();
         berlin.name = "Berlin".to_string();
@@ -236,7 +240,38 @@ mod tests {
             .push(RecentLocation::from_location(&berlin));
         let (tx, _rx) = mpsc::channel(2);
 
-        state.select_recent_city_by_index(&tx, 0);
+        state.select_picker_city_by_index(&tx, 0);
+        assert_eq!(
+            state.selected_location.as_ref().map(|l| l.name.as_str()),
+            Some("Berlin")
+        );
+    }
+
+    #[tokio::test]
+    async fn city_picker_lists_pinned_favorites_before_unpinned_recents() {
+        let mut state = state();
+        let stockholm = test_support::stockholm_location();
+        let mut berlin = stockholm.clone();
+        berlin.name = "Berlin".to_string();
+        berlin.latitude = 52.52;
+        state.push_recent_location(&stockholm);
+        state.push_recent_location(&berlin);
+        state
+            .settings
+            .pin_location(&stockholm, Some("home".to_string()));
+
+        let entries = state.city_picker_entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].0.name, "Stockholm");
+        assert_eq!(
+            entries[0].1.and_then(|pinned| pinned.alias.as_deref()),
+            Some("home")
+        );
+        assert_eq!(entries[1].0.name, "Berlin");
+        assert!(entries[1].1.is_none());
+
+        let (tx, _rx) = mpsc::channel(2);
+        state.select_picker_city_by_index(&tx, 1);
         assert_eq!(
             state.selected_location.as_ref().map(|l| l.name.as_str()),
             Some("Berlin")
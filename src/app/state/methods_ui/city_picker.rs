@@ -1,12 +1,12 @@
+use crate::app::settings::{PinnedLocation, RecentLocation};
 use crate::app::state::input::is_city_char;
-use crate::app::state::{AppEvent, AppMode, AppState, LocationKey, RecentLocation};
+use crate::app::state::{AppEvent, AppMode, AppState, LocationKey};
 use crate::cli::Cli;
 use crate::data::geocode::GeocodeClient;
 use crate::domain::weather::Location;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tokio::sync::mpsc;
 
-const HISTORY_MAX: usize = 12;
 const CITY_PICKER_VISIBLE_MAX: usize = 9;
 
 pub(crate) fn handle_vertical_nav(selected: &mut usize, max_index: usize, code: KeyCode) -> bool {
@@ -50,7 +50,7 @@ impl AppState {
                 self.clear_recent_locations();
             }
             KeyCode::Char(digit @ '1'..='9') => {
-                self.select_recent_city_by_index(tx, (digit as usize) - ('1' as usize));
+                self.select_picker_city_by_index(tx, (digit as usize) - ('1' as usize));
             }
             KeyCode::Char(ch) => {
                 self.push_city_query_char(key, ch);
@@ -83,13 +83,40 @@ impl AppState {
         }
     }
 
-    pub(crate) fn select_recent_city_by_index(
+    /// Pinned favorites first, then recent locations that are not pinned, capped
+    /// at one per number key. Pinned entries carry their alias.
+    pub(crate) fn city_picker_entries(&self) -> Vec<(&RecentLocation, Option<&PinnedLocation>)> {
+        let pinned = self
+            .settings
+            .pinned_locations
+            .iter()
+            .map(|pinned| (&pinned.place, Some(pinned)));
+        let recent = self
+            .settings
+            .recent_locations
+            .iter()
+            .filter(|recent| {
+                !self
+                    .settings
+                    .pinned_locations
+                    .iter()
+                    .any(|pinned| pinned.place.same_place(recent))
+            })
+            .map(|recent| (recent, None));
+        pinned.chain(recent).take(CITY_PICKER_VISIBLE_MAX).collect()
+    }
+
+    pub(crate) fn select_picker_city_by_index(
         &mut self,
         tx: &mpsc::Sender<AppEvent>,
         index: usize,
     ) {
-        if let Some(saved) = self.settings.recent_locations.get(index).cloned() {
-            self.switch_to_location(tx, saved.to_location());
+        let location = self
+            .city_picker_entries()
+            .get(index)
+            .map(|(place, _)| place.to_location());
+        if let Some(location) = location {
+            self.switch_to_location(tx, location);
         }
     }
 
@@ -104,7 +131,7 @@ impl AppState {
             self.clear_recent_locations();
             return;
         }
-        self.select_recent_city_by_index(tx, self.city_history_selected);
+        self.select_picker_city_by_index(tx, self.city_history_selected);
     }
 
     pub(crate) fn push_city_query_char(&mut self, key: KeyEvent, ch: char) {
@@ -119,15 +146,10 @@ impl AppState {
     }
 
     pub(crate) fn push_recent_location(&mut self, location: &Location) {
-        let entry = RecentLocation::from_location(location);
-        self.settings
-            .recent_locations
-            .retain(|existing| !existing.same_place(&entry));
-        self.settings.recent_locations.insert(0, entry);
-        self.settings.recent_locations.truncate(HISTORY_MAX);
+        self.settings.remember_location(location);
         self.city_history_selected = self
             .city_history_selected
-            .min(self.settings.recent_locations.len().saturating_sub(1));
+            .min(self.visible_picker_count().saturating_sub(1));
     }
 
     pub(crate) fn clear_recent_locations(&mut self) {
@@ -137,20 +159,18 @@ impl AppState {
             return;
         }
         self.settings.recent_locations.clear();
+        self.sync_location_tabs();
         self.city_history_selected = 0;
         self.city_status = Some("Cleared all recent locations".to_string());
         self.persist_settings();
     }
 
-    pub(crate) fn visible_recent_count(&self) -> usize {
-        self.settings
-            .recent_locations
-            .len()
-            .min(CITY_PICKER_VISIBLE_MAX)
+    pub(crate) fn visible_picker_count(&self) -> usize {
+        self.city_picker_entries().len()
     }
 
     pub(crate) fn city_picker_action_index(&self) -> Option<usize> {
-        let visible = self.visible_recent_count();
+        let visible = self.visible_picker_count();
         if visible > 0 { Some(visible) } else { None }
     }
 
@@ -173,30 +193,46 @@ impl AppState {
         }
         self.last_render_signature = Some(self.render_signature());
 
+        if self.offline {
+            self.fetch_forecast(tx, location);
+            return;
+        }
         let key: LocationKey = (&location).into();
         if let Some(bundle) = self.forecast_cache.get(&key).cloned() {
             self.handle_fetch_succeeded(bundle.clone());
             if (chrono::Utc::now() - bundle.fetched_at) > chrono::Duration::minutes(10) {
-                let tx2 = tx.clone();
-                tokio::spawn(async move {
-                    let _ = tx2.send(AppEvent::FetchStarted).await;
-                });
-                self.fetch_forecast(tx, location);
+                self.revalidate_in_background(tx, location);
             }
             return;
         }
+        if self.restore_stored_forecast(&location) {
+            self.revalidate_in_background(tx, location);
+            return;
+        }
 
         self.mode = AppMode::Loading;
         self.city_status = Some(format!("Switching to {}", location.display_name()));
         self.fetch_forecast(tx, location);
     }
 
+    fn revalidate_in_background(&self, tx: &mpsc::Sender<AppEvent>, location: Location) {
+        let tx2 = tx.clone();
+        tokio::spawn(async move {
+            let _ = tx2.send(AppEvent::FetchStarted).await;
+        });
+        self.fetch_forecast(tx, location);
+    }
+
     pub(crate) fn start_city_search(
         &mut self,
         tx: &mpsc::Sender<AppEvent>,
         city: String,
         country_code: Option<String>,
     ) {
+        if self.offline {
+            self.search_saved_locations_offline(tx, &city);
+            return;
+        }
         self.pending_locations.clear();
         self.mode = AppMode::Loading;
         self.fetch_in_flight = true;
@@ -223,4 +259,18 @@ impl AppState {
             }
         });
     }
+
+    fn search_saved_locations_offline(&mut self, tx: &mpsc::Sender<AppEvent>, city: &str) {
+        match crate::app::offline::find_saved_location(&self.settings, city) {
+            Some(location) => {
+                self.city_picker_open = false;
+                self.switch_to_location(tx, location);
+            }
+            None => {
+                self.city_status = Some(format!(
+                    "Offline: only pinned and recent locations are available ('{city}' not found)"
+                ));
+            }
+        }
+    }
 }
@@ -0,0 +1,198 @@
+use crate::app::state::{AppEvent, AppState, LocationKey};
+use crate::data::geocode::GeocodeClient;
+use crate::domain::weather::{ForecastBundle, GeocodeResolution, Location};
+use crossterm::event::KeyCode;
+use tokio::sync::mpsc;
+
+impl AppState {
+    /// Opens the comparison view against `query`: a pinned alias or name, a
+    /// recent location, or, when online, any city the geocoder knows.
+    pub(crate) fn open_compare(
+        &mut self,
+        tx: &mpsc::Sender<AppEvent>,
+        query: &str,
+        country_code: Option<String>,
+    ) {
+        self.dashboard.open = false;
+        self.compare.open = true;
+        self.compare.location = None;
+        if let Some(location) = crate::app::offline::find_saved_location(&self.settings, query) {
+            self.set_compare_location(tx, location);
+            return;
+        }
+        if self.offline {
+            self.compare.status = Some(format!(
+                "Offline: only pinned and recent locations can be compared ('{query}' not found)"
+            ));
+            return;
+        }
+        self.compare.status = Some(format!("Searching {query}..."));
+        let query = query.to_string();
+        let tx2 = tx.clone();
+        tokio::spawn(async move {
+            let event = match GeocodeClient::new() {
+                Ok(geocoder) => match geocoder.resolve(query, country_code).await {
+                    Ok(resolution) => AppEvent::CompareResolved(resolution),
+                    Err(err) => AppEvent::CompareLookupFailed(err.to_string()),
+                },
+                Err(err) => AppEvent::CompareLookupFailed(err.to_string()),
+            };
+            let _ = tx2.send(event).await;
+        });
+    }
+
+    fn set_compare_location(&mut self, tx: &mpsc::Sender<AppEvent>, location: Location) {
+        self.compare.status = None;
+        self.compare.location = Some(location);
+        self.refresh_compare(tx, false);
+    }
+
+    pub(crate) fn refresh_compare(&mut self, tx: &mpsc::Sender<AppEvent>, force: bool) {
+        if let Some(location) = self.compare.location.clone() {
+            self.prefetch_forecasts(tx, vec![location], force);
+        }
+    }
+
+    /// Takes the best match when the city name is ambiguous; the view has no
+    /// room for a chooser and `:compare` accepts a fuller name.
+    pub(crate) fn handle_compare_resolved(
+        &mut self,
+        tx: &mpsc::Sender<AppEvent>,
+        resolution: GeocodeResolution,
+    ) {
+        if !self.compare.open {
+            return;
+        }
+        match resolution {
+            GeocodeResolution::Selected(location) => self.set_compare_location(tx, location),
+            GeocodeResolution::NeedsDisambiguation(mut locations) if !locations.is_empty() => {
+                self.set_compare_location(tx, locations.swap_remove(0));
+            }
+            GeocodeResolution::NeedsDisambiguation(_) => {
+                self.compare.status = Some("No locations matched".to_string());
+            }
+            GeocodeResolution::NotFound(city) => {
+                self.compare.status = Some(format!("No results for '{city}'"));
+            }
+        }
+    }
+
+    pub(crate) fn handle_compare_lookup_failed(&mut self, err: String) {
+        if self.compare.open {
+            self.compare.status = Some(format!("Lookup failed: {err}"));
+        }
+    }
+
+    pub(crate) fn close_compare(&mut self) {
+        self.compare = crate::app::state::CompareState::default();
+    }
+
+    /// The second location's forecast, once it has arrived.
+    #[must_use]
+    pub fn compare_bundle(&self) -> Option<&ForecastBundle> {
+        let key: LocationKey = self.compare.location.as_ref()?.into();
+        self.forecast_cache.peek(&key)
+    }
+
+    /// Esc leaves the comparison. `r` also refreshes the second location but
+    /// reports the key as unhandled so the main refresh still runs.
+    pub(crate) fn handle_compare_key(
+        &mut self,
+        code: KeyCode,
+        tx: &mpsc::Sender<AppEvent>,
+    ) -> bool {
+        match code {
+            KeyCode::Esc => {
+                self.close_compare();
+                true
+            }
+            KeyCode::Char('r' | 'R') => {
+                self.refresh_compare(tx, true);
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::state::{AppEvent, AppState, LocationKey};
+    use crate::domain::weather::{GeocodeResolution, Location};
+    use crate::test_support::{sample_bundle, state_test_cli, stockholm_location};
+    use crossterm::event::KeyCode;
+    use tokio::sync::mpsc;
+
+    fn oslo() -> Location {
+        Location {
+            name: "Oslo".to_string(),
+            latitude: 59.91,
+            longitude: 10.75,
+            ..stockholm_location()
+        }
+    }
+
+    #[tokio::test]
+    async fn compare_resolves_pinned_alias_and_prefetches_it() {
+        let mut state = AppState::new(&state_test_cli());
+        state.offline = true;
+        state.dashboard.open = true;
+        state
+            .settings
+            .pin_location(&oslo(), Some("cabin".to_string()));
+        let (tx, _rx) = mpsc::channel::<AppEvent>(8);
+
+        state.open_compare(&tx, "cabin", None);
+        assert!(state.compare.open);
+        assert!(!state.dashboard.open);
+        assert_eq!(
+            state.compare.location.as_ref().map(|l| l.name.as_str()),
+            Some("Oslo")
+        );
+        let key: LocationKey = (&oslo()).into();
+        assert!(state.dashboard.loading.contains(&key));
+        assert!(state.compare_bundle().is_none());
+
+        let mut bundle = sample_bundle();
+        bundle.location = oslo();
+        state.forecast_cache.put(key, bundle);
+        assert!(state.compare_bundle().is_some());
+    }
+
+    #[tokio::test]
+    async fn offline_compare_reports_unknown_city() {
+        let mut state = AppState::new(&state_test_cli());
+        state.offline = true;
+        let (tx, _rx) = mpsc::channel::<AppEvent>(8);
+        state.open_compare(&tx, "Atlantis", None);
+        assert!(state.compare.location.is_none());
+        assert!(
+            state
+                .compare
+                .status
+                .as_deref()
+                .is_some_and(|status| status.contains("Atlantis"))
+        );
+    }
+
+    #[tokio::test]
+    async fn ambiguous_lookup_takes_first_match_and_esc_closes() {
+        let mut state = AppState::new(&state_test_cli());
+        state.offline = true;
+        state.compare.open = true;
+        let (tx, _rx) = mpsc::channel::<AppEvent>(8);
+
+        state.handle_compare_resolved(
+            &tx,
+            GeocodeResolution::NeedsDisambiguation(vec![oslo(), stockholm_location()]),
+        );
+        assert_eq!(
+            state.compare.location.as_ref().map(|l| l.name.as_str()),
+            Some("Oslo")
+        );
+        assert!(!state.handle_compare_key(KeyCode::Char('r'), &tx));
+        assert!(state.handle_compare_key(KeyCode::Esc, &tx));
+        assert!(!state.compare.open);
+        assert!(state.compare.location.is_none());
+    }
+}
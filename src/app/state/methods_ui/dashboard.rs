@@ -0,0 +1,225 @@
+use crate::app::state::{AppEvent, AppState, LocationKey};
+use crate::domain::weather::Location;
+use crossterm::event::KeyCode;
+use tokio::sync::mpsc;
+
+/// Cached forecasts younger than this are shown without refetching.
+const DASHBOARD_FRESH_MINUTES: i64 = 10;
+
+impl AppState {
+    #[must_use]
+    pub fn pinned_locations(&self) -> Vec<Location> {
+        self.settings
+            .pinned_locations
+            .iter()
+            .map(|pinned| pinned.place.to_location())
+            .collect()
+    }
+
+    pub(crate) fn open_dashboard(&mut self, tx: &mpsc::Sender<AppEvent>) {
+        self.close_compare();
+        self.dashboard.open = true;
+        self.clamp_dashboard_selection();
+        self.refresh_dashboard(tx, false);
+    }
+
+    pub(crate) fn refresh_dashboard(&mut self, tx: &mpsc::Sender<AppEvent>, force: bool) {
+        self.prefetch_forecasts(tx, self.pinned_locations(), force);
+    }
+
+    /// Starts a background fetch for every location that has no fresh cached
+    /// forecast, or for all of them when `force` is set. Locations already
+    /// loading are skipped. Results only fill `forecast_cache`.
+    pub(crate) fn prefetch_forecasts(
+        &mut self,
+        tx: &mpsc::Sender<AppEvent>,
+        locations: Vec<Location>,
+        force: bool,
+    ) {
+        let now = chrono::Utc::now();
+        for location in locations {
+            let key: LocationKey = (&location).into();
+            if self.dashboard.loading.contains(&key) {
+                continue;
+            }
+            let fresh = self.forecast_cache.peek(&key).is_some_and(|bundle| {
+                now - bundle.fetched_at < chrono::Duration::minutes(DASHBOARD_FRESH_MINUTES)
+            });
+            if fresh && !force {
+                continue;
+            }
+            self.dashboard.loading.insert(key);
+            self.fetch_dashboard_forecast(tx, location);
+        }
+    }
+
+    /// Leaves the dashboard for the full view of the card at `index`.
+    pub(crate) fn open_dashboard_card(&mut self, tx: &mpsc::Sender<AppEvent>, index: usize) {
+        if let Some(location) = self.pinned_locations().into_iter().nth(index) {
+            self.dashboard.open = false;
+            self.switch_to_location(tx, location);
+        }
+    }
+
+    /// Pins the location on screen, or unpins it when it is already pinned.
+    pub(crate) fn toggle_pin_current_location(&mut self) {
+        let Some(location) = self.selected_location.clone() else {
+            return;
+        };
+        if !self.settings.unpin_location(&location) && !self.settings.pin_location(&location, None)
+        {
+            return;
+        }
+        self.clamp_dashboard_selection();
+        self.sync_location_tabs();
+        self.persist_settings();
+    }
+
+    fn unpin_selected_card(&mut self) {
+        if self.dashboard.selected < self.settings.pinned_locations.len() {
+            self.settings
+                .pinned_locations
+                .remove(self.dashboard.selected);
+            self.clamp_dashboard_selection();
+            self.sync_location_tabs();
+            self.persist_settings();
+        }
+    }
+
+    fn clamp_dashboard_selection(&mut self) {
+        let last = self.settings.pinned_locations.len().saturating_sub(1);
+        self.dashboard.selected = self.dashboard.selected.min(last);
+    }
+
+    /// Keys the dashboard grid owns. Anything else falls through to the main view
+    /// bindings, so quit, settings, help and the city picker keep working.
+    pub(crate) fn handle_dashboard_key(
+        &mut self,
+        code: KeyCode,
+        tx: &mpsc::Sender<AppEvent>,
+    ) -> bool {
+        let count = self.settings.pinned_locations.len();
+        let columns = crate::ui::widgets::dashboard::grid_columns(self.viewport_width);
+        let selected = self.dashboard.selected;
+        match code {
+            KeyCode::Left => self.dashboard.selected = selected.saturating_sub(1),
+            KeyCode::Right => self.dashboard.selected = (selected + 1).min(count.saturating_sub(1)),
+            KeyCode::Up => self.dashboard.selected = selected.saturating_sub(columns),
+            KeyCode::Down if selected + columns < count => self.dashboard.selected += columns,
+            KeyCode::Down => {}
+            KeyCode::Enter => self.open_dashboard_card(tx, selected),
+            KeyCode::Char(digit @ '1'..='9') => {
+                self.open_dashboard_card(tx, (digit as usize) - ('1' as usize));
+            }
+            KeyCode::Char('r' | 'R') => self.refresh_dashboard(tx, true),
+            KeyCode::Char('x' | 'X') | KeyCode::Delete => self.unpin_selected_card(),
+            KeyCode::Char('d' | 'D') | KeyCode::Esc => self.dashboard.open = false,
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::state::{AppEvent, AppState, LocationKey};
+    use crate::test_support::{sample_bundle, state_test_cli, stockholm_location};
+    use crossterm::event::KeyCode;
+    use tokio::sync::mpsc;
+
+    fn pinned_state(count: usize) -> AppState {
+        let mut state = AppState::new(&state_test_cli());
+        for index in 0..count {
+            let mut location = stockholm_location();
+            location.name = format!("City {index}");
+            location.latitude += index as f64;
+            state.settings.pin_location(&location, None);
+        }
+        state
+    }
+
+    #[tokio::test]
+    async fn refresh_dashboard_skips_fresh_cache_unless_forced() {
+        let mut state = pinned_state(0);
+        state.settings.pin_location(&stockholm_location(), None);
+        let bundle = sample_bundle();
+        let key: LocationKey = (&bundle.location).into();
+        let mut fresh = bundle.clone();
+        fresh.fetched_at = chrono::Utc::now();
+        state.forecast_cache.put(key.clone(), fresh);
+        let (tx, _rx) = mpsc::channel(8);
+
+        state.refresh_dashboard(&tx, false);
+        assert!(state.dashboard.loading.is_empty());
+
+        state.offline = true;
+        state.refresh_dashboard(&tx, true);
+        assert!(state.dashboard.loading.contains(&key));
+    }
+
+    #[tokio::test]
+    async fn dashboard_fetch_events_update_cache_and_errors() {
+        let mut state = pinned_state(0);
+        let bundle = sample_bundle();
+        let key: LocationKey = (&bundle.location).into();
+        state.dashboard.loading.insert(key.clone());
+        state.handle_dashboard_fetch_failed(&bundle.location, "timeout".to_string());
+        assert_eq!(
+            state.dashboard.errors.get(&key).map(String::as_str),
+            Some("timeout")
+        );
+
+        let (tx, _rx) = mpsc::channel(8);
+        state
+            .handle_event(AppEvent::DashboardFetched(bundle), &tx, &state_test_cli())
+            .await
+            .expect("dashboard event");
+        assert!(state.dashboard.errors.is_empty());
+        assert!(state.forecast_cache.contains(&key));
+        assert!(state.weather.is_none());
+    }
+
+    #[test]
+    fn dashboard_keys_move_selection_across_grid() {
+        let mut state = pinned_state(5);
+        state.viewport_width = 80;
+        let (tx, _rx) = mpsc::channel(8);
+        let columns = crate::ui::widgets::dashboard::grid_columns(80);
+
+        assert!(state.handle_dashboard_key(KeyCode::Right, &tx));
+        assert_eq!(state.dashboard.selected, 1);
+        assert!(state.handle_dashboard_key(KeyCode::Down, &tx));
+        assert_eq!(state.dashboard.selected, (1 + columns).min(4));
+        assert!(state.handle_dashboard_key(KeyCode::Char('x'), &tx));
+        assert_eq!(state.settings.pinned_locations.len(), 4);
+        assert!(!state.handle_dashboard_key(KeyCode::Char('q'), &tx));
+        assert!(state.handle_dashboard_key(KeyCode::Esc, &tx));
+        assert!(!state.dashboard.open);
+    }
+
+    #[tokio::test]
+    async fn opening_a_card_leaves_dashboard_for_that_location() {
+        let mut state = pinned_state(2);
+        state.dashboard.open = true;
+        let (tx, _rx) = mpsc::channel(8);
+        state.open_dashboard_card(&tx, 1);
+        assert!(!state.dashboard.open);
+        assert_eq!(
+            state
+                .selected_location
+                .as_ref()
+                .map(|loc| loc.name.as_str()),
+            Some("City 1")
+        );
+    }
+
+    #[test]
+    fn toggle_pin_adds_then_removes_current_location() {
+        let mut state = pinned_state(0);
+        state.selected_location = Some(stockholm_location());
+        state.toggle_pin_current_location();
+        assert_eq!(state.settings.pinned_locations.len(), 1);
+        state.toggle_pin_current_location();
+        assert!(state.settings.pinned_locations.is_empty());
+    }
+}
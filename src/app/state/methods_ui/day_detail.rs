@@ -0,0 +1,60 @@
+use crate::app::state::AppState;
+use crossterm::event::KeyCode;
+
+impl AppState {
+    /// Opens the drill-down for the selected day once a forecast is loaded.
+    pub(crate) fn open_day_detail(&mut self) {
+        if self.weather.is_some() {
+            self.day_detail_open = true;
+        }
+    }
+
+    /// Arrows step through the days without leaving the detail; `Esc`,
+    /// `Enter` and `Backspace` return to the main view.
+    pub(crate) fn handle_day_detail_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Backspace => self.day_detail_open = false,
+            KeyCode::Left | KeyCode::Up => self.move_daily_cursor(-1),
+            KeyCode::Right | KeyCode::Down => self.move_daily_cursor(1),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::state::AppState;
+    use crate::test_support::{sample_bundle, state_test_cli};
+    use crossterm::event::KeyCode;
+
+    #[test]
+    fn day_detail_needs_forecast_and_arrows_step_days() {
+        let mut state = AppState::new(&state_test_cli());
+        state.open_day_detail();
+        assert!(!state.day_detail_open);
+
+        let mut bundle = sample_bundle();
+        let first = bundle.daily[0].clone();
+        bundle.daily = (0..3)
+            .map(|offset| {
+                let mut day = first.clone();
+                day.date += chrono::Duration::days(offset);
+                day
+            })
+            .collect();
+        state.weather = Some(bundle);
+        state.open_day_detail();
+        assert!(state.day_detail_open);
+
+        state.handle_day_detail_key(KeyCode::Right);
+        state.handle_day_detail_key(KeyCode::Right);
+        state.handle_day_detail_key(KeyCode::Right);
+        assert_eq!(state.daily_cursor, 2);
+        state.handle_day_detail_key(KeyCode::Left);
+        assert_eq!(state.daily_cursor, 1);
+
+        state.handle_day_detail_key(KeyCode::Esc);
+        assert!(!state.day_detail_open);
+        assert_eq!(state.daily_cursor, 1);
+    }
+}
@@ -25,8 +25,9 @@ impl AppState {
             self.settings_open = false;
             return Ok(());
         }
+        let horizon = self.settings.forecast_days;
         if self.handle_settings_nav_key(code) {
-            return Ok(());
+            return self.refetch_if_horizon_changed(horizon, tx, cli).await;
         }
         if matches!(code, KeyCode::Enter) {
             self.handle_settings_enter(tx, cli).await?;
@@ -34,6 +35,19 @@ impl AppState {
         Ok(())
     }
 
+    /// A new forecast horizon needs a new forecast, not just a redraw.
+    async fn refetch_if_horizon_changed(
+        &mut self,
+        previous: u8,
+        tx: &mpsc::Sender<AppEvent>,
+        cli: &Cli,
+    ) -> Result<()> {
+        if self.settings.forecast_days != previous {
+            self.start_fetch(tx, cli).await?;
+        }
+        Ok(())
+    }
+
     fn handle_settings_nav_key(&mut self, code: KeyCode) -> bool {
         match code {
             KeyCode::Up => {
@@ -64,7 +78,11 @@ impl AppState {
         match self.settings_selected {
             SettingsSelection::RefreshNow => self.start_fetch(tx, cli).await?,
             SettingsSelection::Close => self.settings_open = false,
-            _ => self.adjust_selected_setting(1),
+            _ => {
+                let horizon = self.settings.forecast_days;
+                self.adjust_selected_setting(1);
+                self.refetch_if_horizon_changed(horizon, tx, cli).await?;
+            }
         }
         Ok(())
     }
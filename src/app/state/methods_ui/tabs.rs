@@ -0,0 +1,159 @@
+use crate::app::settings::RecentLocation;
+use crate::app::state::{AppEvent, AppState};
+use crate::domain::weather::Location;
+use tokio::sync::mpsc;
+
+impl AppState {
+    /// Rebuilds the tab bar from the city picker entries while keeping tabs that
+    /// are still present in their old order. Without this, every switch would
+    /// move the chosen city to the front of the recent list and reshuffle the
+    /// tabs under the cursor. Pinned tabs always lead, in pinned order.
+    pub(crate) fn sync_location_tabs(&mut self) {
+        let entries: Vec<(RecentLocation, bool)> = self
+            .city_picker_entries()
+            .into_iter()
+            .map(|(place, pinned)| (place.clone(), pinned.is_some()))
+            .collect();
+        let mut tabs: Vec<RecentLocation> = entries
+            .iter()
+            .filter(|(_, pinned)| *pinned)
+            .map(|(place, _)| place.clone())
+            .collect();
+        for kept in &self.location_tabs {
+            let still_recent = entries
+                .iter()
+                .any(|(place, pinned)| !pinned && place.same_place(kept));
+            if still_recent && !tabs.iter().any(|tab| tab.same_place(kept)) {
+                tabs.push(kept.clone());
+            }
+        }
+        for (place, _) in entries {
+            if !tabs.iter().any(|tab| tab.same_place(&place)) {
+                tabs.push(place);
+            }
+        }
+        self.location_tabs = tabs;
+    }
+
+    /// Index of the tab showing the selected location, if it has one.
+    #[must_use]
+    pub fn active_location_tab(&self) -> Option<usize> {
+        let selected = RecentLocation::from_location(self.selected_location.as_ref()?);
+        self.location_tabs
+            .iter()
+            .position(|tab| tab.same_place(&selected))
+    }
+
+    /// Switches `step` tabs left or right, wrapping around, then prefetches the
+    /// new tab's neighbors so the next step is served from `forecast_cache`.
+    pub(crate) fn cycle_location_tab(&mut self, tx: &mpsc::Sender<AppEvent>, step: isize) {
+        let count = self.location_tabs.len();
+        if count < 2 {
+            return;
+        }
+        let next = match self.active_location_tab() {
+            Some(index) => (index as isize + step).rem_euclid(count as isize) as usize,
+            None if step < 0 => count - 1,
+            None => 0,
+        };
+        let location = self.location_tabs[next].to_location();
+        self.switch_to_location(tx, location);
+        self.prefetch_tab_neighbors(tx);
+    }
+
+    /// Fetches the tabs either side of the active one in the background.
+    pub(crate) fn prefetch_tab_neighbors(&mut self, tx: &mpsc::Sender<AppEvent>) {
+        let count = self.location_tabs.len();
+        let Some(index) = self.active_location_tab().filter(|_| count >= 2) else {
+            return;
+        };
+        let mut neighbors: Vec<Location> = vec![
+            self.location_tabs[(index + 1) % count].to_location(),
+            self.location_tabs[(index + count - 1) % count].to_location(),
+        ];
+        neighbors.dedup_by(|a, b| a.display_name() == b.display_name());
+        self.prefetch_forecasts(tx, neighbors, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::state::{AppEvent, AppState, LocationKey};
+    use crate::domain::weather::Location;
+    use crate::test_support::{state_test_cli, stockholm_location};
+    use tokio::sync::mpsc;
+
+    fn city(name: &str, latitude: f64) -> Location {
+        Location {
+            name: name.to_string(),
+            latitude,
+            ..stockholm_location()
+        }
+    }
+
+    fn tabbed_state() -> AppState {
+        let mut state = AppState::new(&state_test_cli());
+        state.offline = true;
+        for location in [city("Oslo", 10.0), city("Berlin", 20.0)] {
+            state.push_recent_location(&location);
+        }
+        state
+            .settings
+            .pin_location(&city("Stockholm", 30.0), Some("home".to_string()));
+        state.sync_location_tabs();
+        state
+    }
+
+    fn tab_names(state: &AppState) -> Vec<&str> {
+        state
+            .location_tabs
+            .iter()
+            .map(|tab| tab.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn tabs_put_pinned_first_and_keep_order_when_recents_reshuffle() {
+        let mut state = tabbed_state();
+        assert_eq!(tab_names(&state), ["Stockholm", "Berlin", "Oslo"]);
+
+        state.push_recent_location(&city("Oslo", 10.0));
+        state.sync_location_tabs();
+        assert_eq!(tab_names(&state), ["Stockholm", "Berlin", "Oslo"]);
+
+        state.push_recent_location(&city("Paris", 40.0));
+        state.sync_location_tabs();
+        assert_eq!(tab_names(&state), ["Stockholm", "Berlin", "Oslo", "Paris"]);
+    }
+
+    #[tokio::test]
+    async fn cycling_wraps_and_prefetches_neighbors() {
+        let mut state = tabbed_state();
+        let (tx, _rx) = mpsc::channel::<AppEvent>(16);
+        state.selected_location = Some(city("Stockholm", 30.0));
+
+        state.cycle_location_tab(&tx, -1);
+        assert_eq!(state.active_location_tab(), Some(2));
+        assert_eq!(
+            state.selected_location.as_ref().map(|l| l.name.as_str()),
+            Some("Oslo")
+        );
+        let berlin: LocationKey = (&city("Berlin", 20.0)).into();
+        let home: LocationKey = (&city("Stockholm", 30.0)).into();
+        assert!(state.dashboard.loading.contains(&berlin));
+        assert!(state.dashboard.loading.contains(&home));
+
+        state.cycle_location_tab(&tx, 1);
+        assert_eq!(state.active_location_tab(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn single_tab_does_not_cycle() {
+        let mut state = AppState::new(&state_test_cli());
+        state.push_recent_location(&city("Oslo", 10.0));
+        state.sync_location_tabs();
+        let (tx, _rx) = mpsc::channel::<AppEvent>(4);
+        state.cycle_location_tab(&tx, 1);
+        assert!(state.selected_location.is_none());
+    }
+}
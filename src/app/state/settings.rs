@@ -37,16 +37,21 @@ mod tests {
     fn settings_entries_include_actions_and_editable_rows() {
         let state = state();
         let entries = state.settings_entries();
-        assert_eq!(entries.len(), 12);
+        assert_eq!(entries.len(), 17);
         assert!(entries[0].editable);
         assert_eq!(entries[0].label, "Theme");
         assert_eq!(entries[1].label, "Motion");
         assert_eq!(entries[4].label, "Hero Visual");
         assert_eq!(entries[8].label, "Units");
-        assert_eq!(entries[10].label, "Action");
-        assert!(!entries[10].editable);
-        assert_eq!(entries[11].label, "Panel");
-        assert!(!entries[11].editable);
+        assert_eq!(entries[9].label, "Wind");
+        assert_eq!(entries[9].value, "m/s");
+        assert_eq!(entries[12].label, "Distance");
+        assert_eq!(entries[14].label, "Forecast");
+        assert!(entries[14].editable);
+        assert_eq!(entries[15].label, "Action");
+        assert!(!entries[15].editable);
+        assert_eq!(entries[16].label, "Panel");
+        assert!(!entries[16].editable);
     }
 
     #[test]
@@ -120,6 +125,14 @@ mod tests {
             1
         ));
         assert_ne!(state.settings.inline_hints, inline_hints_before);
+
+        let wind_before = state.settings.wind_unit;
+        assert!(adjust_setting_selection(
+            &mut state,
+            SettingsSelection::WindUnit,
+            1
+        ));
+        assert_ne!(state.settings.wind_unit, wind_before);
     }
 
     #[test]
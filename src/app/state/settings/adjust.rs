@@ -1,18 +1,30 @@
 use super::super::AppState;
 use super::super::SettingsEntry;
 use super::options::{
-    HOURLY_VIEW_OPTIONS, REFRESH_OPTIONS, SETTINGS_ORDER, SettingsSelection, THEME_LABELS,
-    THEME_OPTIONS,
+    FORECAST_DAYS_OPTIONS, HOURLY_VIEW_OPTIONS, REFRESH_OPTIONS, SETTINGS_ORDER, SettingsSelection,
+    THEME_LABELS, THEME_OPTIONS,
 };
 use crate::cli::{HeroVisualArg, IconMode, ThemeArg};
-use crate::domain::weather::{HourlyViewMode, Units};
+use crate::domain::weather::{
+    DistanceUnit, HourlyViewMode, PrecipUnit, PressureUnit, Units, WindUnit,
+};
 use crate::ui::animation::MotionMode;
 use crate::ui::theme::theme_preview;
 
 type SettingAdjuster = fn(&mut AppState, i8) -> bool;
 
-const SETTING_ADJUSTERS: [(SettingsSelection, SettingAdjuster); 9] = [
+const SETTING_ADJUSTERS: [(SettingsSelection, SettingAdjuster); 14] = [
     (SettingsSelection::Units, adjust_units_setting),
+    (SettingsSelection::WindUnit, adjust_wind_unit_setting),
+    (
+        SettingsSelection::PressureUnit,
+        adjust_pressure_unit_setting,
+    ),
+    (SettingsSelection::PrecipUnit, adjust_precip_unit_setting),
+    (
+        SettingsSelection::DistanceUnit,
+        adjust_distance_unit_setting,
+    ),
     (SettingsSelection::Theme, adjust_theme_setting),
     (SettingsSelection::Motion, adjust_motion_setting),
     (SettingsSelection::Icons, adjust_icon_setting),
@@ -24,6 +36,10 @@ const SETTING_ADJUSTERS: [(SettingsSelection, SettingAdjuster); 9] = [
         SettingsSelection::RefreshInterval,
         adjust_refresh_interval_setting,
     ),
+    (
+        SettingsSelection::ForecastDays,
+        adjust_forecast_days_setting,
+    ),
 ];
 
 pub(crate) fn adjust_setting_selection(
@@ -61,6 +77,30 @@ fn adjust_units_setting(state: &mut AppState, direction: i8) -> bool {
     )
 }
 
+fn adjust_wind_unit_setting(state: &mut AppState, direction: i8) -> bool {
+    adjust_cycle_setting(&mut state.settings.wind_unit, &WindUnit::ALL, direction)
+}
+
+fn adjust_pressure_unit_setting(state: &mut AppState, direction: i8) -> bool {
+    adjust_cycle_setting(
+        &mut state.settings.pressure_unit,
+        &PressureUnit::ALL,
+        direction,
+    )
+}
+
+fn adjust_precip_unit_setting(state: &mut AppState, direction: i8) -> bool {
+    adjust_cycle_setting(&mut state.settings.precip_unit, &PrecipUnit::ALL, direction)
+}
+
+fn adjust_distance_unit_setting(state: &mut AppState, direction: i8) -> bool {
+    adjust_cycle_setting(
+        &mut state.settings.distance_unit,
+        &DistanceUnit::ALL,
+        direction,
+    )
+}
+
 fn adjust_theme_setting(state: &mut AppState, direction: i8) -> bool {
     adjust_cycle_setting(&mut state.settings.theme, &THEME_OPTIONS, direction)
 }
@@ -133,6 +173,14 @@ fn adjust_refresh_interval_setting(state: &mut AppState, direction: i8) -> bool
     )
 }
 
+fn adjust_forecast_days_setting(state: &mut AppState, direction: i8) -> bool {
+    adjust_cycle_setting(
+        &mut state.settings.forecast_days,
+        &FORECAST_DAYS_OPTIONS,
+        direction,
+    )
+}
+
 pub(crate) fn cycle<T: Copy + Eq>(values: &[T], current: T, direction: i8) -> T {
     if values.is_empty() {
         return current;
@@ -227,7 +275,7 @@ fn settings_hint_for_selection(selected: SettingsSelection) -> &'static str {
     for (candidate, hint) in [
         (
             SettingsSelection::Theme,
-            "Theme applies to all panels: Current, Hourly, 7-Day, popups, and status",
+            "Theme applies to all panels: Current, Hourly, Daily, popups, and status",
         ),
         (
             SettingsSelection::Motion,
@@ -249,10 +297,30 @@ fn settings_hint_for_selection(selected: SettingsSelection) -> &'static str {
             SettingsSelection::HourlyView,
             "Hourly View controls the Hourly panel: Table, Hybrid cards+charts, or Chart",
         ),
+        (
+            SettingsSelection::WindUnit,
+            "Wind and gust speeds in every panel, alerts, and one-shot output",
+        ),
+        (
+            SettingsSelection::PressureUnit,
+            "Sea-level pressure in the Current panel and hourly rows",
+        ),
+        (
+            SettingsSelection::PrecipUnit,
+            "Rain and snow amounts in the Hourly and Daily panels and alerts",
+        ),
+        (
+            SettingsSelection::DistanceUnit,
+            "Visibility distance in the Current panel and hourly rows",
+        ),
         (
             SettingsSelection::RefreshInterval,
             "Auto-refresh cadence updates immediately",
         ),
+        (
+            SettingsSelection::ForecastDays,
+            "Days fetched, each with hourly detail; the forecast reloads on change",
+        ),
     ] {
         if selected == candidate {
             return hint;
@@ -292,11 +360,20 @@ impl AppState {
             SettingsSelection::Flash
             | SettingsSelection::InlineHints
             | SettingsSelection::CommandBar => self.toggle_settings_entry(selection),
+            SettingsSelection::WindUnit
+            | SettingsSelection::PressureUnit
+            | SettingsSelection::PrecipUnit
+            | SettingsSelection::DistanceUnit => self.measure_settings_entry(selection),
             SettingsSelection::RefreshInterval => SettingsEntry {
                 label: "Auto Refresh",
                 value: format!("{} min", self.settings.refresh_interval_secs / 60),
                 editable: true,
             },
+            SettingsSelection::ForecastDays => SettingsEntry {
+                label: "Forecast",
+                value: format!("{} days", self.settings.forecast_days),
+                editable: true,
+            },
             SettingsSelection::RefreshNow | SettingsSelection::Close => {
                 Self::action_settings_entry(selection)
             }
@@ -327,6 +404,24 @@ impl AppState {
         }
     }
 
+    fn measure_settings_entry(&self, selection: SettingsSelection) -> SettingsEntry {
+        match selection {
+            SettingsSelection::WindUnit => {
+                settings_entry("Wind", self.settings.wind_unit.name(), true)
+            }
+            SettingsSelection::PressureUnit => {
+                settings_entry("Pressure", self.settings.pressure_unit.symbol(), true)
+            }
+            SettingsSelection::PrecipUnit => {
+                settings_entry("Precip", self.settings.precip_unit.symbol(), true)
+            }
+            SettingsSelection::DistanceUnit => {
+                settings_entry("Distance", self.settings.distance_unit.symbol(), true)
+            }
+            _ => unreachable!("measure_settings_entry only supports unit selections"),
+        }
+    }
+
     fn toggle_settings_entry(&self, selection: SettingsSelection) -> SettingsEntry {
         match selection {
             SettingsSelection::Flash => settings_entry(
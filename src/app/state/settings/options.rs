@@ -2,6 +2,7 @@ use crate::cli::ThemeArg;
 use crate::domain::weather::HourlyViewMode;
 
 pub(crate) const REFRESH_OPTIONS: [u64; 4] = [300, 600, 900, 1800];
+pub(crate) const FORECAST_DAYS_OPTIONS: [u8; 6] = [3, 5, 7, 10, 14, 16];
 pub(crate) const HOURLY_VIEW_OPTIONS: [HourlyViewMode; 3] = [
     HourlyViewMode::Table,
     HourlyViewMode::Hybrid,
@@ -58,12 +59,17 @@ pub enum SettingsSelection {
     CommandBar,
     HourlyView,
     Units,
+    WindUnit,
+    PressureUnit,
+    PrecipUnit,
+    DistanceUnit,
     RefreshInterval,
+    ForecastDays,
     RefreshNow,
     Close,
 }
 
-pub(crate) const SETTINGS_ORDER: [SettingsSelection; 12] = [
+pub(crate) const SETTINGS_ORDER: [SettingsSelection; 17] = [
     SettingsSelection::Theme,
     SettingsSelection::Motion,
     SettingsSelection::Flash,
@@ -73,7 +79,12 @@ pub(crate) const SETTINGS_ORDER: [SettingsSelection; 12] = [
     SettingsSelection::CommandBar,
     SettingsSelection::HourlyView,
     SettingsSelection::Units,
+    SettingsSelection::WindUnit,
+    SettingsSelection::PressureUnit,
+    SettingsSelection::PrecipUnit,
+    SettingsSelection::DistanceUnit,
     SettingsSelection::RefreshInterval,
+    SettingsSelection::ForecastDays,
     SettingsSelection::RefreshNow,
     SettingsSelection::Close,
 ];
@@ -1,10 +1,18 @@
 #![allow(clippy::missing_errors_doc)]
 
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
 use clap::{Parser, ValueEnum};
 use serde::{Deserialize, Serialize};
 
+use crate::domain::weather::{DistanceUnit, PrecipUnit, PressureUnit, WindUnit};
 use crate::ui::animation::MotionMode;
 
+mod commands;
+
+pub use commands::{CheckArgs, Command, ConfigAction, LocationArgs, LocationsAction};
+
 #[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
 pub enum UnitsArg {
     Celsius,
@@ -61,6 +69,57 @@ pub enum ThemeArg {
     EverforestDark,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProviderArg {
+    #[default]
+    OpenMeteo,
+    MetNorway,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum FormatArg {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    /// iCalendar feed of the daily forecast and alerts.
+    Ics,
+    /// Prometheus/OpenMetrics gauges.
+    OpenMetrics,
+    /// waybar `custom` module JSON.
+    Waybar,
+    /// i3bar protocol JSON.
+    I3bar,
+    /// wttr.in-style template such as `"%i %t (%f) %w"`.
+    Template(String),
+}
+
+fn parse_format(value: &str) -> Result<FormatArg, String> {
+    match value {
+        "text" => Ok(FormatArg::Text),
+        "json" => Ok(FormatArg::Json),
+        "csv" => Ok(FormatArg::Csv),
+        "ics" => Ok(FormatArg::Ics),
+        "openmetrics" => Ok(FormatArg::OpenMetrics),
+        "waybar" => Ok(FormatArg::Waybar),
+        "i3bar" => Ok(FormatArg::I3bar),
+        template if template.contains('%') => crate::export::template::Template::parse(template)
+            .map(|_| FormatArg::Template(template.to_string()))
+            .map_err(|err| err.to_string()),
+        other => Err(format!(
+            "unknown format '{other}' (expected text, json, csv, ics, openmetrics, waybar, i3bar or a template with %-placeholders)"
+        )),
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq, Default)]
+pub enum SeriesArg {
+    #[default]
+    Hourly,
+    Daily,
+}
+
 #[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum HeroVisualArg {
     #[serde(alias = "Auto", alias = "auto")]
@@ -80,11 +139,11 @@ pub enum HeroVisualArg {
     about = "Animated terminal weather dashboard"
 )]
 pub struct Cli {
-    /// City name. Interactive mode auto-detects via IP if omitted; --one-shot falls back to Stockholm.
+    /// City name. Interactive mode auto-detects via IP if omitted; --one-shot and subcommands fall back to Stockholm.
     pub city: Option<String>,
 
     /// Default units
-    #[arg(long, value_enum, default_value_t = UnitsArg::Celsius)]
+    #[arg(long, global = true, value_enum, default_value_t = UnitsArg::Celsius)]
     pub units: UnitsArg,
 
     /// Target FPS (15..60)
@@ -107,12 +166,16 @@ pub struct Cli {
     #[arg(long)]
     pub no_flash: bool,
 
-    /// Force ASCII icons
+    /// Leave the mouse to the terminal, e.g. for native text selection
     #[arg(long)]
+    pub no_mouse: bool,
+
+    /// Force ASCII icons
+    #[arg(long, global = true)]
     pub ascii_icons: bool,
 
     /// Force emoji icons
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub emoji_icons: bool,
 
     /// Use Nerd Font weather icons
@@ -140,18 +203,46 @@ pub struct Cli {
     pub hero_visual: HeroVisualArg,
 
     /// Geocode bias (ISO2)
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub country_code: Option<String>,
 
     /// Direct latitude (requires --lon)
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub lat: Option<f64>,
 
     /// Direct longitude (requires --lat)
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub lon: Option<f64>,
 
-    /// Override forecast API base URL
+    /// Pinned location by alias or name, e.g. `--location home`
+    #[arg(long, global = true, value_name = "ALIAS")]
+    pub location: Option<String>,
+
+    /// Forecast data provider
+    #[arg(long, global = true, value_enum)]
+    pub provider: Option<ProviderArg>,
+
+    /// Forecast horizon in days (1..16); hourly data covers the whole span
+    #[arg(long, global = true, value_name = "DAYS", value_parser = clap::value_parser!(u8).range(1..=16))]
+    pub forecast_days: Option<u8>,
+
+    /// Wind speed unit [default: ms]
+    #[arg(long, global = true, value_enum)]
+    pub wind_unit: Option<WindUnit>,
+
+    /// Pressure unit [default: hpa]
+    #[arg(long, global = true, value_enum)]
+    pub pressure_unit: Option<PressureUnit>,
+
+    /// Precipitation unit [default: mm]
+    #[arg(long, global = true, value_enum)]
+    pub precip_unit: Option<PrecipUnit>,
+
+    /// Visibility distance unit [default: km]
+    #[arg(long, global = true, value_enum)]
+    pub distance_unit: Option<DistanceUnit>,
+
+    /// Override forecast API base URL for the selected provider
     #[arg(long)]
     pub forecast_url: Option<String>,
 
@@ -164,12 +255,59 @@ pub struct Cli {
     pub refresh_interval: u64,
 
     /// Run automated demo script and exit
-    #[arg(long)]
+    #[arg(long, conflicts_with = "offline")]
     pub demo: bool,
 
+    /// Never touch the network; show cached forecasts for recent locations only
+    #[arg(long, global = true)]
+    pub offline: bool,
+
     /// Print weather snapshot to stdout and exit (non-interactive)
     #[arg(long)]
     pub one_shot: bool,
+
+    /// Output format for --one-shot and subcommands: text, json, csv, ics, openmetrics, waybar, i3bar, or a template like "%i %t (%f) %w"
+    #[arg(long, global = true, default_value = "text", value_parser = parse_format)]
+    pub format: FormatArg,
+
+    /// Forecast series written by --format csv
+    #[arg(long, value_enum, default_value_t = SeriesArg::Hourly, requires = "one_shot")]
+    pub series: SeriesArg,
+
+    /// Keep running and reprint the snapshot every refresh interval; text output redraws in place
+    #[arg(long, requires = "one_shot")]
+    pub watch: bool,
+
+    /// Run headless and rewrite this node_exporter textfile every refresh interval
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["one_shot", "demo"])]
+    pub metrics_file: Option<PathBuf>,
+
+    /// Run headless and serve OpenMetrics on http://ADDR/metrics
+    #[arg(long, value_name = "ADDR", conflicts_with_all = ["one_shot", "demo"])]
+    pub metrics_listen: Option<SocketAddr>,
+
+    /// Run headless and serve forecast JSON and an HTML page on http://ADDR/
+    #[arg(long, value_name = "ADDR", conflicts_with_all = ["one_shot", "demo", "metrics_file", "metrics_listen"])]
+    pub serve: Option<SocketAddr>,
+
+    /// Start on the dashboard of pinned locations
+    #[arg(long, conflicts_with_all = ["one_shot", "demo"])]
+    pub dashboard: bool,
+
+    /// Open side by side with a second city: a pinned alias, a recent location, or any city name
+    #[arg(long, value_name = "CITY", conflicts_with_all = ["one_shot", "demo", "dashboard", "inline"])]
+    pub compare: Option<String>,
+
+    /// Draw a compact animated view below the prompt instead of taking over the screen
+    #[arg(long, conflicts_with_all = ["one_shot", "demo", "metrics_file", "metrics_listen", "serve"])]
+    pub inline: bool,
+
+    /// Seconds the inline view keeps animating once the forecast is shown; 0 waits for a key
+    #[arg(long, value_name = "SECS", default_value_t = 5, requires = "inline")]
+    pub inline_seconds: u64,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
 }
 
 impl Cli {
@@ -192,6 +330,9 @@ impl Cli {
         if self.lon.is_some_and(|lon| !(-180.0..=180.0).contains(&lon)) {
             anyhow::bail!("Longitude must be between -180 and 180");
         }
+        if self.format != FormatArg::Text && !self.one_shot && self.command.is_none() {
+            anyhow::bail!("--format needs --one-shot or a subcommand such as `now`");
+        }
 
         Ok(())
     }
@@ -213,112 +354,4 @@ impl Cli {
 }
 
 #[cfg(test)]
-mod tests {
-    use clap::Parser;
-
-    use super::{Cli, ColorArg, HourlyViewArg};
-    use crate::ui::animation::MotionMode;
-
-    #[test]
-    fn parses_color_enum_values() {
-        let cli = Cli::parse_from(["terminal-weather", "--color", "always"]);
-        assert_eq!(cli.color, ColorArg::Always);
-        assert!(!cli.no_color);
-    }
-
-    #[test]
-    fn parses_no_color_alias() {
-        let cli = Cli::parse_from(["terminal-weather", "--no-color"]);
-        assert!(cli.no_color);
-        assert_eq!(cli.effective_color_mode(), ColorArg::Never);
-    }
-
-    #[test]
-    fn rejects_color_and_no_color_together() {
-        let err = Cli::try_parse_from(["terminal-weather", "--color", "always", "--no-color"])
-            .expect_err("expected conflict");
-        let rendered = err.to_string();
-        assert!(rendered.contains("--color"));
-        assert!(rendered.contains("--no-color"));
-    }
-
-    #[test]
-    fn effective_color_mode_prefers_no_color() {
-        let cli = Cli::parse_from(["terminal-weather", "--no-color"]);
-        assert_eq!(cli.effective_color_mode(), ColorArg::Never);
-
-        let cli = Cli::parse_from(["terminal-weather", "--color", "never"]);
-        assert_eq!(cli.effective_color_mode(), ColorArg::Never);
-
-        let cli = Cli::parse_from(["terminal-weather", "--color", "always"]);
-        assert_eq!(cli.effective_color_mode(), ColorArg::Always);
-
-        let cli = Cli::parse_from(["terminal-weather"]);
-        assert_eq!(cli.effective_color_mode(), ColorArg::Auto);
-    }
-
-    #[test]
-    fn effective_motion_mode_prefers_explicit_motion_flag() {
-        let cli = Cli::parse_from([
-            "terminal-weather",
-            "--no-animation",
-            "--motion",
-            "cinematic",
-        ]);
-        assert_eq!(cli.effective_motion_mode(), MotionMode::Cinematic);
-
-        let cli = Cli::parse_from(["terminal-weather", "--reduced-motion"]);
-        assert_eq!(cli.effective_motion_mode(), MotionMode::Reduced);
-
-        let cli = Cli::parse_from(["terminal-weather", "--no-animation"]);
-        assert_eq!(cli.effective_motion_mode(), MotionMode::Off);
-    }
-
-    #[test]
-    fn parses_hourly_view_override() {
-        let cli = Cli::parse_from(["terminal-weather", "--hourly-view", "hybrid"]);
-        assert_eq!(cli.hourly_view, Some(HourlyViewArg::Hybrid));
-    }
-
-    #[test]
-    fn validate_rejects_invalid_lat() {
-        let cli = Cli {
-            lat: Some(91.0),
-            lon: Some(0.0),
-            ..Cli::parse_from(["terminal-weather"])
-        };
-        assert!(cli.validate().is_err());
-    }
-
-    #[test]
-    fn validate_rejects_invalid_lon() {
-        let cli = Cli {
-            lat: Some(0.0),
-            lon: Some(181.0),
-            ..Cli::parse_from(["terminal-weather"])
-        };
-        assert!(cli.validate().is_err());
-    }
-
-    #[test]
-    fn validate_accepts_valid_coords() {
-        let cli = Cli {
-            lat: Some(45.0),
-            lon: Some(-90.0),
-            ..Cli::parse_from(["terminal-weather"])
-        };
-        assert!(cli.validate().is_ok());
-    }
-
-    #[test]
-    fn parses_new_theme_variants() {
-        let cli = Cli::parse_from(["terminal-weather", "--theme", "tokyo-night-storm"]);
-        assert_eq!(cli.theme, super::ThemeArg::TokyoNightStorm);
-
-        let cli = Cli::parse_from(["terminal-weather", "--theme", "rose-pine-moon"]);
-        assert_eq!(cli.theme, super::ThemeArg::RosePineMoon);
-
-        let cli = Cli::parse_from(["terminal-weather", "--theme", "everforest-dark"]);
-        assert_eq!(cli.theme, super::ThemeArg::EverforestDark);
-    }
-}
+mod tests;
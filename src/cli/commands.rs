@@ -0,0 +1,105 @@
+use clap::{Args, Subcommand};
+
+/// Scriptable subcommands. Without one, `terminal-weather` launches the TUI.
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Print current conditions
+    Now(LocationArgs),
+    /// Print the hourly forecast
+    Hourly {
+        /// Number of hours to print
+        #[arg(long, default_value_t = 24, value_parser = clap::value_parser!(u16).range(1..))]
+        hours: u16,
+        #[command(flatten)]
+        location: LocationArgs,
+    },
+    /// Print the daily forecast
+    Daily(LocationArgs),
+    /// Print active weather alerts
+    Alerts(LocationArgs),
+    /// Check alerts for one or more cities; the exit code is the worst severity
+    /// (0 none, 1 info, 2 warning, 3 danger, 4 a city could not be checked)
+    Check(CheckArgs),
+    /// Manage saved recent and pinned locations
+    Locations {
+        #[command(subcommand)]
+        action: LocationsAction,
+    },
+    /// Read or change saved settings
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct LocationArgs {
+    /// City name; defaults to the top-level city, --lat/--lon, or Stockholm
+    pub city: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct CheckArgs {
+    /// Cities to check; defaults to the top-level city, --lat/--lon, or Stockholm
+    pub cities: Vec<String>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum LocationsAction {
+    /// List pinned locations, then recent locations, most recent first
+    List,
+    /// Geocode a city and save it as the most recent location
+    Add {
+        /// City name (use --country-code to bias the search)
+        city: String,
+    },
+    /// Remove a recent location by its list number or name
+    Remove {
+        /// Number from `locations list`, or a location name
+        location: String,
+    },
+    /// Geocode a city and pin it as a favorite
+    Pin {
+        /// City name (use --country-code to bias the search)
+        city: String,
+        /// Short name for `--location`, e.g. home
+        #[arg(long)]
+        alias: Option<String>,
+    },
+    /// Unpin a favorite by its pinned number, alias or name
+    Unpin {
+        /// Number from the pinned section of `locations list`, an alias, or a location name
+        location: String,
+    },
+    /// Set or clear the alias of a pinned location
+    Alias {
+        /// Number from the pinned section of `locations list`, an alias, or a location name
+        location: String,
+        /// New alias; omit to clear it
+        alias: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ConfigAction {
+    /// Print one setting, or every setting when no key is given
+    Get { key: Option<String> },
+    /// Change one setting
+    Set { key: String, value: String },
+    /// Restore default settings; recent and pinned locations are kept
+    Reset,
+}
+
+impl Command {
+    /// The city given to a forecast subcommand, if any.
+    #[must_use]
+    pub fn city(&self) -> Option<&str> {
+        match self {
+            Self::Now(location)
+            | Self::Hourly { location, .. }
+            | Self::Daily(location)
+            | Self::Alerts(location) => location.city.as_deref(),
+            Self::Check(_) | Self::Locations { .. } | Self::Config { .. } => None,
+        }
+    }
+}
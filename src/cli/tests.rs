@@ -0,0 +1,222 @@
+use clap::Parser;
+
+use super::{Cli, ColorArg, Command, FormatArg, HourlyViewArg, SeriesArg, UnitsArg};
+use crate::ui::animation::MotionMode;
+
+#[test]
+fn parses_color_enum_values() {
+    let cli = Cli::parse_from(["terminal-weather", "--color", "always"]);
+    assert_eq!(cli.color, ColorArg::Always);
+    assert!(!cli.no_color);
+}
+
+#[test]
+fn parses_no_color_alias() {
+    let cli = Cli::parse_from(["terminal-weather", "--no-color"]);
+    assert!(cli.no_color);
+    assert_eq!(cli.effective_color_mode(), ColorArg::Never);
+}
+
+#[test]
+fn rejects_color_and_no_color_together() {
+    let err = Cli::try_parse_from(["terminal-weather", "--color", "always", "--no-color"])
+        .expect_err("expected conflict");
+    let rendered = err.to_string();
+    assert!(rendered.contains("--color"));
+    assert!(rendered.contains("--no-color"));
+}
+
+#[test]
+fn effective_color_mode_prefers_no_color() {
+    let cli = Cli::parse_from(["terminal-weather", "--no-color"]);
+    assert_eq!(cli.effective_color_mode(), ColorArg::Never);
+
+    let cli = Cli::parse_from(["terminal-weather", "--color", "never"]);
+    assert_eq!(cli.effective_color_mode(), ColorArg::Never);
+
+    let cli = Cli::parse_from(["terminal-weather", "--color", "always"]);
+    assert_eq!(cli.effective_color_mode(), ColorArg::Always);
+
+    let cli = Cli::parse_from(["terminal-weather"]);
+    assert_eq!(cli.effective_color_mode(), ColorArg::Auto);
+}
+
+#[test]
+fn effective_motion_mode_prefers_explicit_motion_flag() {
+    let cli = Cli::parse_from([
+        "terminal-weather",
+        "--no-animation",
+        "--motion",
+        "cinematic",
+    ]);
+    assert_eq!(cli.effective_motion_mode(), MotionMode::Cinematic);
+
+    let cli = Cli::parse_from(["terminal-weather", "--reduced-motion"]);
+    assert_eq!(cli.effective_motion_mode(), MotionMode::Reduced);
+
+    let cli = Cli::parse_from(["terminal-weather", "--no-animation"]);
+    assert_eq!(cli.effective_motion_mode(), MotionMode::Off);
+}
+
+#[test]
+fn format_requires_one_shot() {
+    let cli = Cli::parse_from(["terminal-weather", "--one-shot", "--format", "json"]);
+    assert_eq!(cli.format, FormatArg::Json);
+    let cli = Cli::parse_from(["terminal-weather", "--format", "json"]);
+    assert!(cli.validate().is_err());
+}
+
+#[test]
+fn csv_series_defaults_to_hourly() {
+    let cli = Cli::parse_from(["terminal-weather", "--one-shot", "--format", "csv"]);
+    assert_eq!(cli.format, FormatArg::Csv);
+    assert_eq!(cli.series, SeriesArg::Hourly);
+
+    let cli = Cli::parse_from([
+        "terminal-weather",
+        "--one-shot",
+        "--format",
+        "csv",
+        "--series",
+        "daily",
+    ]);
+    assert_eq!(cli.series, SeriesArg::Daily);
+}
+
+#[test]
+fn format_accepts_templates_and_rejects_unknown_placeholders() {
+    let cli = Cli::parse_from(["terminal-weather", "--one-shot", "--format", "%i %t"]);
+    assert_eq!(cli.format, FormatArg::Template("%i %t".to_string()));
+    let cli = Cli::parse_from([
+        "terminal-weather",
+        "--one-shot",
+        "--format",
+        "waybar",
+        "--watch",
+    ]);
+    assert_eq!(cli.format, FormatArg::Waybar);
+    assert!(cli.watch);
+    assert!(Cli::try_parse_from(["terminal-weather", "--one-shot", "--format", "%t %q"]).is_err());
+    assert!(Cli::try_parse_from(["terminal-weather", "--one-shot", "--format", "yaml"]).is_err());
+}
+
+#[test]
+fn metrics_modes_parse_and_exclude_one_shot() {
+    let cli = Cli::parse_from([
+        "terminal-weather",
+        "--metrics-listen",
+        "127.0.0.1:9731",
+        "--metrics-file",
+        "/tmp/weather.prom",
+    ]);
+    assert_eq!(cli.metrics_listen, Some(([127, 0, 0, 1], 9731).into()));
+    assert!(cli.metrics_file.is_some());
+    assert!(
+        Cli::try_parse_from(["terminal-weather", "--one-shot", "--metrics-file", "x.prom"])
+            .is_err()
+    );
+}
+
+#[test]
+fn serve_parses_address_and_excludes_other_modes() {
+    let cli = Cli::parse_from(["terminal-weather", "--serve", "127.0.0.1:8080"]);
+    assert_eq!(cli.serve, Some(([127, 0, 0, 1], 8080).into()));
+    assert!(Cli::try_parse_from(["terminal-weather", "--serve", "localhost"]).is_err());
+    assert!(
+        Cli::try_parse_from([
+            "terminal-weather",
+            "--serve",
+            "127.0.0.1:8080",
+            "--one-shot"
+        ])
+        .is_err()
+    );
+}
+
+#[test]
+fn definition_is_consistent() {
+    use clap::CommandFactory;
+    Cli::command().debug_assert();
+}
+
+#[test]
+fn subcommands_take_a_city_and_global_flags() {
+    let cli = Cli::parse_from(["terminal-weather", "now", "Oslo", "--units", "fahrenheit"]);
+    assert_eq!(cli.command.as_ref().and_then(Command::city), Some("Oslo"));
+    assert_eq!(cli.units, UnitsArg::Fahrenheit);
+    assert!(cli.validate().is_ok());
+
+    let cli = Cli::parse_from(["terminal-weather", "hourly", "--hours", "6"]);
+    assert!(matches!(
+        cli.command,
+        Some(Command::Hourly { hours: 6, .. })
+    ));
+
+    let cli = Cli::parse_from(["terminal-weather", "New York"]);
+    assert!(cli.command.is_none());
+    assert_eq!(cli.city.as_deref(), Some("New York"));
+}
+
+#[test]
+fn offline_conflicts_with_demo() {
+    let cli = Cli::parse_from(["terminal-weather", "--offline", "--one-shot"]);
+    assert!(cli.offline);
+    assert!(Cli::try_parse_from(["terminal-weather", "--offline", "--demo"]).is_err());
+}
+
+#[test]
+fn parses_hourly_view_override() {
+    let cli = Cli::parse_from(["terminal-weather", "--hourly-view", "hybrid"]);
+    assert_eq!(cli.hourly_view, Some(HourlyViewArg::Hybrid));
+}
+
+#[test]
+fn validate_rejects_invalid_lat() {
+    let cli = Cli {
+        lat: Some(91.0),
+        lon: Some(0.0),
+        ..Cli::parse_from(["terminal-weather"])
+    };
+    assert!(cli.validate().is_err());
+}
+
+#[test]
+fn validate_rejects_invalid_lon() {
+    let cli = Cli {
+        lat: Some(0.0),
+        lon: Some(181.0),
+        ..Cli::parse_from(["terminal-weather"])
+    };
+    assert!(cli.validate().is_err());
+}
+
+#[test]
+fn validate_accepts_valid_coords() {
+    let cli = Cli {
+        lat: Some(45.0),
+        lon: Some(-90.0),
+        ..Cli::parse_from(["terminal-weather"])
+    };
+    assert!(cli.validate().is_ok());
+}
+
+#[test]
+fn parses_new_theme_variants() {
+    let cli = Cli::parse_from(["terminal-weather", "--theme", "tokyo-night-storm"]);
+    assert_eq!(cli.theme, super::ThemeArg::TokyoNightStorm);
+
+    let cli = Cli::parse_from(["terminal-weather", "--theme", "rose-pine-moon"]);
+    assert_eq!(cli.theme, super::ThemeArg::RosePineMoon);
+
+    let cli = Cli::parse_from(["terminal-weather", "--theme", "everforest-dark"]);
+    assert_eq!(cli.theme, super::ThemeArg::EverforestDark);
+}
+
+#[test]
+fn parses_provider_override() {
+    let cli = Cli::parse_from(["terminal-weather", "--provider", "met-norway"]);
+    assert_eq!(cli.provider, Some(super::ProviderArg::MetNorway));
+
+    let cli = Cli::parse_from(["terminal-weather"]);
+    assert_eq!(cli.provider, None);
+}
@@ -0,0 +1,32 @@
+//! Scriptable subcommands: `now`, `hourly`, `daily`, `alerts`, `check`,
+//! `locations` and `config`. Bare `terminal-weather` still launches the TUI.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::{Context, Result};
+
+use crate::app::settings::settings_path;
+use crate::cli::{Cli, Command};
+
+mod check;
+mod config;
+mod forecast;
+mod locations;
+
+/// Everything but `check` exits 0 on success.
+pub(crate) async fn run(cli: &Cli, command: &Command) -> Result<ExitCode> {
+    match command {
+        Command::Check(args) => return check::run(cli, &args.cities).await,
+        Command::Locations { action } => locations::run(cli, action, &settings_file()?).await,
+        Command::Config { action } => config::run(action, &settings_file()?),
+        Command::Now(_) | Command::Hourly { .. } | Command::Daily(_) | Command::Alerts(_) => {
+            forecast::run(cli, command).await
+        }
+    }?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn settings_file() -> Result<PathBuf> {
+    settings_path().context("No settings location; set HOME or TERMINAL_WEATHER_CONFIG_DIR")
+}
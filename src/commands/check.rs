@@ -0,0 +1,156 @@
+use std::process::ExitCode;
+
+use anyhow::{Result, bail};
+
+use crate::app::{forecast_store::ForecastStore, settings::load_runtime_settings};
+use crate::cli::{Cli, FormatArg};
+use crate::domain::alerts::{AlertSeverity, WeatherAlert, scan_alerts};
+use crate::one_shot::load_bundle;
+
+/// Exit code when at least one city could not be fetched. It outranks every
+/// severity so a monitor never reads a failed check as "all clear".
+const CHECK_FAILED: u8 = 4;
+
+/// Scans the whole forecast for alerts, including the saved alert rules, for
+/// each city and exits with the worst severity found, so cron jobs and
+/// monitors need not parse the output.
+pub(super) async fn run(cli: &Cli, cities: &[String]) -> Result<ExitCode> {
+    if cli.format != FormatArg::Text {
+        bail!("`check` supports --format text");
+    }
+    let (settings, settings_path) = load_runtime_settings(cli, !cfg!(test));
+    let store = settings_path
+        .as_deref()
+        .and_then(ForecastStore::beside_settings);
+    let measures = settings.measure_units();
+
+    let mut worst = None;
+    let mut failed = false;
+    for target in targets(cli, cities) {
+        match load_bundle(&target, &settings, store.as_ref()).await {
+            Ok(bundle) => {
+                let alerts = scan_alerts(&bundle, settings.units, measures, &settings.alert_rules);
+                print!("{}", report(&bundle.location.display_name(), &alerts));
+                worst = worst.max(alerts.iter().map(|alert| alert.severity).max());
+            }
+            Err(err) => {
+                eprintln!("{}: could not check: {err:#}", target_label(&target));
+                failed = true;
+            }
+        }
+    }
+    Ok(ExitCode::from(exit_code(worst, failed)))
+}
+
+/// One CLI per city, or the top-level location when no cities are given.
+fn targets(cli: &Cli, cities: &[String]) -> Vec<Cli> {
+    if cities.is_empty() {
+        return vec![cli.clone()];
+    }
+    cities
+        .iter()
+        .map(|city| {
+            let mut target = cli.clone();
+            target.city = Some(city.clone());
+            target.lat = None;
+            target.lon = None;
+            target
+        })
+        .collect()
+}
+
+fn target_label(cli: &Cli) -> String {
+    match (cli.lat, cli.lon, &cli.city) {
+        (_, _, Some(city)) => city.clone(),
+        (Some(lat), Some(lon), None) => format!("{lat:.4},{lon:.4}"),
+        _ => cli.default_city(),
+    }
+}
+
+fn report(place: &str, alerts: &[WeatherAlert]) -> String {
+    let worst = alerts.iter().map(|alert| alert.severity).max();
+    let mut out = format!("{place}: {}\n", worst.map_or("clear", AlertSeverity::label));
+    for alert in alerts {
+        out.push_str(&format!(
+            "  {} {} ({})\n",
+            alert.severity.label(),
+            alert.message,
+            alert.timing_label()
+        ));
+    }
+    out
+}
+
+const fn exit_code(worst: Option<AlertSeverity>, failed: bool) -> u8 {
+    if failed {
+        return CHECK_FAILED;
+    }
+    match worst {
+        None => 0,
+        Some(AlertSeverity::Info) => 1,
+        Some(AlertSeverity::Warning) => 2,
+        Some(AlertSeverity::Danger) => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::weather::{MeasureUnits, Units};
+    use crate::test_support::{sample_bundle, state_test_cli};
+
+    #[test]
+    fn exit_code_follows_worst_severity_and_failures() {
+        assert_eq!(exit_code(None, false), 0);
+        assert_eq!(exit_code(Some(AlertSeverity::Info), false), 1);
+        assert_eq!(exit_code(Some(AlertSeverity::Warning), false), 2);
+        assert_eq!(exit_code(Some(AlertSeverity::Danger), false), 3);
+        assert_eq!(exit_code(Some(AlertSeverity::Danger), true), CHECK_FAILED);
+    }
+
+    #[test]
+    fn report_lists_alerts_with_eta() {
+        let mut bundle = sample_bundle();
+        assert_eq!(
+            report(
+                "Stockholm",
+                &scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[])
+            ),
+            "Stockholm: clear\n"
+        );
+
+        bundle.hourly[0].wind_gusts_10m = Some(95.0);
+        let text = report(
+            "Stockholm",
+            &scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]),
+        );
+        assert!(text.starts_with("Stockholm: danger\n"));
+        assert!(text.contains("(now · 10:00–11:00)"));
+    }
+
+    #[test]
+    fn alert_rules_count_towards_the_report() {
+        let bundle = sample_bundle();
+        let rules = ["temp < 10 C within 6h -> info: Chilly"
+            .parse()
+            .expect("rule")];
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &rules);
+
+        assert_eq!(
+            report("Stockholm", &alerts),
+            "Stockholm: info\n  info Chilly (now · 10:00–11:00)\n"
+        );
+    }
+
+    #[test]
+    fn cities_override_the_top_level_location() {
+        let mut cli = state_test_cli();
+        cli.lat = Some(59.33);
+        cli.lon = Some(18.07);
+        assert_eq!(target_label(&targets(&cli, &[])[0]), "59.3300,18.0700");
+
+        let cities = ["Oslo".to_string(), "Bergen".to_string()];
+        let labels: Vec<String> = targets(&cli, &cities).iter().map(target_label).collect();
+        assert_eq!(labels, ["Oslo", "Bergen"]);
+    }
+}
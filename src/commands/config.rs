@@ -0,0 +1,122 @@
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+
+use crate::app::settings::{RuntimeSettings, read_saved_settings, save_runtime_settings};
+use crate::cli::ConfigAction;
+
+/// Bookkeeping fields that `config` neither shows nor edits. Recent and pinned
+/// locations have their own `locations` subcommand.
+const HIDDEN_KEYS: [&str; 4] = [
+    "recent_locations",
+    "pinned_locations",
+    "last_update_check_unix",
+    "last_seen_latest_version",
+];
+
+pub(super) fn run(action: &ConfigAction, path: &Path) -> Result<()> {
+    let settings = read_saved_settings(path).unwrap_or_default();
+    match action {
+        ConfigAction::Get { key } => print!("{}", get(&settings, key.as_deref())?),
+        ConfigAction::Set { key, value } => {
+            let updated = set(&settings, key, value)?;
+            save_runtime_settings(path, &updated)?;
+            print!("{}", get(&updated, Some(key))?);
+        }
+        ConfigAction::Reset => {
+            let defaults = RuntimeSettings {
+                recent_locations: settings.recent_locations,
+                pinned_locations: settings.pinned_locations,
+                ..RuntimeSettings::default()
+            };
+            save_runtime_settings(path, &defaults)?;
+            println!("Settings reset to defaults");
+        }
+    }
+    Ok(())
+}
+
+/// One value, or `key = value` lines for every visible setting.
+fn get(settings: &RuntimeSettings, key: Option<&str>) -> Result<String> {
+    let fields = visible_fields(settings)?;
+    match key {
+        Some(key) => {
+            let value = fields.get(key).with_context(|| unknown_key(key, &fields))?;
+            Ok(format!("{}\n", display(value)))
+        }
+        None => Ok(fields
+            .iter()
+            .map(|(key, value)| format!("{key} = {}\n", display(value)))
+            .collect()),
+    }
+}
+
+/// `value` is read as JSON (`true`, `300`, `"Celsius"`) and otherwise as a
+/// bare string, then checked by deserializing the whole settings struct.
+fn set(settings: &RuntimeSettings, key: &str, value: &str) -> Result<RuntimeSettings> {
+    let mut fields = visible_fields(settings)?;
+    if !fields.contains_key(key) {
+        bail!(unknown_key(key, &fields));
+    }
+    let parsed = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+    fields.insert(key.to_string(), parsed);
+
+    let mut all = serde_json::to_value(settings)?;
+    if let Value::Object(object) = &mut all {
+        object.extend(fields);
+    }
+    serde_json::from_value(all).with_context(|| format!("Invalid value '{value}' for {key}"))
+}
+
+fn visible_fields(settings: &RuntimeSettings) -> Result<serde_json::Map<String, Value>> {
+    let Value::Object(mut fields) = serde_json::to_value(settings)? else {
+        bail!("settings did not serialize to an object");
+    };
+    fields.retain(|key, _| !HIDDEN_KEYS.contains(&key.as_str()));
+    Ok(fields)
+}
+
+fn unknown_key(key: &str, fields: &serde_json::Map<String, Value>) -> String {
+    let known: Vec<&str> = fields.keys().map(String::as_str).collect();
+    format!("Unknown setting '{key}' (known: {})", known.join(", "))
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::weather::Units;
+
+    #[test]
+    fn get_lists_visible_settings() {
+        let settings = RuntimeSettings::default();
+        let all = get(&settings, None).expect("all");
+
+        assert!(all.contains("units = Celsius\n"));
+        assert!(all.contains("refresh_interval_secs = 600\n"));
+        assert!(!all.contains("recent_locations"));
+        assert_eq!(get(&settings, Some("no_flash")).expect("one"), "false\n");
+        assert!(get(&settings, Some("recent_locations")).is_err());
+    }
+
+    #[test]
+    fn set_parses_json_or_strings_and_validates() {
+        let settings = RuntimeSettings::default();
+        let updated = set(&settings, "units", "Fahrenheit").expect("bare string");
+        assert_eq!(updated.units, Units::Fahrenheit);
+
+        let updated = set(&updated, "refresh_interval_secs", "300").expect("number");
+        assert_eq!(updated.refresh_interval_secs, 300);
+        assert_eq!(updated.units, Units::Fahrenheit);
+
+        assert!(set(&settings, "units", "kelvin").is_err());
+        assert!(set(&settings, "colour", "red").is_err());
+    }
+}
@@ -0,0 +1,240 @@
+use anyhow::{Result, bail};
+
+use crate::app::{
+    forecast_store::ForecastStore,
+    settings::{RuntimeSettings, load_runtime_settings},
+};
+use crate::cli::{Cli, Command, FormatArg, IconMode};
+use crate::domain::alerts::{AlertRule, scan_alerts};
+use crate::domain::weather::{
+    ForecastBundle, HourlyForecast, MeasureUnits, RefreshMetadata, Units, WindUnit, convert_temp,
+    round_temp, weather_icon, weather_label,
+};
+use crate::export::template::{Template, TemplateContext};
+use crate::export::{csv, ical, json};
+use crate::one_shot::{
+    freshness_notice, load_bundle, one_shot_current_text, one_shot_daily_text, one_shot_icon_mode,
+    one_shot_refresh_meta, temperature_symbol,
+};
+
+/// Fetches (or under `--offline`, loads) the forecast for the subcommand's city
+/// and prints the part it asks for.
+pub(super) async fn run(cli: &Cli, command: &Command) -> Result<()> {
+    let mut cli = cli.clone();
+    if let Some(city) = command.city() {
+        cli.city = Some(city.to_string());
+        cli.lat = None;
+        cli.lon = None;
+    }
+    check_format(command, &cli.format)?;
+
+    let (settings, settings_path) = load_runtime_settings(&cli, !cfg!(test));
+    let store = settings_path
+        .as_deref()
+        .and_then(ForecastStore::beside_settings);
+    let mut bundle = load_bundle(&cli, &settings, store.as_ref()).await?;
+    if let Command::Hourly { hours, .. } = command {
+        bundle.hourly.truncate(usize::from(*hours));
+    }
+    let refresh_meta = one_shot_refresh_meta(&bundle, cli.offline);
+
+    if cli.format == FormatArg::Text {
+        print!("{}", freshness_notice(&refresh_meta));
+    }
+    print!(
+        "{}",
+        render(&cli, command, &bundle, &refresh_meta, &settings)?
+    );
+    Ok(())
+}
+
+/// Each subcommand supports the formats that make sense for its data.
+fn check_format(command: &Command, format: &FormatArg) -> Result<()> {
+    let (name, supported) = match (command, format) {
+        (_, FormatArg::Text | FormatArg::Json)
+        | (Command::Now(_), FormatArg::Template(_))
+        | (Command::Hourly { .. }, FormatArg::Csv)
+        | (Command::Daily(_), FormatArg::Csv | FormatArg::Ics) => return Ok(()),
+        (Command::Now(_), _) => ("now", "text, json or a template"),
+        (Command::Hourly { .. }, _) => ("hourly", "text, json or csv"),
+        (Command::Daily(_), _) => ("daily", "text, json, csv or ics"),
+        _ => ("alerts", "text or json"),
+    };
+    bail!("`{name}` supports --format {supported}")
+}
+
+fn render(
+    cli: &Cli,
+    command: &Command,
+    bundle: &ForecastBundle,
+    refresh_meta: &RefreshMetadata,
+    settings: &RuntimeSettings,
+) -> Result<String> {
+    let units = settings.units;
+    let unit_symbol = temperature_symbol(units);
+    let measures = settings.measure_units();
+    let alert_rules = settings.alert_rules.as_slice();
+    let icon_mode = one_shot_icon_mode(cli);
+    let section = match command {
+        Command::Now(_) => "current",
+        Command::Hourly { .. } => "hourly",
+        Command::Daily(_) => "daily",
+        _ => "alerts",
+    };
+    Ok(match &cli.format {
+        FormatArg::Json => {
+            let document =
+                json::render_section(bundle, units, measures, refresh_meta, alert_rules, section)?;
+            format!("{}\n", serde_json::to_string_pretty(&document)?)
+        }
+        FormatArg::Csv if section == "daily" => {
+            csv::render(bundle, units, measures, csv::Series::Daily)
+        }
+        FormatArg::Csv => csv::render(bundle, units, measures, csv::Series::Hourly),
+        FormatArg::Ics => ical::render(bundle, units, measures, icon_mode, alert_rules),
+        FormatArg::Template(source) => {
+            let ctx = TemplateContext {
+                bundle,
+                units,
+                measures,
+                icon_mode,
+                refresh_meta,
+                alert_rules,
+            };
+            format!("{}\n", Template::parse(source)?.render(&ctx))
+        }
+        _ => match command {
+            Command::Now(_) => {
+                let display_name = bundle.location.display_name();
+                one_shot_current_text(bundle, &display_name, units, unit_symbol, measures)
+            }
+            Command::Hourly { .. } => hourly_text(bundle, units, measures.wind, icon_mode),
+            Command::Daily(_) => one_shot_daily_text(bundle, units, measures, icon_mode),
+            _ => alerts_text(bundle, units, measures, alert_rules),
+        },
+    })
+}
+
+fn hourly_text(
+    bundle: &ForecastBundle,
+    units: Units,
+    wind_unit: WindUnit,
+    icon_mode: IconMode,
+) -> String {
+    let mut out = format!("  {}\n", bundle.location.display_name());
+    for hour in &bundle.hourly {
+        out.push_str(&format!(
+            "  {}\n",
+            hourly_line(hour, units, wind_unit, icon_mode)
+        ));
+    }
+    out
+}
+
+/// `Thu 10:00  ☁     7°  Overcast       20%   3 m/s`
+fn hourly_line(
+    hour: &HourlyForecast,
+    units: Units,
+    wind_unit: WindUnit,
+    icon_mode: IconMode,
+) -> String {
+    let time = hour.time.format("%a %H:%M");
+    let is_day = hour.is_day.unwrap_or(true);
+    let (icon, condition) = hour.weather_code.map_or(("--", "--"), |code| {
+        (weather_icon(code, icon_mode, is_day), weather_label(code))
+    });
+    let temp = hour.temperature_2m_c.map_or_else(
+        || "--".to_string(),
+        |c| format!("{}°", round_temp(convert_temp(c, units))),
+    );
+    let rain = hour
+        .precipitation_probability
+        .map_or_else(|| "--".to_string(), |p| format!("{p:.0}%"));
+    let wind = hour
+        .wind_speed_10m
+        .map_or_else(|| "--".to_string(), |kmh| wind_unit.format(kmh));
+    format!("{time}  {icon:<4} {temp:>4}  {condition:<14} {rain:>4}  {wind}")
+}
+
+fn alerts_text(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+    rules: &[AlertRule],
+) -> String {
+    let alerts = scan_alerts(bundle, units, measures, rules);
+    if alerts.is_empty() {
+        return format!("  No alerts for {}\n", bundle.location.display_name());
+    }
+    alerts
+        .iter()
+        .map(|alert| {
+            format!(
+                "  {} {} ({})\n",
+                alert.icon,
+                alert.message,
+                alert.timing_label()
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+    use crate::test_support::sample_bundle;
+
+    fn parse(args: &[&str]) -> (Cli, Command) {
+        let cli = Cli::parse_from(args);
+        let command = cli.command.clone().expect("subcommand");
+        (cli, command)
+    }
+
+    #[test]
+    fn formats_are_checked_per_subcommand() {
+        let (cli, command) = parse(&["terminal-weather", "daily", "--format", "ics"]);
+        assert!(check_format(&command, &cli.format).is_ok());
+
+        let (cli, command) = parse(&["terminal-weather", "alerts", "--format", "csv"]);
+        let err = check_format(&command, &cli.format).expect_err("csv alerts");
+        assert_eq!(err.to_string(), "`alerts` supports --format text or json");
+    }
+
+    #[test]
+    fn json_holds_only_the_requested_section() {
+        let (cli, command) = parse(&["terminal-weather", "hourly", "--format", "json"]);
+        let rendered = render(
+            &cli,
+            &command,
+            &sample_bundle(),
+            &RefreshMetadata::default(),
+            &RuntimeSettings::default(),
+        )
+        .expect("render");
+        let value: serde_json::Value = serde_json::from_str(&rendered).expect("json");
+
+        assert_eq!(value["hourly"][0]["time"], "2026-02-12T10:00:00");
+        assert!(value.get("daily").is_none());
+        assert_eq!(value["location"]["name"], "Stockholm");
+    }
+
+    #[test]
+    fn text_lists_hours_and_alerts() {
+        let mut bundle = sample_bundle();
+        let hourly = hourly_text(&bundle, Units::Celsius, WindUnit::Ms, IconMode::Ascii);
+        assert!(hourly.contains("Thu 10:00"));
+        assert!(hourly.contains("Overcast"));
+
+        assert!(
+            alerts_text(&bundle, Units::Celsius, MeasureUnits::default(), &[])
+                .contains("No alerts for Stockholm")
+        );
+        bundle.hourly[0].wind_gusts_10m = Some(95.0);
+        assert!(
+            alerts_text(&bundle, Units::Celsius, MeasureUnits::default(), &[])
+                .contains("(now · 10:00–11:00)")
+        );
+    }
+}
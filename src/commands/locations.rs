@@ -0,0 +1,283 @@
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+
+use crate::app::settings::{
+    PINNED_LOCATIONS_MAX, PinnedLocation, RecentLocation, RuntimeSettings, read_saved_settings,
+    save_runtime_settings,
+};
+use crate::cli::{Cli, LocationsAction};
+use crate::data::geocode::GeocodeClient;
+use crate::domain::weather::Location;
+use crate::one_shot::resolve_one_shot_location;
+
+/// Lists and edits the recent locations the TUI's city picker offers and the
+/// pinned favorites its dashboard and `--location` use.
+pub(super) async fn run(cli: &Cli, action: &LocationsAction, path: &Path) -> Result<()> {
+    match action {
+        LocationsAction::List => print!("{}", list(&saved(path))),
+        LocationsAction::Add { city } => {
+            let location = geocode(cli, city).await?;
+            add(path, &location)?;
+            println!("Added {}", location.display_name());
+        }
+        LocationsAction::Remove { location } => {
+            let removed = remove(path, location)?;
+            println!("Removed {}", removed.display_name());
+        }
+        LocationsAction::Pin { city, alias } => {
+            let alias = alias.as_deref().map(checked_alias).transpose()?;
+            let location = geocode(cli, city).await?;
+            pin(path, &location, alias)?;
+            println!("Pinned {}", location.display_name());
+        }
+        LocationsAction::Unpin { location } => {
+            let removed = unpin(path, location)?;
+            println!("Unpinned {}", removed.label());
+        }
+        LocationsAction::Alias { location, alias } => {
+            let alias = alias.as_deref().map(checked_alias).transpose()?;
+            let updated = set_alias(path, location, alias)?;
+            println!("{}", updated.label());
+        }
+    }
+    Ok(())
+}
+
+async fn geocode(cli: &Cli, city: &str) -> Result<Location> {
+    let mut cli = cli.clone();
+    cli.city = Some(city.to_string());
+    cli.lat = None;
+    cli.lon = None;
+    resolve_one_shot_location(&cli, &GeocodeClient::new()?).await
+}
+
+fn saved(path: &Path) -> RuntimeSettings {
+    read_saved_settings(path).unwrap_or_default()
+}
+
+fn list(settings: &RuntimeSettings) -> String {
+    let mut out = String::new();
+    if !settings.pinned_locations.is_empty() {
+        out.push_str("Pinned\n");
+        out.push_str(&numbered(
+            settings.pinned_locations.iter().map(PinnedLocation::label),
+        ));
+        out.push_str("Recent\n");
+    }
+    if settings.recent_locations.is_empty() {
+        out.push_str("No recent locations\n");
+    } else {
+        out.push_str(&numbered(
+            settings
+                .recent_locations
+                .iter()
+                .map(RecentLocation::display_name),
+        ));
+    }
+    out
+}
+
+fn numbered(labels: impl Iterator<Item = String>) -> String {
+    labels
+        .enumerate()
+        .map(|(index, label)| format!("{:>2}. {label}\n", index + 1))
+        .collect()
+}
+
+fn add(path: &Path, location: &Location) -> Result<()> {
+    let mut settings = saved(path);
+    settings.remember_location(location);
+    save_runtime_settings(path, &settings)
+}
+
+/// Removes by the 1-based number shown by `list`, or by name (case-insensitive,
+/// either the bare name or the full display name).
+fn remove(path: &Path, target: &str) -> Result<RecentLocation> {
+    let mut settings = saved(path);
+    let Some(index) = find(&settings.recent_locations, target) else {
+        bail!("No recent location matches '{target}'; see `terminal-weather locations list`");
+    };
+    let removed = settings.recent_locations.remove(index);
+    save_runtime_settings(path, &settings)?;
+    Ok(removed)
+}
+
+/// Aliases are matched ignoring case, so they must be unique that way, and may
+/// not be bare numbers, which address pinned entries by position.
+fn checked_alias(alias: &str) -> Result<String> {
+    let alias = alias.trim();
+    if alias.is_empty() || alias.chars().all(|ch| ch.is_ascii_digit()) {
+        bail!("Alias '{alias}' must contain a non-digit character");
+    }
+    Ok(alias.to_string())
+}
+
+fn ensure_alias_free(settings: &RuntimeSettings, alias: Option<&str>) -> Result<()> {
+    if let Some(alias) = alias
+        && let Some(existing) = settings.pinned_locations.iter().find(|pinned| {
+            pinned
+                .alias
+                .as_deref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(alias))
+        })
+    {
+        bail!(
+            "Alias '{alias}' is already used by {}",
+            existing.place.display_name()
+        );
+    }
+    Ok(())
+}
+
+fn pin(path: &Path, location: &Location, alias: Option<String>) -> Result<()> {
+    let mut settings = saved(path);
+    if settings.is_pinned(location) {
+        bail!("{} is already pinned", location.display_name());
+    }
+    ensure_alias_free(&settings, alias.as_deref())?;
+    if !settings.pin_location(location, alias) {
+        bail!("At most {PINNED_LOCATIONS_MAX} locations can be pinned; unpin one first");
+    }
+    save_runtime_settings(path, &settings)
+}
+
+/// Unpins by the 1-based number in the pinned section of `list`, by alias, or
+/// by name.
+fn unpin(path: &Path, target: &str) -> Result<PinnedLocation> {
+    let mut settings = saved(path);
+    let index = find_pinned_index(&settings, target)?;
+    let removed = settings.pinned_locations.remove(index);
+    save_runtime_settings(path, &settings)?;
+    Ok(removed)
+}
+
+fn set_alias(path: &Path, target: &str, alias: Option<String>) -> Result<PinnedLocation> {
+    let mut settings = saved(path);
+    let index = find_pinned_index(&settings, target)?;
+    settings.pinned_locations[index].alias = None;
+    ensure_alias_free(&settings, alias.as_deref())?;
+    settings.pinned_locations[index].alias = alias;
+    save_runtime_settings(path, &settings)?;
+    Ok(settings.pinned_locations[index].clone())
+}
+
+fn find_pinned_index(settings: &RuntimeSettings, target: &str) -> Result<usize> {
+    let pinned = &settings.pinned_locations;
+    let index = match target.parse::<usize>() {
+        Ok(number) => number.checked_sub(1).filter(|index| *index < pinned.len()),
+        Err(_) => pinned
+            .iter()
+            .position(|entry| {
+                entry
+                    .alias
+                    .as_deref()
+                    .is_some_and(|alias| alias.eq_ignore_ascii_case(target))
+            })
+            .or_else(|| {
+                pinned
+                    .iter()
+                    .position(|entry| matches_name(&entry.place, target))
+            }),
+    };
+    index.with_context(|| {
+        format!("No pinned location matches '{target}'; see `terminal-weather locations list`")
+    })
+}
+
+fn find(entries: &[RecentLocation], target: &str) -> Option<usize> {
+    match target.parse::<usize>() {
+        Ok(number) => number.checked_sub(1).filter(|index| *index < entries.len()),
+        Err(_) => entries.iter().position(|entry| matches_name(entry, target)),
+    }
+}
+
+fn matches_name(entry: &RecentLocation, target: &str) -> bool {
+    entry.name.eq_ignore_ascii_case(target) || entry.display_name().eq_ignore_ascii_case(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::stockholm_location;
+
+    fn oslo() -> Location {
+        Location {
+            name: "Oslo".to_string(),
+            latitude: 59.91,
+            longitude: 10.75,
+            country: Some("Norway".to_string()),
+            admin1: None,
+            timezone: None,
+            population: None,
+        }
+    }
+
+    #[test]
+    fn add_puts_newest_first_without_duplicates() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("settings.json");
+        assert_eq!(list(&saved(&path)), "No recent locations\n");
+
+        add(&path, &stockholm_location()).expect("add");
+        add(&path, &oslo()).expect("add");
+        add(&path, &stockholm_location()).expect("add again");
+
+        assert_eq!(
+            list(&saved(&path)),
+            " 1. Stockholm, Stockholm, Sweden\n 2. Oslo, Norway\n"
+        );
+    }
+
+    #[test]
+    fn remove_by_number_or_name() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("settings.json");
+        add(&path, &oslo()).expect("add");
+        add(&path, &stockholm_location()).expect("add");
+
+        assert_eq!(remove(&path, "oslo").expect("by name").name, "Oslo");
+        assert!(remove(&path, "2").is_err());
+        assert_eq!(remove(&path, "1").expect("by number").name, "Stockholm");
+        assert!(saved(&path).recent_locations.is_empty());
+    }
+
+    #[test]
+    fn pinned_locations_list_first_and_unpin_by_number() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("settings.json");
+        add(&path, &oslo()).expect("add");
+        pin(&path, &stockholm_location(), None).expect("pin");
+        assert!(pin(&path, &stockholm_location(), None).is_err());
+
+        assert_eq!(
+            list(&saved(&path)),
+            "Pinned\n 1. Stockholm, Stockholm, Sweden\nRecent\n 1. Oslo, Norway\n"
+        );
+        assert_eq!(
+            unpin(&path, "1").expect("by number").place.name,
+            "Stockholm"
+        );
+        assert!(unpin(&path, "stockholm").is_err());
+        assert!(saved(&path).pinned_locations.is_empty());
+    }
+
+    #[test]
+    fn aliases_are_listed_unique_and_address_pinned_entries() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("settings.json");
+        pin(&path, &stockholm_location(), Some("home".to_string())).expect("pin");
+        assert!(pin(&path, &oslo(), Some("HOME".to_string())).is_err());
+        pin(&path, &oslo(), None).expect("pin without alias");
+        assert!(checked_alias("42").is_err());
+        assert!(set_alias(&path, "oslo", Some("Home".to_string())).is_err());
+        set_alias(&path, "2", Some("cabin".to_string())).expect("alias by number");
+
+        assert_eq!(
+            list(&saved(&path)),
+            "Pinned\n 1. home · Stockholm, Stockholm, Sweden\n 2. cabin · Oslo, Norway\nRecent\nNo recent locations\n"
+        );
+        assert_eq!(set_alias(&path, "HOME", None).expect("clear").alias, None);
+        assert_eq!(unpin(&path, "cabin").expect("by alias").place.name, "Oslo");
+    }
+}
@@ -15,12 +15,17 @@ const FORECAST_URL: &str = "https://api.open-meteo.com/v1/forecast";
 const AIR_QUALITY_URL: &str = "https://air-quality-api.open-meteo.com/v1/air-quality";
 const FORECAST_URL_ENV: &str = "TERMINAL_WEATHER_FORECAST_URL";
 const AIR_QUALITY_URL_ENV: &str = "TERMINAL_WEATHER_AIR_QUALITY_URL";
+/// Days of forecast requested unless `--forecast-days` or the setting says otherwise.
+pub const DEFAULT_FORECAST_DAYS: u8 = 7;
+/// Longest horizon Open-Meteo serves.
+pub const MAX_FORECAST_DAYS: u8 = 16;
 
 #[derive(Debug, Clone)]
 pub struct ForecastClient {
     client: Client,
     base_url: String,
     air_quality_url: String,
+    forecast_days: u8,
 }
 
 impl ForecastClient {
@@ -53,6 +58,7 @@ impl ForecastClient {
             client,
             base_url,
             air_quality_url,
+            forecast_days: DEFAULT_FORECAST_DAYS,
         })
     }
 
@@ -62,12 +68,20 @@ impl ForecastClient {
         self
     }
 
+    /// Requests `days` of daily forecast and hourly data for all of them,
+    /// clamped to `1..=MAX_FORECAST_DAYS`.
+    #[must_use]
+    pub fn with_forecast_days(mut self, days: u8) -> Self {
+        self.forecast_days = days.clamp(1, MAX_FORECAST_DAYS);
+        self
+    }
+
     pub async fn fetch(&self, location: Location) -> Result<ForecastBundle> {
         let forecast_fut = async {
             let mut response = self
                 .client
                 .get(&self.base_url)
-                .query(&forecast_query(&location))
+                .query(&forecast_query(&location, self.forecast_days))
                 .send()
                 .await
                 .context("forecast request failed")?
@@ -107,6 +121,7 @@ impl ForecastClient {
             daily,
             air_quality,
             fetched_at: Utc::now(),
+            utc_offset_seconds: payload.utc_offset_seconds,
         })
     }
 
@@ -140,7 +155,7 @@ fn resolve_api_urls(get_env: impl Fn(&str) -> Option<String>) -> (String, String
     (forecast_url, air_quality_url)
 }
 
-fn forecast_query(location: &Location) -> Vec<(&'static str, String)> {
+fn forecast_query(location: &Location, days: u8) -> Vec<(&'static str, String)> {
     vec![
         ("latitude", location.latitude.to_string()),
         ("longitude", location.longitude.to_string()),
@@ -164,8 +179,9 @@ fn forecast_query(location: &Location) -> Vec<(&'static str, String)> {
                 .join(","),
         ),
         ("timezone", "auto".to_string()),
-        ("forecast_days", "7".to_string()),
-        ("forecast_hours", "48".to_string()),
+        ("forecast_days", days.to_string()),
+        // Hourly data for the whole span, so every forecast day can be drilled into.
+        ("forecast_hours", (u16::from(days) * 24).to_string()),
     ]
 }
 
@@ -298,6 +314,8 @@ macro_rules! api_struct {
 
 #[derive(Debug, Deserialize)]
 struct ForecastResponse {
+    #[serde(default)]
+    utc_offset_seconds: i32,
     current: CurrentBlock,
     hourly: HourlyBlock,
     daily: DailyBlock,
@@ -453,4 +471,23 @@ mod tests {
         assert_eq!(client.base_url, "https://example.com/forecast");
         assert_eq!(client.air_quality_url, "https://example.com/aq");
     }
+
+    #[test]
+    fn forecast_days_are_clamped_and_request_hourly_for_the_whole_span() {
+        let client =
+            ForecastClient::with_urls("https://example.com/forecast", "https://example.com/aq")
+                .expect("failed to build forecast client for test")
+                .with_forecast_days(40);
+        assert_eq!(client.forecast_days, MAX_FORECAST_DAYS);
+
+        let query = forecast_query(&Location::from_coords(59.3, 18.0), 14);
+        let value = |key: &str| {
+            query
+                .iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.clone())
+        };
+        assert_eq!(value("forecast_days").as_deref(), Some("14"));
+        assert_eq!(value("forecast_hours").as_deref(), Some("336"));
+    }
 }
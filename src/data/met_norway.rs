@@ -0,0 +1,489 @@
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::missing_errors_doc
+)]
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+
+use crate::data::forecast::{DEFAULT_FORECAST_DAYS, MAX_FORECAST_DAYS};
+use crate::data::http::apply_loopback_proxy_policy;
+use crate::domain::weather::{
+    CurrentConditions, DailyForecast, ForecastBundle, HourlyForecast, Location,
+};
+
+mod symbols;
+use symbols::{symbol_is_day, symbol_to_wmo};
+
+const LOCATIONFORECAST_URL: &str = "https://api.met.no/weatherapi/locationforecast/2.0/complete";
+const LOCATIONFORECAST_URL_ENV: &str = "TERMINAL_WEATHER_MET_NORWAY_URL";
+// MET Norway's terms of service require an identifying user agent with a contact point.
+const USER_AGENT: &str = concat!(
+    "terminal-weather/",
+    env!("CARGO_PKG_VERSION"),
+    " https://github.com/markpasternak/terminal-weather"
+);
+const SNOW_CM_PER_MM: f32 = 0.7;
+const FOG_VISIBILITY_M: f32 = 1_000.0;
+const CLEAR_VISIBILITY_M: f32 = 10_000.0;
+
+#[derive(Debug, Clone)]
+pub struct MetNorwayClient {
+    client: Client,
+    base_url: String,
+    forecast_days: u8,
+}
+
+impl MetNorwayClient {
+    pub fn new() -> Result<Self> {
+        let base_url = std::env::var(LOCATIONFORECAST_URL_ENV)
+            .unwrap_or_else(|_| LOCATIONFORECAST_URL.to_string());
+        Self::with_base_url(base_url)
+    }
+
+    pub fn with_base_url(base_url: impl Into<String>) -> Result<Self> {
+        let base_url = base_url.into();
+        let client_builder = Client::builder()
+            .user_agent(USER_AGENT)
+            .timeout(std::time::Duration::from_secs(10));
+        let client = apply_loopback_proxy_policy(client_builder, &[base_url.as_str()])
+            .build()
+            .context("failed to build MET Norway client")?;
+        Ok(Self {
+            client,
+            base_url,
+            forecast_days: DEFAULT_FORECAST_DAYS,
+        })
+    }
+
+    /// Caps the days kept from the payload. Locationforecast covers about nine
+    /// days, with hourly steps only for the first two or three.
+    #[must_use]
+    pub fn with_forecast_days(mut self, days: u8) -> Self {
+        self.forecast_days = days.clamp(1, MAX_FORECAST_DAYS);
+        self
+    }
+
+    pub async fn fetch(&self, location: Location) -> Result<ForecastBundle> {
+        let mut response = self
+            .client
+            .get(&self.base_url)
+            .query(&locationforecast_query(&location))
+            .send()
+            .await
+            .context("MET Norway request failed")?
+            .error_for_status()
+            .context("MET Norway request returned non-success status")?;
+
+        let mut body_bytes = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .context("reading MET Norway chunk failed")?
+        {
+            if body_bytes.len() + chunk.len() > 2 * 1024 * 1024 {
+                anyhow::bail!("MET Norway response too large");
+            }
+            body_bytes.extend_from_slice(&chunk);
+        }
+
+        let payload: LocationforecastResponse =
+            serde_json::from_slice(&body_bytes).context("failed to parse MET Norway payload")?;
+        bundle_from_payload(location, &payload, usize::from(self.forecast_days))
+    }
+}
+
+fn locationforecast_query(location: &Location) -> Vec<(&'static str, String)> {
+    // The API rejects coordinates with more than four decimals.
+    vec![
+        ("lat", format!("{:.4}", location.latitude)),
+        ("lon", format!("{:.4}", location.longitude)),
+    ]
+}
+
+fn bundle_from_payload(
+    location: Location,
+    payload: &LocationforecastResponse,
+    days: usize,
+) -> Result<ForecastBundle> {
+    let offset = nominal_utc_offset(location.longitude);
+    let steps = parse_steps(&payload.properties.timeseries, offset);
+    let first = steps
+        .first()
+        .context("MET Norway payload has no timeseries")?;
+    let hourly = parse_hourly(&steps, days * 24);
+    let daily = parse_daily(&steps, days);
+    let current = current_from_step(first, &daily);
+
+    Ok(ForecastBundle {
+        location,
+        current,
+        hourly,
+        daily,
+        air_quality: None,
+        fetched_at: Utc::now(),
+        utc_offset_seconds: i32::try_from(offset.num_seconds()).unwrap_or_default(),
+    })
+}
+
+/// MET Norway reports UTC timestamps without zone metadata, while the rest of the
+/// app works in location-local wall time. Without a timezone database we shift by
+/// the nominal solar offset so dayparts and sunrise-relative views line up.
+fn nominal_utc_offset(longitude: f64) -> Duration {
+    Duration::hours((longitude / 15.0).round().clamp(-12.0, 14.0) as i64)
+}
+
+/// One timeseries entry resolved to local time, with the forecast period
+/// (one or six hours) that follows it.
+#[derive(Debug, Clone)]
+struct Step<'a> {
+    time: NaiveDateTime,
+    instant: &'a InstantDetails,
+    period: Option<&'a Period>,
+    period_hours: i64,
+}
+
+impl Step<'_> {
+    fn weather_code(&self) -> Option<u8> {
+        self.period
+            .and_then(|period| period.summary.as_ref())
+            .map(|summary| symbol_to_wmo(&summary.symbol_code))
+    }
+
+    fn is_day(&self) -> bool {
+        self.period
+            .and_then(|period| period.summary.as_ref())
+            .and_then(|summary| symbol_is_day(&summary.symbol_code))
+            .unwrap_or_else(|| (6..18).contains(&self.time.hour()))
+    }
+
+    fn precipitation_mm(&self) -> Option<f32> {
+        self.period
+            .and_then(|period| period.details.precipitation_amount)
+    }
+
+    fn precipitation_probability(&self) -> Option<f32> {
+        self.period
+            .and_then(|period| period.details.probability_of_precipitation)
+    }
+}
+
+fn parse_steps(timeseries: &[TimeStep], offset: Duration) -> Vec<Step<'_>> {
+    timeseries
+        .iter()
+        .filter_map(|step| {
+            let utc = DateTime::parse_from_rfc3339(&step.time).ok()?;
+            let (period, period_hours) = match (&step.data.next_1_hours, &step.data.next_6_hours) {
+                (Some(period), _) => (Some(period), 1),
+                (None, Some(period)) => (Some(period), 6),
+                (None, None) => (None, 0),
+            };
+            Some(Step {
+                time: (utc.with_timezone(&Utc) + offset).naive_utc(),
+                instant: &step.data.instant.details,
+                period,
+                period_hours,
+            })
+        })
+        .collect()
+}
+
+fn current_from_step(step: &Step<'_>, daily: &[DailyForecast]) -> CurrentConditions {
+    let details = step.instant;
+    let temperature = details.air_temperature.unwrap_or(0.0);
+    CurrentConditions {
+        temperature_2m_c: temperature,
+        relative_humidity_2m: details.relative_humidity.unwrap_or(0.0),
+        // Locationforecast has no feels-like field; fall back to air temperature.
+        apparent_temperature_c: temperature,
+        dew_point_2m_c: details.dew_point_temperature.unwrap_or(temperature),
+        weather_code: step.weather_code().unwrap_or(3),
+        precipitation_mm: step.precipitation_mm().unwrap_or(0.0),
+        cloud_cover: details.cloud_area_fraction.unwrap_or(0.0),
+        pressure_msl_hpa: details.air_pressure_at_sea_level.unwrap_or(1013.0),
+        visibility_m: visibility_from_fog(details.fog_area_fraction),
+        wind_speed_10m: ms_to_kmh(details.wind_speed).unwrap_or(0.0),
+        wind_gusts_10m: ms_to_kmh(details.wind_speed_of_gust.or(details.wind_speed)).unwrap_or(0.0),
+        wind_direction_10m: details.wind_from_direction.unwrap_or(0.0),
+        is_day: step.is_day(),
+        high_today_c: daily.first().and_then(|d| d.temperature_max_c),
+        low_today_c: daily.first().and_then(|d| d.temperature_min_c),
+    }
+}
+
+fn parse_hourly(steps: &[Step<'_>], limit: usize) -> Vec<HourlyForecast> {
+    steps
+        .iter()
+        .filter(|step| step.period_hours == 1)
+        .take(limit)
+        .map(hourly_from_step)
+        .collect()
+}
+
+fn hourly_from_step(step: &Step<'_>) -> HourlyForecast {
+    let details = step.instant;
+    let weather_code = step.weather_code();
+    let precipitation = step.precipitation_mm();
+    let (rain_mm, snowfall_cm) = split_precipitation(weather_code, precipitation);
+    HourlyForecast {
+        time: step.time,
+        temperature_2m_c: details.air_temperature,
+        weather_code,
+        is_day: Some(step.is_day()),
+        relative_humidity_2m: details.relative_humidity,
+        precipitation_probability: step.precipitation_probability(),
+        precipitation_mm: precipitation,
+        rain_mm,
+        snowfall_cm,
+        wind_speed_10m: ms_to_kmh(details.wind_speed),
+        wind_gusts_10m: ms_to_kmh(details.wind_speed_of_gust),
+        pressure_msl_hpa: details.air_pressure_at_sea_level,
+        visibility_m: details
+            .fog_area_fraction
+            .map(|fog| visibility_from_fog(Some(fog))),
+        cloud_cover: details.cloud_area_fraction,
+        cloud_cover_low: details.cloud_area_fraction_low,
+        cloud_cover_mid: details.cloud_area_fraction_medium,
+        cloud_cover_high: details.cloud_area_fraction_high,
+    }
+}
+
+fn parse_daily(steps: &[Step<'_>], limit: usize) -> Vec<DailyForecast> {
+    let mut dates = Vec::<NaiveDate>::with_capacity(limit);
+    for step in steps {
+        let date = step.time.date();
+        if dates.last() != Some(&date) {
+            if dates.len() == limit {
+                break;
+            }
+            dates.push(date);
+        }
+    }
+    let mut covered_until = None;
+    dates
+        .into_iter()
+        .map(|date| daily_for_date(steps, date, &mut covered_until))
+        .collect()
+}
+
+fn daily_for_date(
+    steps: &[Step<'_>],
+    date: NaiveDate,
+    covered_until: &mut Option<NaiveDateTime>,
+) -> DailyForecast {
+    let day_steps = steps
+        .iter()
+        .filter(|step| step.time.date() == date)
+        .collect::<Vec<_>>();
+    let mut acc = DayAccumulator::default();
+    for step in &day_steps {
+        acc.observe(step, covered_until);
+    }
+
+    DailyForecast {
+        date,
+        weather_code: dominant_code(&acc.codes),
+        temperature_max_c: acc.temp_max,
+        temperature_min_c: acc.temp_min,
+        sunrise: None,
+        sunset: None,
+        uv_index_max: acc.uv_max,
+        precipitation_probability_max: acc.precip_probability_max,
+        precipitation_sum_mm: Some(acc.precip_sum),
+        rain_sum_mm: Some(acc.rain_sum),
+        snowfall_sum_cm: Some(acc.snow_sum_cm),
+        precipitation_hours: acc.has_hourly.then_some(acc.precip_hours),
+        wind_gusts_10m_max: ms_to_kmh(acc.gust_max_ms),
+        daylight_duration_s: None,
+        sunshine_duration_s: None,
+    }
+}
+
+#[derive(Debug, Default)]
+struct DayAccumulator {
+    codes: Vec<u8>,
+    temp_max: Option<f32>,
+    temp_min: Option<f32>,
+    uv_max: Option<f32>,
+    precip_probability_max: Option<f32>,
+    precip_sum: f32,
+    rain_sum: f32,
+    snow_sum_cm: f32,
+    precip_hours: f32,
+    has_hourly: bool,
+    gust_max_ms: Option<f32>,
+}
+
+impl DayAccumulator {
+    fn observe(&mut self, step: &Step<'_>, covered_until: &mut Option<NaiveDateTime>) {
+        let details = step.instant;
+        if let Some(code) = step.weather_code() {
+            self.codes.push(code);
+        }
+        let period_max = step.period.and_then(|p| p.details.air_temperature_max);
+        let period_min = step.period.and_then(|p| p.details.air_temperature_min);
+        self.temp_max = max_opt(max_opt(self.temp_max, details.air_temperature), period_max);
+        self.temp_min = min_opt(min_opt(self.temp_min, details.air_temperature), period_min);
+        self.uv_max = max_opt(self.uv_max, details.ultraviolet_index_clear_sky);
+        self.gust_max_ms = max_opt(self.gust_max_ms, details.wind_speed_of_gust);
+        self.precip_probability_max = max_opt(
+            self.precip_probability_max,
+            step.precipitation_probability(),
+        );
+        self.has_hourly |= step.period_hours == 1;
+
+        // Hourly and six-hourly periods overlap where the series changes resolution;
+        // only count a period once the previous one has ended.
+        if covered_until.is_some_and(|until| step.time < until) {
+            return;
+        }
+        let Some(amount) = step.precipitation_mm() else {
+            return;
+        };
+        *covered_until = Some(step.time + Duration::hours(step.period_hours));
+        let amount = amount.max(0.0);
+        let (rain, snow_cm) = split_precipitation(step.weather_code(), Some(amount));
+        self.precip_sum += amount;
+        self.rain_sum += rain.unwrap_or(0.0);
+        self.snow_sum_cm += snow_cm.unwrap_or(0.0);
+        if step.period_hours == 1 && amount >= 0.1 {
+            self.precip_hours += 1.0;
+        }
+    }
+}
+
+fn max_opt(current: Option<f32>, candidate: Option<f32>) -> Option<f32> {
+    match (current, candidate) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn min_opt(current: Option<f32>, candidate: Option<f32>) -> Option<f32> {
+    match (current, candidate) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn dominant_code(codes: &[u8]) -> Option<u8> {
+    let mut counts = [0usize; 100];
+    let mut best: Option<(usize, u8)> = None;
+    for &code in codes.iter().filter(|&&code| code < 100) {
+        counts[code as usize] += 1;
+        let count = counts[code as usize];
+        // Ties favour the more significant (higher) WMO code.
+        if best.is_none_or(|(best_count, best_code)| {
+            count > best_count || (count == best_count && code > best_code)
+        }) {
+            best = Some((count, code));
+        }
+    }
+    best.map(|(_, code)| code)
+}
+
+fn split_precipitation(
+    weather_code: Option<u8>,
+    precipitation_mm: Option<f32>,
+) -> (Option<f32>, Option<f32>) {
+    let Some(amount) = precipitation_mm else {
+        return (None, None);
+    };
+    if weather_code.is_some_and(is_snow_code) {
+        (Some(0.0), Some(amount * SNOW_CM_PER_MM))
+    } else {
+        (Some(amount), Some(0.0))
+    }
+}
+
+fn is_snow_code(code: u8) -> bool {
+    matches!(code, 71..=77 | 85 | 86)
+}
+
+fn ms_to_kmh(value: Option<f32>) -> Option<f32> {
+    value.map(|ms| ms * 3.6)
+}
+
+fn visibility_from_fog(fog_fraction: Option<f32>) -> f32 {
+    if fog_fraction.is_some_and(|fog| fog >= 50.0) {
+        FOG_VISIBILITY_M
+    } else {
+        CLEAR_VISIBILITY_M
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LocationforecastResponse {
+    properties: Properties,
+}
+
+#[derive(Debug, Deserialize)]
+struct Properties {
+    timeseries: Vec<TimeStep>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TimeStep {
+    time: String,
+    data: StepData,
+}
+
+#[derive(Debug, Deserialize)]
+struct StepData {
+    instant: Instant,
+    #[serde(default)]
+    next_1_hours: Option<Period>,
+    #[serde(default)]
+    next_6_hours: Option<Period>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Instant {
+    details: InstantDetails,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct InstantDetails {
+    air_pressure_at_sea_level: Option<f32>,
+    air_temperature: Option<f32>,
+    cloud_area_fraction: Option<f32>,
+    cloud_area_fraction_high: Option<f32>,
+    cloud_area_fraction_low: Option<f32>,
+    cloud_area_fraction_medium: Option<f32>,
+    dew_point_temperature: Option<f32>,
+    fog_area_fraction: Option<f32>,
+    relative_humidity: Option<f32>,
+    ultraviolet_index_clear_sky: Option<f32>,
+    wind_from_direction: Option<f32>,
+    wind_speed: Option<f32>,
+    wind_speed_of_gust: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Period {
+    #[serde(default)]
+    summary: Option<Summary>,
+    #[serde(default)]
+    details: PeriodDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct Summary {
+    symbol_code: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PeriodDetails {
+    precipitation_amount: Option<f32>,
+    probability_of_precipitation: Option<f32>,
+    air_temperature_max: Option<f32>,
+    air_temperature_min: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,38 @@
+fn symbol_base(symbol: &str) -> &str {
+    symbol.split('_').next().unwrap_or(symbol)
+}
+
+pub(super) fn symbol_is_day(symbol: &str) -> Option<bool> {
+    match symbol.rsplit_once('_').map(|(_, suffix)| suffix) {
+        Some("day" | "polartwilight") => Some(true),
+        Some("night") => Some(false),
+        _ => None,
+    }
+}
+
+/// Maps a MET Norway `symbol_code` onto the WMO weather code used across the app.
+pub(super) fn symbol_to_wmo(symbol: &str) -> u8 {
+    let base = symbol_base(symbol);
+    if base.contains("thunder") {
+        return 95;
+    }
+    match base {
+        "clearsky" => 0,
+        "fair" => 1,
+        "partlycloudy" => 2,
+        "cloudy" => 3,
+        "fog" => 45,
+        "lightrain" => 61,
+        "rain" => 63,
+        "heavyrain" => 65,
+        "lightrainshowers" => 80,
+        "rainshowers" => 81,
+        "heavyrainshowers" => 82,
+        "lightsleet" | "lightsleetshowers" | "lightsnow" => 71,
+        "sleet" | "sleetshowers" | "snow" => 73,
+        "heavysleet" | "heavysleetshowers" | "heavysnow" => 75,
+        "lightsnowshowers" | "snowshowers" => 85,
+        "heavysnowshowers" => 86,
+        _ => 3,
+    }
+}
@@ -0,0 +1,151 @@
+use super::*;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, query_param},
+};
+
+fn hourly_step(time: &str, temp: f32, symbol: &str, precip: f32) -> serde_json::Value {
+    serde_json::json!({
+        "time": time,
+        "data": {
+            "instant": { "details": {
+                "air_pressure_at_sea_level": 1012.0,
+                "air_temperature": temp,
+                "cloud_area_fraction": 80.0,
+                "relative_humidity": 70.0,
+                "wind_from_direction": 200.0,
+                "wind_speed": 5.0,
+                "wind_speed_of_gust": 10.0,
+                "ultraviolet_index_clear_sky": 2.0
+            }},
+            "next_1_hours": {
+                "summary": { "symbol_code": symbol },
+                "details": { "precipitation_amount": precip, "probability_of_precipitation": 40.0 }
+            },
+            "next_6_hours": {
+                "summary": { "symbol_code": symbol },
+                "details": { "precipitation_amount": precip * 6.0 }
+            }
+        }
+    })
+}
+
+fn six_hour_step(time: &str, temp: f32, symbol: &str, precip: f32) -> serde_json::Value {
+    serde_json::json!({
+        "time": time,
+        "data": {
+            "instant": { "details": { "air_temperature": temp } },
+            "next_6_hours": {
+                "summary": { "symbol_code": symbol },
+                "details": {
+                    "precipitation_amount": precip,
+                    "air_temperature_max": temp + 2.0,
+                    "air_temperature_min": temp - 2.0
+                }
+            }
+        }
+    })
+}
+
+fn sample_payload() -> serde_json::Value {
+    serde_json::json!({
+        "type": "Feature",
+        "properties": {
+            "timeseries": [
+                hourly_step("2026-02-12T10:00:00Z", 4.0, "cloudy", 0.0),
+                hourly_step("2026-02-12T11:00:00Z", 5.0, "lightrain", 0.5),
+                hourly_step("2026-02-12T12:00:00Z", 6.0, "rain_day", 1.0),
+                six_hour_step("2026-02-13T00:00:00Z", 1.0, "snow", 3.0),
+                six_hour_step("2026-02-13T06:00:00Z", 2.0, "clearsky_day", 0.0)
+            ]
+        }
+    })
+}
+
+fn sample_bundle() -> ForecastBundle {
+    let payload: LocationforecastResponse =
+        serde_json::from_value(sample_payload()).expect("fixture parses");
+    bundle_from_payload(Location::from_coords(59.91, 10.75), &payload, 7).expect("bundle")
+}
+
+#[test]
+fn symbol_codes_map_to_wmo_categories() {
+    assert_eq!(symbol_to_wmo("clearsky_day"), 0);
+    assert_eq!(symbol_to_wmo("fair_night"), 1);
+    assert_eq!(symbol_to_wmo("fog"), 45);
+    assert_eq!(symbol_to_wmo("heavyrainshowers_polartwilight"), 82);
+    assert_eq!(symbol_to_wmo("lightsnow"), 71);
+    assert_eq!(symbol_to_wmo("rainandthunder"), 95);
+    assert_eq!(symbol_to_wmo("unknown_symbol"), 3);
+}
+
+#[test]
+fn symbol_suffix_determines_day_and_night() {
+    assert_eq!(symbol_is_day("clearsky_day"), Some(true));
+    assert_eq!(symbol_is_day("clearsky_night"), Some(false));
+    assert_eq!(symbol_is_day("cloudy"), None);
+}
+
+#[test]
+fn nominal_offset_follows_longitude() {
+    assert_eq!(nominal_utc_offset(10.75), Duration::hours(1));
+    assert_eq!(nominal_utc_offset(-74.0), Duration::hours(-5));
+    assert_eq!(nominal_utc_offset(179.9), Duration::hours(12));
+}
+
+#[test]
+fn bundle_converts_units_and_shifts_to_local_time() {
+    let bundle = sample_bundle();
+    assert_eq!(bundle.hourly.len(), 3);
+    assert_eq!(bundle.hourly[0].time.format("%H:%M").to_string(), "11:00");
+    assert_eq!(bundle.utc_offset_seconds, 3600);
+    assert_eq!(bundle.hourly[0].wind_speed_10m, Some(18.0));
+    assert_eq!(bundle.current.weather_code, 3);
+    assert!((bundle.current.wind_gusts_10m - 36.0).abs() < f32::EPSILON);
+    assert!(bundle.air_quality.is_none());
+}
+
+#[test]
+fn daily_rows_aggregate_hourly_and_six_hour_periods() {
+    let bundle = sample_bundle();
+    assert_eq!(bundle.daily.len(), 2);
+
+    let today = &bundle.daily[0];
+    assert_eq!(today.temperature_max_c, Some(6.0));
+    assert_eq!(today.temperature_min_c, Some(4.0));
+    assert_eq!(today.precipitation_sum_mm, Some(1.5));
+    assert_eq!(today.precipitation_hours, Some(2.0));
+    assert_eq!(bundle.current.high_today_c, Some(6.0));
+
+    let tomorrow = &bundle.daily[1];
+    assert_eq!(tomorrow.weather_code, Some(73));
+    assert_eq!(tomorrow.temperature_max_c, Some(4.0));
+    assert_eq!(tomorrow.snowfall_sum_cm, Some(3.0 * SNOW_CM_PER_MM));
+    assert_eq!(tomorrow.precipitation_hours, None);
+}
+
+#[test]
+fn empty_timeseries_is_an_error() {
+    let payload: LocationforecastResponse =
+        serde_json::from_value(serde_json::json!({ "properties": { "timeseries": [] } }))
+            .expect("empty payload parses");
+    assert!(bundle_from_payload(Location::from_coords(0.0, 0.0), &payload, 7).is_err());
+}
+
+#[tokio::test]
+async fn fetch_requests_truncated_coordinates() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(query_param("lat", "59.9139"))
+        .and(query_param("lon", "10.7522"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(sample_payload()))
+        .mount(&server)
+        .await;
+
+    let client = MetNorwayClient::with_base_url(server.uri()).expect("client");
+    let bundle = client
+        .fetch(Location::from_coords(59.913_868, 10.752_245))
+        .await
+        .expect("fetch succeeds");
+    assert_eq!(bundle.hourly.len(), 3);
+}
@@ -2,3 +2,5 @@ pub mod forecast;
 pub mod geocode;
 pub mod geoip;
 pub(crate) mod http;
+pub mod met_norway;
+pub mod provider;
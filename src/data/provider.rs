@@ -0,0 +1,118 @@
+#![allow(clippy::missing_errors_doc)]
+
+use std::future::Future;
+
+use anyhow::Result;
+
+use crate::cli::ProviderArg;
+use crate::data::{forecast::ForecastClient, met_norway::MetNorwayClient};
+use crate::domain::weather::{ForecastBundle, Location};
+
+/// A forecast source that can produce a complete [`ForecastBundle`] for a location.
+pub trait WeatherProvider {
+    fn fetch(&self, location: Location) -> impl Future<Output = Result<ForecastBundle>> + Send;
+}
+
+impl WeatherProvider for ForecastClient {
+    fn fetch(&self, location: Location) -> impl Future<Output = Result<ForecastBundle>> + Send {
+        ForecastClient::fetch(self, location)
+    }
+}
+
+impl WeatherProvider for MetNorwayClient {
+    fn fetch(&self, location: Location) -> impl Future<Output = Result<ForecastBundle>> + Send {
+        MetNorwayClient::fetch(self, location)
+    }
+}
+
+/// Runtime-selected provider. Dispatches to the concrete client chosen via
+/// `--provider` or the persisted `provider` setting.
+#[derive(Debug, Clone)]
+pub enum ForecastProvider {
+    OpenMeteo(ForecastClient),
+    MetNorway(MetNorwayClient),
+}
+
+impl ForecastProvider {
+    /// Builds the provider for `kind`. `forecast_url` replaces the provider's
+    /// forecast endpoint; `air_quality_url` only applies to Open-Meteo, which is
+    /// the only provider with an air-quality feed.
+    pub fn build(
+        kind: ProviderArg,
+        forecast_url: Option<&str>,
+        air_quality_url: Option<&str>,
+    ) -> Result<Self> {
+        match kind {
+            ProviderArg::OpenMeteo => {
+                build_open_meteo(forecast_url, air_quality_url).map(Self::OpenMeteo)
+            }
+            ProviderArg::MetNorway => {
+                let client = match forecast_url {
+                    Some(url) => MetNorwayClient::with_base_url(url)?,
+                    None => MetNorwayClient::new()?,
+                };
+                Ok(Self::MetNorway(client))
+            }
+        }
+    }
+
+    /// Sets the forecast horizon in days; see `--forecast-days`.
+    #[must_use]
+    pub fn with_forecast_days(self, days: u8) -> Self {
+        match self {
+            Self::OpenMeteo(client) => Self::OpenMeteo(client.with_forecast_days(days)),
+            Self::MetNorway(client) => Self::MetNorway(client.with_forecast_days(days)),
+        }
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> ProviderArg {
+        match self {
+            Self::OpenMeteo(_) => ProviderArg::OpenMeteo,
+            Self::MetNorway(_) => ProviderArg::MetNorway,
+        }
+    }
+}
+
+impl WeatherProvider for ForecastProvider {
+    async fn fetch(&self, location: Location) -> Result<ForecastBundle> {
+        match self {
+            Self::OpenMeteo(client) => client.fetch(location).await,
+            Self::MetNorway(client) => client.fetch(location).await,
+        }
+    }
+}
+
+fn build_open_meteo(
+    forecast_url: Option<&str>,
+    air_quality_url: Option<&str>,
+) -> Result<ForecastClient> {
+    match (forecast_url, air_quality_url) {
+        (Some(forecast_url), Some(air_quality_url)) => {
+            ForecastClient::with_urls(forecast_url, air_quality_url)
+        }
+        (Some(forecast_url), None) => ForecastClient::with_base_url(forecast_url),
+        (None, Some(air_quality_url)) => {
+            Ok(ForecastClient::new()?.with_air_quality_url(air_quality_url))
+        }
+        (None, None) => ForecastClient::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_selects_requested_provider() {
+        let provider =
+            ForecastProvider::build(ProviderArg::OpenMeteo, Some("http://127.0.0.1:1"), None)
+                .expect("open-meteo provider");
+        assert_eq!(provider.kind(), ProviderArg::OpenMeteo);
+
+        let provider =
+            ForecastProvider::build(ProviderArg::MetNorway, Some("http://127.0.0.1:1"), None)
+                .expect("met norway provider");
+        assert_eq!(provider.kind(), ProviderArg::MetNorway);
+    }
+}
@@ -1,223 +1,372 @@
 #![allow(clippy::cast_possible_truncation)]
 
+use std::ops::Range;
+use std::str::FromStr;
+
+use chrono::{Duration, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
 use crate::domain::weather::{
-    ForecastBundle, HourlyForecast, Units, convert_temp, round_temp, round_wind_speed,
+    DailyForecast, DistanceUnit, ForecastBundle, HourlyForecast, MeasureUnits, PrecipUnit, Units,
+    WindUnit, convert_temp, convert_wind_speed, round_temp,
 };
 
+mod rules;
+mod window;
+
+pub use rules::{AlertMetric, AlertRule, RuleComparison, RuleUnit};
+pub use window::{AlertWindow, MERGE_GAP_HOURS};
+
+// Thresholds are in canonical units: m/s for wind, millimetres for
+// precipitation, metres for visibility and °C for temperature. Providers
+// report wind in km/h, so gusts are converted before they are compared; the
+// user's unit preferences only affect how a triggered alert is worded.
+
+/// Near-gale gusts (50 km/h).
+pub const GUST_WARNING_MS: f32 = 50.0 / 3.6;
+/// Storm-force gusts (80 km/h).
+pub const GUST_DANGER_MS: f32 = 80.0 / 3.6;
+/// Precipitation total within [`HEAVY_PRECIP_HOURS`] that counts as heavy.
+pub const HEAVY_PRECIP_MM: f32 = 25.0;
+/// Length of the rolling window that precipitation is totalled over.
+pub const HEAVY_PRECIP_HOURS: usize = 24;
+pub const LOW_VISIBILITY_M: f32 = 1_000.0;
+pub const EXTREME_HEAT_C: f32 = 38.0;
+pub const EXTREME_COLD_C: f32 = -15.0;
+
 #[derive(Debug, Clone)]
 pub struct WeatherAlert {
     pub icon: &'static str,
     pub message: String,
+    /// Hours from now until the window starts. `None` for a daily alert that
+    /// covers today.
     pub eta_hours: Option<usize>,
     pub severity: AlertSeverity,
+    pub window: Option<AlertWindow>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+impl WeatherAlert {
+    /// `now`, `in 3h`, or `today` for alerts without an hourly ETA.
+    #[must_use]
+    pub fn eta_label(&self) -> String {
+        match self.eta_hours {
+            None => "today".to_string(),
+            Some(0) => "now".to_string(),
+            Some(hours) => format!("in {hours}h"),
+        }
+    }
+
+    /// When the alert's window falls, as in `14:00–21:00` or `Sat–Mon`.
+    #[must_use]
+    pub fn window_label(&self) -> Option<String> {
+        let window = self.window?;
+        // The ETA counts from the first forecast hour, which is today.
+        let today = self.eta_hours.map_or(window.start.date(), |hours| {
+            (window.start - Duration::hours(i64::try_from(hours).unwrap_or_default())).date()
+        });
+        Some(window.label(today))
+    }
+
+    /// The ETA followed by the window, as in `in 3h · 14:00–21:00`, or just
+    /// the days for a daily alert such as `Sat–Mon`.
+    #[must_use]
+    pub fn timing_label(&self) -> String {
+        match (self.window_label(), self.window) {
+            (Some(span), Some(window)) if self.eta_hours.is_some() && !window.is_whole_days() => {
+                format!("{} · {span}", self.eta_label())
+            }
+            (Some(span), _) => span,
+            (None, _) => self.eta_label(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AlertSeverity {
     Info,
     Warning,
     Danger,
 }
 
+impl AlertSeverity {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Danger => "danger",
+        }
+    }
+}
+
+impl FromStr for AlertSeverity {
+    type Err = String;
+
+    fn from_str(label: &str) -> Result<Self, Self::Err> {
+        match label.to_ascii_lowercase().as_str() {
+            "info" => Ok(Self::Info),
+            "warning" => Ok(Self::Warning),
+            "danger" => Ok(Self::Danger),
+            _ => Err(format!(
+                "unknown severity '{label}', expected info, warning or danger"
+            )),
+        }
+    }
+}
+
+/// Built-in alerts and any that `rules` raise over the whole forecast, one per
+/// spell of matching weather, most severe first and then soonest first.
 #[must_use]
-pub fn scan_alerts(bundle: &ForecastBundle, units: Units) -> Vec<WeatherAlert> {
+pub fn scan_alerts(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+    rules: &[AlertRule],
+) -> Vec<WeatherAlert> {
+    let hourly = bundle.hourly.as_slice();
     let mut alerts = Vec::new();
-    let next_24h = next_24h_hours(bundle);
-
-    push_alert(&mut alerts, wind_gust_alert(next_24h));
-    push_alert(&mut alerts, uv_alert(bundle));
-    push_alert(&mut alerts, freezing_alert(next_24h));
-    push_alert(&mut alerts, heavy_precip_alert(next_24h));
-    push_alert(&mut alerts, low_visibility_alert(next_24h));
-    push_alert(&mut alerts, extreme_heat_alert(next_24h, units));
-    push_alert(&mut alerts, extreme_cold_alert(next_24h, units));
-    push_alert(&mut alerts, thunder_alert(next_24h));
-
-    alerts.sort_by_key(|alert| std::cmp::Reverse(alert.severity));
+
+    alerts.extend(wind_gust_alerts(hourly, measures.wind));
+    alerts.extend(uv_alerts(bundle));
+    alerts.extend(freezing_alerts(hourly));
+    alerts.extend(heavy_precip_alerts(hourly, measures.precip));
+    alerts.extend(low_visibility_alerts(hourly, measures.distance));
+    alerts.extend(extreme_heat_alerts(hourly, units));
+    alerts.extend(extreme_cold_alerts(hourly, units));
+    alerts.extend(thunder_alerts(hourly));
+    for rule in rules {
+        alerts.extend(rule.evaluate(hourly, units, measures));
+    }
+
+    alerts.sort_by_key(|alert| {
+        (
+            std::cmp::Reverse(alert.severity),
+            alert.window.map(|window| window.start),
+        )
+    });
     alerts
 }
 
-fn push_alert(alerts: &mut Vec<WeatherAlert>, alert: Option<WeatherAlert>) {
-    if let Some(alert) = alert {
-        alerts.push(alert);
+/// An alert over `hours[range]`, which starts `range.start` hours from now.
+fn hourly_alert(
+    hours: &[HourlyForecast],
+    range: Range<usize>,
+    icon: &'static str,
+    message: String,
+    severity: AlertSeverity,
+) -> WeatherAlert {
+    WeatherAlert {
+        icon,
+        message,
+        eta_hours: Some(range.start),
+        severity,
+        window: AlertWindow::for_hours(hours, range),
     }
 }
 
-fn next_24h_hours(bundle: &ForecastBundle) -> &[HourlyForecast] {
-    &bundle.hourly[..bundle.hourly.len().min(24)]
+fn max_in(hours: &[HourlyForecast], value: impl Fn(&HourlyForecast) -> Option<f32>) -> f32 {
+    hours
+        .iter()
+        .filter_map(value)
+        .max_by(f32::total_cmp)
+        .unwrap_or_default()
 }
 
-fn wind_gust_alert(next_24h: &[HourlyForecast]) -> Option<WeatherAlert> {
-    let max_gust = next_24h
+fn min_in(hours: &[HourlyForecast], value: impl Fn(&HourlyForecast) -> Option<f32>) -> f32 {
+    hours
         .iter()
-        .filter_map(|h| h.wind_gusts_10m)
-        .max_by(f32::total_cmp)?;
-    let eta_hours = first_hour_index(next_24h, |hour| {
-        hour.wind_gusts_10m.is_some_and(|gust| gust >= 50.0)
-    });
-    if max_gust >= 80.0 {
-        return Some(WeatherAlert {
-            icon: "⚡",
-            message: format!("Forecast gusts up to {} m/s", round_wind_speed(max_gust)),
-            eta_hours,
-            severity: AlertSeverity::Danger,
-        });
-    }
-    if max_gust >= 50.0 {
-        return Some(WeatherAlert {
-            icon: "💨",
-            message: format!("Forecast gusts up to {} m/s", round_wind_speed(max_gust)),
-            eta_hours,
-            severity: AlertSeverity::Warning,
-        });
-    }
-    None
+        .filter_map(value)
+        .min_by(f32::total_cmp)
+        .unwrap_or_default()
 }
 
-fn uv_alert(bundle: &ForecastBundle) -> Option<WeatherAlert> {
-    let uv = bundle.daily.first().and_then(|d| d.uv_index_max)?;
-    if uv >= 8.0 {
-        return Some(WeatherAlert {
-            icon: "☀",
-            message: format!("UV index very high ({uv:.0})"),
-            eta_hours: None,
-            severity: AlertSeverity::Danger,
-        });
-    }
-    if uv >= 6.0 {
-        return Some(WeatherAlert {
+fn wind_gust_alerts(hourly: &[HourlyForecast], wind: WindUnit) -> Vec<WeatherAlert> {
+    window::episodes(hourly, |hour| {
+        hour.wind_gusts_10m
+            .is_some_and(|gust| convert_wind_speed(gust) >= GUST_WARNING_MS)
+    })
+    .into_iter()
+    .map(|range| {
+        let max_gust = max_in(&hourly[range.clone()], |hour| hour.wind_gusts_10m);
+        let (icon, severity) = if convert_wind_speed(max_gust) >= GUST_DANGER_MS {
+            ("⚡", AlertSeverity::Danger)
+        } else {
+            ("💨", AlertSeverity::Warning)
+        };
+        let message = format!("Forecast gusts up to {}", wind.format(max_gust));
+        hourly_alert(hourly, range, icon, message, severity)
+    })
+    .collect()
+}
+
+/// One alert per run of days with a high UV index, spanning those days.
+fn uv_alerts(bundle: &ForecastBundle) -> Vec<WeatherAlert> {
+    let high = |day: &DailyForecast| day.uv_index_max.is_some_and(|uv| uv >= 6.0);
+    let mut alerts = Vec::new();
+    let mut days = bundle.daily.iter().peekable();
+    while let Some(first) = days.next() {
+        if !high(first) {
+            continue;
+        }
+        let mut run = vec![first];
+        while let Some(day) = days.next_if(|day| high(day)) {
+            run.push(day);
+        }
+        let uv = run
+            .iter()
+            .filter_map(|day| day.uv_index_max)
+            .fold(0.0_f32, f32::max);
+        let (message, severity) = if uv >= 8.0 {
+            (
+                format!("UV index very high ({uv:.0})"),
+                AlertSeverity::Danger,
+            )
+        } else {
+            (format!("UV index high ({uv:.0})"), AlertSeverity::Warning)
+        };
+        let last = run.last().map_or(first.date, |day| day.date);
+        alerts.push(WeatherAlert {
             icon: "☀",
-            message: format!("UV index high ({uv:.0})"),
-            eta_hours: None,
-            severity: AlertSeverity::Warning,
+            message,
+            eta_hours: hours_until(&bundle.hourly, first.date),
+            severity,
+            window: Some(AlertWindow::for_days(first.date, last)),
         });
     }
-    None
+    alerts
 }
 
-fn freezing_alert(next_24h: &[HourlyForecast]) -> Option<WeatherAlert> {
-    let has_freezing = next_24h.iter().any(|h| {
-        h.weather_code
-            .is_some_and(|c| matches!(c, 56 | 57 | 66 | 67))
-    });
-    if has_freezing {
-        Some(WeatherAlert {
-            icon: "❄",
-            message: "Freezing rain/drizzle expected".to_string(),
-            eta_hours: first_hour_index(next_24h, |hour| {
-                hour.weather_code
-                    .is_some_and(|code| matches!(code, 56 | 57 | 66 | 67))
-            }),
-            severity: AlertSeverity::Danger,
-        })
-    } else {
-        None
+/// Hours from the first forecast hour to the start of `date`; `None` when
+/// `date` is today.
+fn hours_until(hourly: &[HourlyForecast], date: NaiveDate) -> Option<usize> {
+    let now = hourly.first()?.time;
+    if date <= now.date() {
+        return None;
     }
+    usize::try_from((date.and_time(NaiveTime::MIN) - now).num_hours()).ok()
 }
 
-fn heavy_precip_alert(next_24h: &[HourlyForecast]) -> Option<WeatherAlert> {
-    let total_precip: f32 = next_24h
-        .iter()
-        .filter_map(|h| h.precipitation_mm)
-        .map(|p| p.max(0.0))
-        .sum();
-    if total_precip >= 25.0 {
-        Some(WeatherAlert {
-            icon: "🌧",
-            message: format!("Heavy precipitation: {total_precip:.1}mm in 24h"),
-            eta_hours: first_hour_index(next_24h, |hour| {
-                hour.precipitation_mm.unwrap_or(0.0).max(0.0) >= 1.0
-            }),
-            severity: AlertSeverity::Warning,
-        })
-    } else {
-        None
-    }
+fn freezing_alerts(hourly: &[HourlyForecast]) -> Vec<WeatherAlert> {
+    window::episodes(hourly, |hour| {
+        hour.weather_code
+            .is_some_and(|code| matches!(code, 56 | 57 | 66 | 67))
+    })
+    .into_iter()
+    .map(|range| {
+        hourly_alert(
+            hourly,
+            range,
+            "❄",
+            "Freezing rain/drizzle expected".to_string(),
+            AlertSeverity::Danger,
+        )
+    })
+    .collect()
 }
 
-fn low_visibility_alert(next_24h: &[HourlyForecast]) -> Option<WeatherAlert> {
-    let min_vis = next_24h
+/// Stretches where some [`HEAVY_PRECIP_HOURS`] consecutive hours add up to
+/// [`HEAVY_PRECIP_MM`] or more, trimmed to their first and last wet hour.
+/// Overlapping windows join into one alert.
+fn heavy_precip_alerts(hourly: &[HourlyForecast], precip: PrecipUnit) -> Vec<WeatherAlert> {
+    let mm: Vec<f32> = hourly
         .iter()
-        .filter_map(|h| h.visibility_m)
-        .min_by(f32::total_cmp)?;
-    if min_vis < 1000.0 {
-        Some(WeatherAlert {
-            icon: "≡",
-            message: format!("Low visibility: {:.1}km", min_vis / 1000.0),
-            eta_hours: first_hour_index(next_24h, |hour| {
-                hour.visibility_m.unwrap_or(10_000.0) < 1000.0
-            }),
-            severity: AlertSeverity::Warning,
-        })
-    } else {
-        None
+        .map(|hour| hour.precipitation_mm.unwrap_or(0.0).max(0.0))
+        .collect();
+    let span = HEAVY_PRECIP_HOURS.min(mm.len());
+    let mut spells: Vec<Range<usize>> = Vec::new();
+    for start in 0..=mm.len() - span {
+        if mm[start..start + span].iter().sum::<f32>() < HEAVY_PRECIP_MM {
+            continue;
+        }
+        match spells.last_mut() {
+            Some(spell) if start <= spell.end => spell.end = start + span,
+            _ => spells.push(start..start + span),
+        }
     }
+    spells
+        .into_iter()
+        .filter_map(|spell| {
+            let first = spell.clone().find(|&idx| mm[idx] > 0.0)?;
+            let last = spell.rev().find(|&idx| mm[idx] > 0.0)?;
+            let range = first..last + 1;
+            let total: f32 = mm[range.clone()].iter().sum();
+            let message = format!(
+                "Heavy precipitation: {} in {}h",
+                precip.format(total),
+                range.len()
+            );
+            Some(hourly_alert(
+                hourly,
+                range,
+                "🌧",
+                message,
+                AlertSeverity::Warning,
+            ))
+        })
+        .collect()
 }
 
-fn extreme_heat_alert(next_24h: &[HourlyForecast], units: Units) -> Option<WeatherAlert> {
-    let max_temp = next_24h
-        .iter()
-        .filter_map(|h| h.temperature_2m_c)
-        .max_by(f32::total_cmp)?;
-    if max_temp >= 38.0 {
-        let display_temp = round_temp(convert_temp(max_temp, units));
-        Some(WeatherAlert {
-            icon: "🔥",
-            message: format!("Extreme heat: up to {display_temp}°{}", units.symbol()),
-            eta_hours: first_hour_index(next_24h, |hour| {
-                hour.temperature_2m_c.is_some_and(|temp| temp >= 38.0)
-            }),
-            severity: AlertSeverity::Danger,
-        })
-    } else {
-        None
-    }
+fn low_visibility_alerts(hourly: &[HourlyForecast], distance: DistanceUnit) -> Vec<WeatherAlert> {
+    window::episodes(hourly, |hour| {
+        hour.visibility_m
+            .is_some_and(|visibility| visibility < LOW_VISIBILITY_M)
+    })
+    .into_iter()
+    .map(|range| {
+        let min_vis = min_in(&hourly[range.clone()], |hour| hour.visibility_m);
+        let message = format!("Low visibility: {}", distance.format(min_vis));
+        hourly_alert(hourly, range, "≡", message, AlertSeverity::Warning)
+    })
+    .collect()
 }
 
-fn extreme_cold_alert(next_24h: &[HourlyForecast], units: Units) -> Option<WeatherAlert> {
-    let min_temp = next_24h
-        .iter()
-        .filter_map(|h| h.temperature_2m_c)
-        .min_by(f32::total_cmp)?;
-    if min_temp <= -15.0 {
-        let display_temp = round_temp(convert_temp(min_temp, units));
-        Some(WeatherAlert {
-            icon: "❄",
-            message: format!("Extreme cold: down to {display_temp}°{}", units.symbol()),
-            eta_hours: first_hour_index(next_24h, |hour| {
-                hour.temperature_2m_c.is_some_and(|temp| temp <= -15.0)
-            }),
-            severity: AlertSeverity::Danger,
-        })
-    } else {
-        None
-    }
+fn extreme_heat_alerts(hourly: &[HourlyForecast], units: Units) -> Vec<WeatherAlert> {
+    window::episodes(hourly, |hour| {
+        hour.temperature_2m_c
+            .is_some_and(|temp| temp >= EXTREME_HEAT_C)
+    })
+    .into_iter()
+    .map(|range| {
+        let max_temp = max_in(&hourly[range.clone()], |hour| hour.temperature_2m_c);
+        let display_temp = round_temp(convert_temp(max_temp, units));
+        let message = format!("Extreme heat: up to {display_temp}°{}", units.symbol());
+        hourly_alert(hourly, range, "🔥", message, AlertSeverity::Danger)
+    })
+    .collect()
 }
 
-fn thunder_alert(next_24h: &[HourlyForecast]) -> Option<WeatherAlert> {
-    let has_thunder = next_24h
-        .iter()
-        .any(|h| h.weather_code.is_some_and(|c| matches!(c, 95 | 96 | 99)));
-    if has_thunder {
-        Some(WeatherAlert {
-            icon: "⚡",
-            message: "Thunderstorms expected".to_string(),
-            eta_hours: first_hour_index(next_24h, |hour| {
-                hour.weather_code
-                    .is_some_and(|code| matches!(code, 95 | 96 | 99))
-            }),
-            severity: AlertSeverity::Warning,
-        })
-    } else {
-        None
-    }
+fn extreme_cold_alerts(hourly: &[HourlyForecast], units: Units) -> Vec<WeatherAlert> {
+    window::episodes(hourly, |hour| {
+        hour.temperature_2m_c
+            .is_some_and(|temp| temp <= EXTREME_COLD_C)
+    })
+    .into_iter()
+    .map(|range| {
+        let min_temp = min_in(&hourly[range.clone()], |hour| hour.temperature_2m_c);
+        let display_temp = round_temp(convert_temp(min_temp, units));
+        let message = format!("Extreme cold: down to {display_temp}°{}", units.symbol());
+        hourly_alert(hourly, range, "❄", message, AlertSeverity::Danger)
+    })
+    .collect()
 }
 
-fn first_hour_index(
-    next_24h: &[HourlyForecast],
-    predicate: impl Fn(&HourlyForecast) -> bool,
-) -> Option<usize> {
-    next_24h.iter().position(predicate)
+fn thunder_alerts(hourly: &[HourlyForecast]) -> Vec<WeatherAlert> {
+    window::episodes(hourly, |hour| {
+        hour.weather_code
+            .is_some_and(|code| matches!(code, 95 | 96 | 99))
+    })
+    .into_iter()
+    .map(|range| {
+        hourly_alert(
+            hourly,
+            range,
+            "⚡",
+            "Thunderstorms expected".to_string(),
+            AlertSeverity::Warning,
+        )
+    })
+    .collect()
 }
 
 #[cfg(test)]
@@ -233,7 +382,7 @@ mod tests {
         bundle.hourly[0].wind_gusts_10m = Some(90.0);
         bundle.daily[0].uv_index_max = Some(9.0);
 
-        let alerts = scan_alerts(&bundle, Units::Celsius);
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
         assert!(!alerts.is_empty());
         assert!(
             alerts
@@ -253,7 +402,7 @@ mod tests {
         bundle.hourly[0].temperature_2m_c = Some(40.0);
         bundle.hourly[1].temperature_2m_c = Some(-20.0);
 
-        let alerts = scan_alerts(&bundle, Units::Celsius);
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
         assert!(
             alerts
                 .iter()
@@ -274,6 +423,7 @@ mod tests {
             daily: vec![sample_daily_forecast()],
             air_quality: None,
             fetched_at: Utc::now(),
+            utc_offset_seconds: 0,
         }
     }
 
@@ -330,29 +480,99 @@ mod tests {
         let mut bundle = sample_bundle();
         bundle.hourly[0].wind_gusts_10m = Some(65.0);
 
-        let alerts = scan_alerts(&bundle, Units::Celsius);
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
         let wind = alerts.iter().find(|a| a.message.contains("gusts"));
         assert!(wind.is_some(), "expected a wind gust alert");
         assert_eq!(wind.unwrap().severity, AlertSeverity::Warning);
     }
 
+    #[test]
+    fn wind_gust_thresholds_include_50_and_80_kmh() {
+        let severity = |gust_kmh| {
+            let mut bundle = sample_bundle();
+            bundle.hourly[0].wind_gusts_10m = Some(gust_kmh);
+            scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[])
+                .into_iter()
+                .find(|a| a.message.contains("gusts"))
+                .map(|a| a.severity)
+        };
+
+        assert_eq!(severity(49.9), None);
+        assert_eq!(severity(50.0), Some(AlertSeverity::Warning));
+        assert_eq!(severity(79.9), Some(AlertSeverity::Warning));
+        assert_eq!(severity(80.0), Some(AlertSeverity::Danger));
+    }
+
+    #[test]
+    fn alert_messages_follow_measure_units() {
+        let mut bundle = sample_bundle();
+        bundle.hourly[0].wind_gusts_10m = Some(64.4);
+        bundle.hourly[1].visibility_m = Some(804.672);
+        for h in &mut bundle.hourly {
+            h.precipitation_mm = Some(2.54);
+        }
+        let measures = MeasureUnits {
+            wind: WindUnit::Mph,
+            precip: PrecipUnit::In,
+            distance: DistanceUnit::Mi,
+            ..MeasureUnits::default()
+        };
+
+        let messages: Vec<String> = scan_alerts(&bundle, Units::Fahrenheit, measures, &[])
+            .into_iter()
+            .map(|alert| alert.message)
+            .collect();
+        assert!(messages.contains(&"Forecast gusts up to 40 mph".to_string()));
+        assert!(messages.contains(&"Heavy precipitation: 2.40in in 24h".to_string()));
+        assert!(messages.contains(&"Low visibility: 0.5mi".to_string()));
+    }
+
     #[test]
     fn uv_warning_fires_between_6_and_7() {
         let mut bundle = sample_bundle();
         bundle.daily[0].uv_index_max = Some(7.0);
 
-        let alerts = scan_alerts(&bundle, Units::Celsius);
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
         let uv = alerts.iter().find(|a| a.message.contains("UV"));
         assert!(uv.is_some(), "expected a UV alert");
         assert_eq!(uv.unwrap().severity, AlertSeverity::Warning);
     }
 
+    #[test]
+    fn uv_alerts_span_runs_of_high_days() {
+        let mut bundle = sample_bundle();
+        let first = bundle.daily[0].clone();
+        bundle.daily = [5.0, 7.0, 9.0, 4.0, 6.0]
+            .into_iter()
+            .zip(0..)
+            .map(|(uv, offset)| DailyForecast {
+                date: first.date + Duration::days(offset),
+                uv_index_max: Some(uv),
+                ..first.clone()
+            })
+            .collect();
+
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
+        let labels: Vec<(String, String)> = alerts
+            .iter()
+            .map(|alert| (alert.message.clone(), alert.timing_label()))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                ("UV index very high (9)".to_string(), "Sat–Sun".to_string()),
+                ("UV index high (6)".to_string(), "Tue".to_string()),
+            ]
+        );
+        assert_eq!(alerts[0].eta_hours, Some(24));
+    }
+
     #[test]
     fn freezing_rain_alert_fires_for_code_56() {
         let mut bundle = sample_bundle();
         bundle.hourly[0].weather_code = Some(56);
 
-        let alerts = scan_alerts(&bundle, Units::Celsius);
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
         assert!(alerts.iter().any(|a| a.message.contains("Freezing")));
     }
 
@@ -363,23 +583,40 @@ mod tests {
             h.precipitation_mm = Some(2.0); // 24 * 2 = 48 mm total
         }
 
-        let alerts = scan_alerts(&bundle, Units::Celsius);
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
         assert!(alerts.iter().any(|a| a.message.contains("precipitation")));
     }
 
+    #[test]
+    fn heavy_precip_alert_counts_light_rain_between_showers() {
+        let mut bundle = sample_bundle();
+        for (idx, h) in bundle.hourly.iter_mut().enumerate() {
+            // 0.9 mm drizzle with a 3 mm shower every sixth hour: 30 mm in 24h
+            h.precipitation_mm = Some(if idx % 6 == 0 { 3.0 } else { 0.9 });
+        }
+
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
+        let alert = alerts
+            .iter()
+            .find(|a| a.message.starts_with("Heavy precipitation"))
+            .expect("heavy precipitation alert");
+        assert_eq!(alert.message, "Heavy precipitation: 30.0mm in 24h");
+        assert_eq!(alert.eta_hours, Some(0));
+    }
+
     #[test]
     fn low_visibility_alert_fires_below_1000m() {
         let mut bundle = sample_bundle();
         bundle.hourly[0].visibility_m = Some(500.0);
 
-        let alerts = scan_alerts(&bundle, Units::Celsius);
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
         assert!(alerts.iter().any(|a| a.message.contains("visibility")));
     }
 
     #[test]
     fn no_alerts_for_benign_conditions() {
         let bundle = sample_bundle();
-        let alerts = scan_alerts(&bundle, Units::Celsius);
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
         assert!(
             alerts.is_empty(),
             "benign conditions should produce no alerts"
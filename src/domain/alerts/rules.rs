@@ -0,0 +1,635 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlertSeverity, WeatherAlert, hourly_alert, window::episodes};
+use crate::domain::weather::{HourlyForecast, MeasureUnits, Units, convert_temp, round_temp};
+
+/// A user-defined alert, written and persisted as one line of text:
+///
+/// `gust > 40 km/h within 12h -> warning: Lower the crane`
+///
+/// Everything after the threshold is optional; without a window the whole
+/// forecast is checked, and the severity defaults to warning. The threshold
+/// keeps the unit it was written in and is converted when the rule is
+/// evaluated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AlertRule {
+    pub metric: AlertMetric,
+    pub comparison: RuleComparison,
+    pub threshold: f32,
+    pub unit: RuleUnit,
+    /// Hours from now the rule looks ahead, or the whole forecast when `None`.
+    pub within_hours: Option<usize>,
+    pub severity: AlertSeverity,
+    /// Replaces the generated message. `{value}` and `{threshold}` expand to
+    /// the extreme matching value and the rule's threshold.
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertMetric {
+    Gust,
+    Wind,
+    Temperature,
+    Precipitation,
+    Snowfall,
+    PrecipChance,
+    Humidity,
+    CloudCover,
+    Visibility,
+    Pressure,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleComparison {
+    Above,
+    AtLeast,
+    Below,
+    AtMost,
+}
+
+/// Units a threshold may be written in. Each belongs to one [`Quantity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleUnit {
+    Kmh,
+    Ms,
+    Mph,
+    Knots,
+    Celsius,
+    Fahrenheit,
+    Mm,
+    Cm,
+    Inch,
+    Metre,
+    Km,
+    Mile,
+    Hpa,
+    InHg,
+    MmHg,
+    Percent,
+}
+
+/// What a metric measures, which decides the units its threshold accepts and
+/// how its values are shown. Values are in the forecast's own units: km/h,
+/// °C, mm, metres, hPa and percent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quantity {
+    Speed,
+    Temperature,
+    Depth,
+    Distance,
+    Pressure,
+    Percent,
+}
+
+impl AlertRule {
+    /// One alert per spell of hours in the rule's window that cross the
+    /// threshold, reporting the most extreme value of the spell.
+    #[must_use]
+    pub fn evaluate(
+        &self,
+        hourly: &[HourlyForecast],
+        units: Units,
+        measures: MeasureUnits,
+    ) -> Vec<WeatherAlert> {
+        let window = &hourly[..hourly.len().min(self.within_hours.unwrap_or(usize::MAX))];
+        let threshold = self.unit.to_forecast_units(self.threshold);
+        episodes(window, |hour| {
+            self.metric
+                .value(hour)
+                .is_some_and(|value| self.comparison.holds(value, threshold))
+        })
+        .into_iter()
+        .filter_map(|range| {
+            let values = window[range.clone()]
+                .iter()
+                .filter_map(|hour| self.metric.value(hour))
+                .filter(|value| self.comparison.holds(*value, threshold));
+            let extreme = if self.comparison.is_upper_bound() {
+                values.max_by(f32::total_cmp)
+            } else {
+                values.min_by(f32::total_cmp)
+            }?;
+            let value = self.metric.quantity().format(extreme, units, measures);
+            Some(hourly_alert(
+                window,
+                range,
+                self.metric.icon(),
+                self.message(&value),
+                self.severity,
+            ))
+        })
+        .collect()
+    }
+
+    fn message(&self, value: &str) -> String {
+        let threshold = self.threshold_label();
+        match &self.message {
+            Some(template) => template
+                .replace("{value}", value)
+                .replace("{threshold}", &threshold),
+            None => format!(
+                "{} {value} {} {threshold}",
+                self.metric.label(),
+                self.comparison.symbol()
+            ),
+        }
+    }
+
+    fn threshold_label(&self) -> String {
+        match self.unit {
+            RuleUnit::Percent => format!("{}%", self.threshold),
+            RuleUnit::Celsius | RuleUnit::Fahrenheit => {
+                format!("{}{}", self.threshold, self.unit.symbol())
+            }
+            _ => format!("{} {}", self.threshold, self.unit.symbol()),
+        }
+    }
+}
+
+impl FromStr for AlertRule {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.replace('→', "->");
+        let (head, message) = match text.split_once(':') {
+            Some((head, message)) => (head, Some(message.trim().to_string())),
+            None => (text.as_str(), None),
+        };
+        let (condition, severity) = match head.split_once("->") {
+            Some((condition, severity)) => (condition, AlertSeverity::from_str(severity.trim())?),
+            None => (head, AlertSeverity::Warning),
+        };
+
+        let op_start = condition
+            .find(['<', '>'])
+            .ok_or_else(|| format!("'{}' needs a comparison: >, >=, < or <=", text.trim()))?;
+        let metric = AlertMetric::from_str(condition[..op_start].trim())?;
+        let rest = &condition[op_start..];
+        let (comparison, rest) = RuleComparison::split_prefix(rest);
+
+        let rest = rest.trim_start();
+        let number_end = rest
+            .find(|ch: char| !(ch.is_ascii_digit() || matches!(ch, '.' | '-' | '+')))
+            .unwrap_or(rest.len());
+        let threshold: f32 = rest[..number_end]
+            .parse()
+            .map_err(|_| format!("'{}' is not a number", &rest[..number_end]))?;
+
+        let (unit_text, within_hours) = match rest[number_end..].split_once("within") {
+            Some((unit_text, window)) => (unit_text, Some(parse_window(window)?)),
+            None => (&rest[number_end..], None),
+        };
+        let unit = match unit_text.trim() {
+            "" => metric.quantity().default_unit(),
+            unit_text => RuleUnit::from_str(unit_text)?,
+        };
+        if unit.quantity() != metric.quantity() {
+            return Err(format!(
+                "{} cannot be compared in {}",
+                metric.name(),
+                unit.symbol()
+            ));
+        }
+
+        Ok(Self {
+            metric,
+            comparison,
+            threshold,
+            unit,
+            within_hours,
+            severity,
+            message: message.filter(|message| !message.is_empty()),
+        })
+    }
+}
+
+fn parse_window(text: &str) -> Result<usize, String> {
+    let hours = text.trim().trim_end_matches("hours").trim_end_matches('h');
+    match hours.trim().parse() {
+        Ok(hours) if hours > 0 => Ok(hours),
+        _ => Err(format!(
+            "'within{text}' should be a number of hours, e.g. 12h"
+        )),
+    }
+}
+
+impl fmt::Display for AlertRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.metric.name(),
+            self.comparison.symbol(),
+            self.threshold_label()
+        )?;
+        if let Some(hours) = self.within_hours {
+            write!(f, " within {hours}h")?;
+        }
+        write!(f, " -> {}", self.severity.label())?;
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for AlertRule {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+impl From<AlertRule> for String {
+    fn from(rule: AlertRule) -> Self {
+        rule.to_string()
+    }
+}
+
+impl AlertMetric {
+    const NAMES: [(&'static str, Self); 12] = [
+        ("gust", Self::Gust),
+        ("gusts", Self::Gust),
+        ("wind", Self::Wind),
+        ("temp", Self::Temperature),
+        ("temperature", Self::Temperature),
+        ("precip", Self::Precipitation),
+        ("snow", Self::Snowfall),
+        ("precip_chance", Self::PrecipChance),
+        ("humidity", Self::Humidity),
+        ("cloud", Self::CloudCover),
+        ("visibility", Self::Visibility),
+        ("pressure", Self::Pressure),
+    ];
+
+    /// The name rules are written with.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Gust => "gust",
+            Self::Wind => "wind",
+            Self::Temperature => "temp",
+            Self::Precipitation => "precip",
+            Self::Snowfall => "snow",
+            Self::PrecipChance => "precip_chance",
+            Self::Humidity => "humidity",
+            Self::CloudCover => "cloud",
+            Self::Visibility => "visibility",
+            Self::Pressure => "pressure",
+        }
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Gust => "Gusts",
+            Self::Wind => "Wind",
+            Self::Temperature => "Temperature",
+            Self::Precipitation => "Precipitation",
+            Self::Snowfall => "Snowfall",
+            Self::PrecipChance => "Precip chance",
+            Self::Humidity => "Humidity",
+            Self::CloudCover => "Cloud cover",
+            Self::Visibility => "Visibility",
+            Self::Pressure => "Pressure",
+        }
+    }
+
+    const fn icon(self) -> &'static str {
+        match self {
+            Self::Gust | Self::Wind => "💨",
+            Self::Temperature => "🌡",
+            Self::Precipitation => "🌧",
+            Self::Snowfall => "❄",
+            Self::PrecipChance => "☂",
+            Self::Humidity => "💧",
+            Self::CloudCover => "☁",
+            Self::Visibility => "≡",
+            Self::Pressure => "◎",
+        }
+    }
+
+    const fn quantity(self) -> Quantity {
+        match self {
+            Self::Gust | Self::Wind => Quantity::Speed,
+            Self::Temperature => Quantity::Temperature,
+            Self::Precipitation | Self::Snowfall => Quantity::Depth,
+            Self::Visibility => Quantity::Distance,
+            Self::Pressure => Quantity::Pressure,
+            Self::PrecipChance | Self::Humidity | Self::CloudCover => Quantity::Percent,
+        }
+    }
+
+    /// The hour's value in the forecast's units; snowfall is converted from
+    /// centimetres to millimetres so every depth compares alike.
+    fn value(self, hour: &HourlyForecast) -> Option<f32> {
+        match self {
+            Self::Gust => hour.wind_gusts_10m,
+            Self::Wind => hour.wind_speed_10m,
+            Self::Temperature => hour.temperature_2m_c,
+            Self::Precipitation => hour.precipitation_mm,
+            Self::Snowfall => hour.snowfall_cm.map(|cm| cm * 10.0),
+            Self::PrecipChance => hour.precipitation_probability,
+            Self::Humidity => hour.relative_humidity_2m,
+            Self::CloudCover => hour.cloud_cover,
+            Self::Visibility => hour.visibility_m,
+            Self::Pressure => hour.pressure_msl_hpa,
+        }
+    }
+}
+
+impl FromStr for AlertMetric {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.to_ascii_lowercase();
+        Self::NAMES
+            .iter()
+            .find(|(candidate, _)| *candidate == name)
+            .map(|(_, metric)| *metric)
+            .ok_or_else(|| {
+                format!(
+                    "unknown metric '{name}', expected gust, wind, temp, precip, snow, \
+precip_chance, humidity, cloud, visibility or pressure"
+                )
+            })
+    }
+}
+
+impl RuleComparison {
+    /// Splits the leading operator off `text`, which starts with `<` or `>`.
+    fn split_prefix(text: &str) -> (Self, &str) {
+        if let Some(rest) = text.strip_prefix(">=") {
+            (Self::AtLeast, rest)
+        } else if let Some(rest) = text.strip_prefix("<=") {
+            (Self::AtMost, rest)
+        } else if let Some(rest) = text.strip_prefix('>') {
+            (Self::Above, rest)
+        } else {
+            (Self::Below, &text[1..])
+        }
+    }
+
+    const fn symbol(self) -> &'static str {
+        match self {
+            Self::Above => ">",
+            Self::AtLeast => ">=",
+            Self::Below => "<",
+            Self::AtMost => "<=",
+        }
+    }
+
+    const fn is_upper_bound(self) -> bool {
+        matches!(self, Self::Above | Self::AtLeast)
+    }
+
+    fn holds(self, value: f32, threshold: f32) -> bool {
+        match self {
+            Self::Above => value > threshold,
+            Self::AtLeast => value >= threshold,
+            Self::Below => value < threshold,
+            Self::AtMost => value <= threshold,
+        }
+    }
+}
+
+impl RuleUnit {
+    const SYMBOLS: [(&'static str, Self); 19] = [
+        ("km/h", Self::Kmh),
+        ("kmh", Self::Kmh),
+        ("m/s", Self::Ms),
+        ("mph", Self::Mph),
+        ("kn", Self::Knots),
+        ("knots", Self::Knots),
+        ("°c", Self::Celsius),
+        ("c", Self::Celsius),
+        ("°f", Self::Fahrenheit),
+        ("f", Self::Fahrenheit),
+        ("mm", Self::Mm),
+        ("cm", Self::Cm),
+        ("in", Self::Inch),
+        ("m", Self::Metre),
+        ("km", Self::Km),
+        ("mi", Self::Mile),
+        ("hpa", Self::Hpa),
+        ("inhg", Self::InHg),
+        ("mmhg", Self::MmHg),
+    ];
+
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Kmh => "km/h",
+            Self::Ms => "m/s",
+            Self::Mph => "mph",
+            Self::Knots => "kn",
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+            Self::Mm => "mm",
+            Self::Cm => "cm",
+            Self::Inch => "in",
+            Self::Metre => "m",
+            Self::Km => "km",
+            Self::Mile => "mi",
+            Self::Hpa => "hPa",
+            Self::InHg => "inHg",
+            Self::MmHg => "mmHg",
+            Self::Percent => "%",
+        }
+    }
+
+    const fn quantity(self) -> Quantity {
+        match self {
+            Self::Kmh | Self::Ms | Self::Mph | Self::Knots => Quantity::Speed,
+            Self::Celsius | Self::Fahrenheit => Quantity::Temperature,
+            Self::Mm | Self::Cm | Self::Inch => Quantity::Depth,
+            Self::Metre | Self::Km | Self::Mile => Quantity::Distance,
+            Self::Hpa | Self::InHg | Self::MmHg => Quantity::Pressure,
+            Self::Percent => Quantity::Percent,
+        }
+    }
+
+    fn to_forecast_units(self, value: f32) -> f32 {
+        match self {
+            Self::Ms => value * 3.6,
+            Self::Mph => value * 1.609_344,
+            Self::Knots => value * 1.852,
+            Self::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            Self::Cm => value * 10.0,
+            Self::Inch => value * 25.4,
+            Self::Km => value * 1000.0,
+            Self::Mile => value * 1_609.344,
+            Self::InHg => value / 0.029_529_983,
+            Self::MmHg => value / 0.750_061_7,
+            Self::Kmh | Self::Celsius | Self::Mm | Self::Metre | Self::Hpa | Self::Percent => value,
+        }
+    }
+}
+
+impl FromStr for RuleUnit {
+    type Err = String;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        let symbol = symbol.trim().to_ascii_lowercase();
+        if symbol == "%" {
+            return Ok(Self::Percent);
+        }
+        Self::SYMBOLS
+            .iter()
+            .find(|(candidate, _)| *candidate == symbol)
+            .map(|(_, unit)| *unit)
+            .ok_or_else(|| format!("unknown unit '{symbol}'"))
+    }
+}
+
+impl Quantity {
+    const fn default_unit(self) -> RuleUnit {
+        match self {
+            Self::Speed => RuleUnit::Ms,
+            Self::Temperature => RuleUnit::Celsius,
+            Self::Depth => RuleUnit::Mm,
+            Self::Distance => RuleUnit::Metre,
+            Self::Pressure => RuleUnit::Hpa,
+            Self::Percent => RuleUnit::Percent,
+        }
+    }
+
+    fn format(self, value: f32, units: Units, measures: MeasureUnits) -> String {
+        match self {
+            Self::Speed => measures.wind.format(value),
+            Self::Temperature => {
+                format!(
+                    "{}°{}",
+                    round_temp(convert_temp(value, units)),
+                    units.symbol()
+                )
+            }
+            Self::Depth => measures.precip.format(value),
+            Self::Distance => measures.distance.format(value),
+            Self::Pressure => measures.pressure.format(value),
+            Self::Percent => format!("{value:.0}%"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::weather::{PrecipUnit, WindUnit};
+    use crate::test_support::sample_bundle;
+
+    fn rule(text: &str) -> AlertRule {
+        text.parse().expect("valid rule")
+    }
+
+    #[test]
+    fn parses_full_and_minimal_rules() {
+        let full = rule("gust > 40 km/h within 12h -> warning: Lower the crane");
+        assert_eq!(full.metric, AlertMetric::Gust);
+        assert_eq!(full.comparison, RuleComparison::Above);
+        assert!((full.threshold - 40.0).abs() < f32::EPSILON);
+        assert_eq!(full.unit, RuleUnit::Kmh);
+        assert_eq!(full.within_hours, Some(12));
+        assert_eq!(full.severity, AlertSeverity::Warning);
+        assert_eq!(full.message.as_deref(), Some("Lower the crane"));
+
+        let minimal = rule("temp<=-2");
+        assert_eq!(minimal.comparison, RuleComparison::AtMost);
+        assert_eq!(minimal.unit, RuleUnit::Celsius);
+        assert_eq!(minimal.within_hours, None);
+        assert_eq!(minimal.to_string(), "temp <= -2°C -> warning");
+        assert_eq!(minimal.severity, AlertSeverity::Warning);
+        assert_eq!(
+            rule("humidity >= 95% → danger").severity,
+            AlertSeverity::Danger
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_rules() {
+        assert!("gust 40".parse::<AlertRule>().is_err());
+        assert!("breeze > 40".parse::<AlertRule>().is_err());
+        assert!("gust > fast".parse::<AlertRule>().is_err());
+        assert!("gust > 40 mm".parse::<AlertRule>().is_err());
+        assert!("gust > 40 km/h within soon".parse::<AlertRule>().is_err());
+        assert!("gust > 40 -> severe".parse::<AlertRule>().is_err());
+    }
+
+    #[test]
+    fn display_round_trips_through_serde() {
+        let original = rule("precip >= 2.5 mm within 6h -> info: Cover the beds at {value}");
+        assert_eq!(
+            original.to_string(),
+            "precip >= 2.5 mm within 6h -> info: Cover the beds at {value}"
+        );
+        let json = serde_json::to_string(&original).expect("serialize");
+        let restored: AlertRule = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn evaluate_converts_threshold_and_reports_extreme_value() {
+        let mut bundle = sample_bundle();
+        let base = bundle.hourly[0].clone();
+        bundle.hourly = (0..6)
+            .map(|idx| {
+                let mut hour = base.clone();
+                hour.time += chrono::Duration::hours(idx);
+                hour.wind_gusts_10m = Some([20.0, 30.0, 70.0, 90.0, 40.0, 95.0][idx as usize]);
+                hour
+            })
+            .collect();
+        let measures = MeasureUnits {
+            wind: WindUnit::Kmh,
+            ..MeasureUnits::default()
+        };
+
+        // 15 m/s is 54 km/h; the 95 km/h hour is outside the window.
+        let alerts = rule("gust > 15 m/s within 5h -> danger").evaluate(
+            &bundle.hourly,
+            Units::Celsius,
+            measures,
+        );
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].eta_hours, Some(2));
+        assert_eq!(alerts[0].severity, AlertSeverity::Danger);
+        assert_eq!(alerts[0].message, "Gusts 90 km/h > 15 m/s");
+        assert_eq!(alerts[0].timing_label(), "in 2h · 12:00–14:00");
+
+        let whole_forecast =
+            rule("gust > 15 m/s").evaluate(&bundle.hourly, Units::Celsius, measures);
+        assert_eq!(whole_forecast[0].message, "Gusts 95 km/h > 15 m/s");
+        assert_eq!(whole_forecast[0].timing_label(), "in 2h · 12:00–16:00");
+
+        assert!(
+            rule("gust > 100 km/h")
+                .evaluate(&bundle.hourly, Units::Celsius, measures)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn evaluate_fills_message_template_in_display_units() {
+        let mut bundle = sample_bundle();
+        bundle.hourly[0].temperature_2m_c = Some(-4.0);
+        bundle.hourly[0].snowfall_cm = Some(3.0);
+
+        let frost = rule("temp < 32 F -> warning: Frost {value} (limit {threshold})")
+            .evaluate(&bundle.hourly, Units::Fahrenheit, MeasureUnits::default())
+            .remove(0);
+        assert_eq!(frost.message, "Frost 25°F (limit 32°F)");
+
+        let measures = MeasureUnits {
+            precip: PrecipUnit::In,
+            ..MeasureUnits::default()
+        };
+        let snow = rule("snow >= 1 in")
+            .evaluate(&bundle.hourly, Units::Celsius, measures)
+            .remove(0);
+        assert_eq!(snow.message, "Snowfall 1.18in >= 1 in");
+    }
+}
@@ -0,0 +1,150 @@
+use std::ops::Range;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::domain::weather::HourlyForecast;
+
+/// Quiet hours that may separate two runs of matching hours before they count
+/// as separate alerts, so a gust forecast hovering at the threshold reads as
+/// one windy spell.
+pub const MERGE_GAP_HOURS: usize = 2;
+
+/// When an alert's conditions hold: from the start of its first hour to the
+/// end of its last. Alerts found in the daily forecast cover whole days, from
+/// midnight to midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlertWindow {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+impl AlertWindow {
+    /// The window over `hours[range]`, each hour lasting until the next.
+    #[must_use]
+    pub fn for_hours(hours: &[HourlyForecast], range: Range<usize>) -> Option<Self> {
+        let start = hours.get(range.start)?.time;
+        let last = hours.get(range.end.checked_sub(1)?)?.time;
+        Some(Self {
+            start,
+            end: last + Duration::hours(1),
+        })
+    }
+
+    /// The window over the whole days `first..=last`.
+    #[must_use]
+    pub fn for_days(first: NaiveDate, last: NaiveDate) -> Self {
+        Self {
+            start: first.and_time(NaiveTime::MIN),
+            end: (last + Duration::days(1)).and_time(NaiveTime::MIN),
+        }
+    }
+
+    #[must_use]
+    pub fn is_whole_days(&self) -> bool {
+        self.start.time() == NaiveTime::MIN && self.end.time() == NaiveTime::MIN
+    }
+
+    /// `14:00–21:00` on `today`, `Sat 14:00–21:00` or `Fri 22:00–Sat 04:00`
+    /// later, and `today`, `Sat` or `Sat–Mon` for whole days.
+    #[must_use]
+    pub fn label(&self, today: NaiveDate) -> String {
+        let day = |date: NaiveDate| {
+            if date == today {
+                "today".to_string()
+            } else {
+                date.format("%a").to_string()
+            }
+        };
+        if self.is_whole_days() {
+            let last = self.end.date() - Duration::days(1);
+            return if last <= self.start.date() {
+                day(self.start.date())
+            } else {
+                format!("{}–{}", day(self.start.date()), day(last))
+            };
+        }
+        let start = if self.start.date() == today {
+            self.start.format("%H:%M").to_string()
+        } else {
+            self.start.format("%a %H:%M").to_string()
+        };
+        let ends_same_day = self.end.date() == self.start.date()
+            || (self.end.time() == NaiveTime::MIN
+                && self.end.date() == self.start.date() + Duration::days(1));
+        let end = if ends_same_day {
+            self.end.format("%H:%M")
+        } else {
+            self.end.format("%a %H:%M")
+        };
+        format!("{start}–{end}")
+    }
+}
+
+/// Index ranges of the runs of `hours` where `predicate` holds, joining runs
+/// separated by at most [`MERGE_GAP_HOURS`] quiet hours.
+pub(super) fn episodes(
+    hours: &[HourlyForecast],
+    predicate: impl Fn(&HourlyForecast) -> bool,
+) -> Vec<Range<usize>> {
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    for (idx, _) in hours.iter().enumerate().filter(|(_, hour)| predicate(hour)) {
+        match runs.last_mut() {
+            Some(run) if idx - run.end <= MERGE_GAP_HOURS => run.end = idx + 1,
+            _ => runs.push(idx..idx + 1),
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_bundle;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 2, day)
+            .and_then(|date| date.and_hms_opt(hour, 0, 0))
+            .expect("valid time")
+    }
+
+    #[test]
+    fn labels_name_days_other_than_today() {
+        let today = at(12, 0).date();
+        let window = |start, end| AlertWindow { start, end };
+
+        assert_eq!(window(at(12, 14), at(12, 21)).label(today), "14:00–21:00");
+        assert_eq!(window(at(12, 22), at(13, 0)).label(today), "22:00–00:00");
+        assert_eq!(
+            window(at(14, 14), at(14, 21)).label(today),
+            "Sat 14:00–21:00"
+        );
+        assert_eq!(
+            window(at(13, 22), at(14, 4)).label(today),
+            "Fri 22:00–Sat 04:00"
+        );
+        assert_eq!(AlertWindow::for_days(today, today).label(today), "today");
+        assert_eq!(
+            AlertWindow::for_days(at(14, 0).date(), at(16, 0).date()).label(today),
+            "Sat–Mon"
+        );
+    }
+
+    #[test]
+    fn episodes_join_short_lulls() {
+        let mut bundle = sample_bundle();
+        let base = bundle.hourly[0].clone();
+        bundle.hourly = [1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+            .into_iter()
+            .map(|mm| {
+                let mut hour = base.clone();
+                hour.precipitation_mm = Some(mm);
+                hour
+            })
+            .collect();
+
+        let runs = episodes(&bundle.hourly, |hour| {
+            hour.precipitation_mm.is_some_and(|mm| mm > 0.0)
+        });
+        assert_eq!(runs, [0..5, 8..9]);
+    }
+}
@@ -0,0 +1,177 @@
+//! Aligns two forecasts for the side-by-side comparison view and classifies
+//! how the second location differs from the first.
+
+use crate::domain::weather::{
+    DailyForecast, ForecastBundle, HourlyForecast, Units, convert_temp, round_temp,
+};
+
+/// Precipitation-probability gap, in percentage points, that counts as wetter or drier.
+const PROBABILITY_DELTA_MIN: f32 = 20.0;
+/// Precipitation-amount gap, in millimetres, that counts as wetter or drier.
+const AMOUNT_DELTA_MIN_MM: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wetness {
+    Wetter,
+    Drier,
+    Similar,
+}
+
+impl Wetness {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Wetter => "wetter",
+            Self::Drier => "drier",
+            Self::Similar => "",
+        }
+    }
+}
+
+/// How location B compares with location A at one hour or on one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delta {
+    /// B minus A in display units; `None` when either side has no reading.
+    pub temp: Option<i32>,
+    pub wetness: Wetness,
+}
+
+impl Delta {
+    /// `+2° wetter`, `-1°`, `= drier`, or empty when nothing is known.
+    #[must_use]
+    pub fn label(self) -> String {
+        let temp = match self.temp {
+            Some(0) => "=".to_string(),
+            Some(delta) => format!("{delta:+}°"),
+            None => String::new(),
+        };
+        [temp.as_str(), self.wetness.label()]
+            .iter()
+            .filter(|part| !part.is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Pairs the hours of both forecasts by position. Both hourly series start at
+/// the current hour of their own location, so equal positions are the same
+/// instant even when the local clock times differ.
+#[must_use]
+pub fn align_hourly<'a>(
+    a: &'a ForecastBundle,
+    b: &'a ForecastBundle,
+    hours: usize,
+) -> Vec<(&'a HourlyForecast, &'a HourlyForecast)> {
+    a.hourly.iter().zip(&b.hourly).take(hours).collect()
+}
+
+/// Pairs days with the same calendar date, in A's order. Days only one side
+/// forecasts are dropped.
+#[must_use]
+pub fn align_daily<'a>(
+    a: &'a ForecastBundle,
+    b: &'a ForecastBundle,
+) -> Vec<(&'a DailyForecast, &'a DailyForecast)> {
+    a.daily
+        .iter()
+        .filter_map(|day_a| {
+            b.daily
+                .iter()
+                .find(|day_b| day_b.date == day_a.date)
+                .map(|day_b| (day_a, day_b))
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn hourly_delta(a: &HourlyForecast, b: &HourlyForecast, units: Units) -> Delta {
+    Delta {
+        temp: temp_delta(a.temperature_2m_c, b.temperature_2m_c, units),
+        wetness: wetness(
+            (a.precipitation_probability, a.precipitation_mm),
+            (b.precipitation_probability, b.precipitation_mm),
+        ),
+    }
+}
+
+/// Compares daily highs, and the chance and total of precipitation.
+#[must_use]
+pub fn daily_delta(a: &DailyForecast, b: &DailyForecast, units: Units) -> Delta {
+    Delta {
+        temp: temp_delta(a.temperature_max_c, b.temperature_max_c, units),
+        wetness: wetness(
+            (a.precipitation_probability_max, a.precipitation_sum_mm),
+            (b.precipitation_probability_max, b.precipitation_sum_mm),
+        ),
+    }
+}
+
+fn temp_delta(a: Option<f32>, b: Option<f32>, units: Units) -> Option<i32> {
+    Some(round_temp(convert_temp(b?, units)) - round_temp(convert_temp(a?, units)))
+}
+
+/// Probability decides when both sides report it; the amount breaks ties.
+fn wetness(a: (Option<f32>, Option<f32>), b: (Option<f32>, Option<f32>)) -> Wetness {
+    let classify = |gap: f32, threshold: f32| {
+        if gap >= threshold {
+            Some(Wetness::Wetter)
+        } else if gap <= -threshold {
+            Some(Wetness::Drier)
+        } else {
+            None
+        }
+    };
+    let by_probability =
+        a.0.zip(b.0)
+            .and_then(|(a, b)| classify(b - a, PROBABILITY_DELTA_MIN));
+    let by_amount =
+        a.1.zip(b.1)
+            .and_then(|(a, b)| classify(b - a, AMOUNT_DELTA_MIN_MM));
+    by_probability.or(by_amount).unwrap_or(Wetness::Similar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{sample_bundle, sample_daily, sample_hourly};
+
+    #[test]
+    fn hourly_delta_reports_warmer_and_wetter() {
+        let a = sample_hourly();
+        let mut b = sample_hourly();
+        b.temperature_2m_c = a.temperature_2m_c.map(|t| t + 2.4);
+        b.precipitation_probability = a.precipitation_probability.map(|p| p + 40.0);
+
+        let delta = hourly_delta(&a, &b, Units::Celsius);
+        assert_eq!(delta.temp, Some(2));
+        assert_eq!(delta.wetness, Wetness::Wetter);
+        assert_eq!(delta.label(), "+2° wetter");
+        assert_eq!(hourly_delta(&a, &a, Units::Celsius).label(), "=");
+    }
+
+    #[test]
+    fn daily_delta_falls_back_to_amount_and_converts_units() {
+        let mut a = sample_daily();
+        let mut b = sample_daily();
+        a.precipitation_probability_max = None;
+        a.precipitation_sum_mm = Some(6.0);
+        b.precipitation_sum_mm = Some(0.5);
+        a.temperature_max_c = Some(10.0);
+        b.temperature_max_c = Some(5.0);
+
+        let delta = daily_delta(&a, &b, Units::Fahrenheit);
+        assert_eq!(delta.temp, Some(-9));
+        assert_eq!(delta.wetness, Wetness::Drier);
+    }
+
+    #[test]
+    fn daily_rows_align_on_date() {
+        let a = sample_bundle();
+        let mut b = sample_bundle();
+        b.daily[0].date = b.daily[0].date.succ_opt().expect("next day");
+        assert!(align_daily(&a, &b).is_empty());
+        assert_eq!(align_daily(&a, &a).len(), a.daily.len());
+        assert_eq!(align_hourly(&a, &a, 1).len(), 1);
+    }
+}
@@ -6,6 +6,7 @@ mod conditions;
 mod conversions;
 mod insights;
 mod types;
+mod units;
 
 pub use conditions::{
     ParticleKind, WeatherCategory, weather_code_to_category, weather_code_to_particle,
@@ -25,6 +26,7 @@ pub use types::{
     PRECIP_NEAR_TERM_HOURS, PRECIP_SIGNIFICANT_THRESHOLD_MM, PrecipWindowSummary, RefreshMetadata,
     Units, categorize_european_aqi, categorize_us_aqi,
 };
+pub use units::{DistanceUnit, MeasureUnits, PrecipUnit, PressureUnit, WindUnit, beaufort_force};
 
 #[must_use]
 pub fn daypart_for_time(time: NaiveDateTime) -> Daypart {
@@ -1,7 +1,7 @@
 use super::{
     super::{
-        ForecastBundle, HourlyForecast, RefreshMetadata, Units, WeatherCategory, convert_temp,
-        round_temp, round_wind_speed, weather_code_to_category, weather_label_for_time,
+        ForecastBundle, HourlyForecast, MeasureUnits, RefreshMetadata, Units, WeatherCategory,
+        WindUnit, convert_temp, round_temp, weather_code_to_category, weather_label_for_time,
     },
     ActionCue, ChangeEvent, ChangeKind, InsightConfidence, NowcastInsight, ReliabilitySummary,
 };
@@ -21,6 +21,7 @@ const SIGNIFICANT_TEMP_SHIFT_C: f32 = 4.0;
 pub fn derive_nowcast_insight(
     bundle: &ForecastBundle,
     units: Units,
+    measures: MeasureUnits,
     refresh_meta: &RefreshMetadata,
 ) -> NowcastInsight {
     let base_confidence = confidence_from_hourly(&bundle.hourly);
@@ -29,8 +30,8 @@ pub fn derive_nowcast_insight(
 
     NowcastInsight {
         action,
-        action_text: action_text(action, bundle, units),
-        next_change: next_notable_change(&bundle.hourly, units),
+        action_text: action_text(action, bundle, units, measures.wind),
+        next_change: next_notable_change(&bundle.hourly, units, measures.wind),
         reliability: ReliabilitySummary {
             state: refresh_meta.state,
             age_minutes: refresh_meta.age_minutes(),
@@ -38,12 +39,16 @@ pub fn derive_nowcast_insight(
             consecutive_failures: refresh_meta.consecutive_failures,
         },
         confidence,
-        next_6h_summary: summarize_next_6h(&bundle.hourly, units),
+        next_6h_summary: summarize_next_6h(&bundle.hourly, units, measures),
     }
 }
 
 #[must_use]
-pub fn next_notable_change(hourly: &[HourlyForecast], units: Units) -> Option<ChangeEvent> {
+pub fn next_notable_change(
+    hourly: &[HourlyForecast],
+    units: Units,
+    wind_unit: WindUnit,
+) -> Option<ChangeEvent> {
     if hourly.len() < 2 {
         return None;
     }
@@ -71,7 +76,7 @@ pub fn next_notable_change(hourly: &[HourlyForecast], units: Units) -> Option<Ch
             });
         }
 
-        if let Some(message) = wind_jump_message(base, current) {
+        if let Some(message) = wind_jump_message(base, current, wind_unit) {
             return Some(ChangeEvent {
                 hours_from_now,
                 kind: ChangeKind::WindIncrease,
@@ -116,16 +121,17 @@ fn condition_shift_message(previous: &HourlyForecast, current: &HourlyForecast)
     Some(format!("Conditions shift to {next_label}"))
 }
 
-fn wind_jump_message(base: &HourlyForecast, current: &HourlyForecast) -> Option<String> {
+fn wind_jump_message(
+    base: &HourlyForecast,
+    current: &HourlyForecast,
+    wind_unit: WindUnit,
+) -> Option<String> {
     let base_wind = wind_reference(base)?;
     let next_wind = wind_reference(current)?;
     if next_wind - base_wind < SIGNIFICANT_WIND_JUMP_KMH {
         return None;
     }
-    Some(format!(
-        "Wind picks up to {} m/s",
-        round_wind_speed(next_wind)
-    ))
+    Some(format!("Wind picks up to {}", wind_unit.format(next_wind)))
 }
 
 fn wind_reference(hour: &HourlyForecast) -> Option<f32> {
@@ -282,11 +288,16 @@ fn freezing_temperatures_in_window(
     })
 }
 
-fn action_text(action: ActionCue, bundle: &ForecastBundle, units: Units) -> String {
+fn action_text(
+    action: ActionCue,
+    bundle: &ForecastBundle,
+    units: Units,
+    wind_unit: WindUnit,
+) -> String {
     if let Some(text) = static_action_text(action) {
         return text.to_string();
     }
-    dynamic_action_text(action, bundle, units)
+    dynamic_action_text(action, bundle, units, wind_unit)
 }
 
 fn static_action_text(action: ActionCue) -> Option<&'static str> {
@@ -302,7 +313,12 @@ fn static_action_text(action: ActionCue) -> Option<&'static str> {
     }
 }
 
-fn dynamic_action_text(action: ActionCue, bundle: &ForecastBundle, units: Units) -> String {
+fn dynamic_action_text(
+    action: ActionCue,
+    bundle: &ForecastBundle,
+    units: Units,
+    wind_unit: WindUnit,
+) -> String {
     match action {
         ActionCue::WinterTraction => format_temp_action(
             "use winter traction + warm layers",
@@ -310,8 +326,8 @@ fn dynamic_action_text(action: ActionCue, bundle: &ForecastBundle, units: Units)
             units,
         ),
         ActionCue::SecureLooseItems => format!(
-            "Now action: secure loose items (gusts {} m/s)",
-            round_wind_speed(bundle.current.wind_gusts_10m)
+            "Now action: secure loose items (gusts {})",
+            wind_unit.format(bundle.current.wind_gusts_10m)
         ),
         ActionCue::SunProtection => sun_protection_action(bundle),
         ActionCue::Hydrate => format_temp_action(
@@ -328,20 +344,21 @@ fn dynamic_action_text(action: ActionCue, bundle: &ForecastBundle, units: Units)
     }
 }
 
-fn summarize_next_6h(hourly: &[HourlyForecast], units: Units) -> String {
+fn summarize_next_6h(hourly: &[HourlyForecast], units: Units, measures: MeasureUnits) -> String {
     let Some(window) = hourly.get(..hourly.len().min(6)) else {
         return "Next 6h: data unavailable".to_string();
     };
     if window.is_empty() {
         return "Next 6h: data unavailable".to_string();
     }
-    let precip_total = next_6h_precip_total(window);
+    let precip_total = measures.precip.format(next_6h_precip_total(window));
     let max_probability = next_6h_max_probability(window);
-    let max_wind = next_6h_max_wind(window);
+    let max_wind = next_6h_max_wind(window, measures.wind);
     let delta_temp = next_6h_delta_temp(window, units);
 
     format!(
-        "Next 6h: P {precip_total:.1}mm · Pmax {max_probability} · Gust {max_wind} m/s · ΔT {delta_temp}"
+        "Next 6h: P {precip_total} · Pmax {max_probability} · Gust {max_wind} {} · ΔT {delta_temp}",
+        measures.wind.symbol()
     )
 }
 
@@ -377,14 +394,14 @@ fn next_6h_max_probability(window: &[HourlyForecast]) -> String {
         .map_or_else(|| "--".to_string(), |value| format!("{value:.0}%"))
 }
 
-fn next_6h_max_wind(window: &[HourlyForecast]) -> String {
+fn next_6h_max_wind(window: &[HourlyForecast], wind_unit: WindUnit) -> String {
     window
         .iter()
         .filter_map(wind_reference)
         .max_by(f32::total_cmp)
         .map_or_else(
             || "--".to_string(),
-            |value| round_wind_speed(value).to_string(),
+            |value| wind_unit.round(value).to_string(),
         )
 }
 
@@ -13,7 +13,8 @@ mod tests {
     use super::*;
     use crate::{
         domain::weather::{
-            CurrentConditions, DailyForecast, HourlyForecast, Location, RefreshMetadata, Units,
+            CurrentConditions, DailyForecast, HourlyForecast, Location, MeasureUnits,
+            RefreshMetadata, Units, WindUnit,
         },
         resilience::freshness::FreshnessState,
     };
@@ -99,6 +100,7 @@ mod tests {
             }],
             air_quality: None,
             fetched_at: Utc::now(),
+            utc_offset_seconds: 0,
         }
     }
 
@@ -121,7 +123,12 @@ mod tests {
     #[test]
     fn comfortable_action_for_clear_mild_weather() {
         let bundle = clear_bundle();
-        let insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
+        let insight = derive_nowcast_insight(
+            &bundle,
+            Units::Celsius,
+            MeasureUnits::default(),
+            &fresh_meta(),
+        );
         assert_eq!(insight.action, ActionCue::Comfortable);
         assert_eq!(insight.confidence, InsightConfidence::High);
     }
@@ -129,14 +136,19 @@ mod tests {
     #[test]
     fn carry_umbrella_when_rain_imminent() {
         let bundle = rainy_bundle();
-        let insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
+        let insight = derive_nowcast_insight(
+            &bundle,
+            Units::Celsius,
+            MeasureUnits::default(),
+            &fresh_meta(),
+        );
         assert_eq!(insight.action, ActionCue::CarryUmbrella);
     }
 
     #[test]
     fn next_notable_change_detects_precip_start() {
         let bundle = rainy_bundle();
-        let change = next_notable_change(&bundle.hourly, Units::Celsius);
+        let change = next_notable_change(&bundle.hourly, Units::Celsius, WindUnit::Ms);
         assert!(change.is_some(), "expected a change event for rain onset");
         let change = change.unwrap();
         assert_eq!(change.kind, ChangeKind::PrecipStart);
@@ -146,12 +158,17 @@ mod tests {
     #[test]
     fn next_notable_change_returns_none_for_stable_clear() {
         let bundle = clear_bundle();
-        assert!(next_notable_change(&bundle.hourly, Units::Celsius).is_none());
+        assert!(next_notable_change(&bundle.hourly, Units::Celsius, WindUnit::Ms).is_none());
     }
 
     #[test]
     fn reliability_line_contains_freshness_state() {
-        let insight = derive_nowcast_insight(&clear_bundle(), Units::Celsius, &fresh_meta());
+        let insight = derive_nowcast_insight(
+            &clear_bundle(),
+            Units::Celsius,
+            MeasureUnits::default(),
+            &fresh_meta(),
+        );
         assert!(
             insight.reliability.line().contains("fresh"),
             "got: {}",
@@ -162,8 +179,18 @@ mod tests {
     #[test]
     fn confidence_degrades_under_stale_data() {
         let bundle = clear_bundle();
-        let fresh_insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
-        let stale_insight = derive_nowcast_insight(&bundle, Units::Celsius, &stale_meta());
+        let fresh_insight = derive_nowcast_insight(
+            &bundle,
+            Units::Celsius,
+            MeasureUnits::default(),
+            &fresh_meta(),
+        );
+        let stale_insight = derive_nowcast_insight(
+            &bundle,
+            Units::Celsius,
+            MeasureUnits::default(),
+            &stale_meta(),
+        );
         assert_eq!(fresh_insight.confidence, InsightConfidence::High);
         assert_ne!(stale_insight.confidence, InsightConfidence::High);
     }
@@ -172,7 +199,12 @@ mod tests {
     fn layer_up_when_below_freezing() {
         let mut bundle = clear_bundle();
         bundle.current.temperature_2m_c = -5.0;
-        let insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
+        let insight = derive_nowcast_insight(
+            &bundle,
+            Units::Celsius,
+            MeasureUnits::default(),
+            &fresh_meta(),
+        );
         assert_eq!(insight.action, ActionCue::LayerUp);
     }
 
@@ -181,7 +213,12 @@ mod tests {
         let mut bundle = rainy_bundle();
         bundle.current.weather_code = 71;
         bundle.current.temperature_2m_c = -2.0;
-        let insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
+        let insight = derive_nowcast_insight(
+            &bundle,
+            Units::Celsius,
+            MeasureUnits::default(),
+            &fresh_meta(),
+        );
         assert_eq!(insight.action, ActionCue::WinterTraction);
     }
 }
@@ -1,6 +1,9 @@
+use serde::Serialize;
+
 use crate::resilience::freshness::FreshnessState;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum InsightConfidence {
     High,
     Medium,
@@ -27,7 +30,8 @@ impl InsightConfidence {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ActionCue {
     CarryUmbrella,
     WinterTraction,
@@ -39,7 +43,8 @@ pub enum ActionCue {
     Comfortable,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ChangeKind {
     PrecipStart,
     WindIncrease,
@@ -47,7 +52,7 @@ pub enum ChangeKind {
     ConditionShift,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ChangeEvent {
     pub hours_from_now: usize,
     pub kind: ChangeKind,
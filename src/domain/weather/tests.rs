@@ -30,6 +30,7 @@ fn minimal_bundle(high_c: Option<f32>, low_c: Option<f32>) -> ForecastBundle {
         daily: vec![],
         air_quality: None,
         fetched_at: chrono::Utc::now(),
+        utc_offset_seconds: 0,
     }
 }
 
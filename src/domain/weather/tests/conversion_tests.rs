@@ -33,3 +33,40 @@ fn wind_speed_conversion_rounding_covers_negative_and_fractional_values() {
     assert_eq!(round_wind_speed(-3.6), -1);
     assert_eq!(round_wind_speed(-5.4), -2);
 }
+
+#[test]
+fn wind_units_convert_from_kmh() {
+    assert_eq!(WindUnit::Kmh.round(36.0), 36);
+    assert_eq!(WindUnit::Ms.round(36.0), 10);
+    assert_eq!(WindUnit::Mph.round(36.0), 22);
+    assert_eq!(WindUnit::Knots.round(36.0), 19);
+    assert_eq!(WindUnit::Ms.round(36.0), round_wind_speed(36.0));
+    assert_eq!(WindUnit::Knots.format(18.52), "10 kn");
+}
+
+#[test]
+fn beaufort_force_follows_scale_boundaries() {
+    assert_eq!(beaufort_force(0.0), 0);
+    assert_eq!(beaufort_force(-5.0), 0);
+    assert_eq!(beaufort_force(10.0), 2);
+    assert_eq!(beaufort_force(36.0), 5);
+    assert_eq!(beaufort_force(60.0), 7);
+    assert_eq!(beaufort_force(62.0), 8);
+    assert_eq!(beaufort_force(130.0), 12);
+    assert_eq!(WindUnit::Beaufort.format(36.0), "5 Bft");
+}
+
+#[test]
+fn pressure_precip_and_distance_units_format_at_unit_precision() {
+    assert_eq!(PressureUnit::Hpa.format(1013.25), "1013hPa");
+    assert_eq!(PressureUnit::InHg.format(1013.25), "29.92inHg");
+    assert_eq!(PressureUnit::MmHg.format(1013.25), "760mmHg");
+
+    assert_eq!(PrecipUnit::Mm.format(12.7), "12.7mm");
+    assert_eq!(PrecipUnit::In.format(12.7), "0.50in");
+
+    assert_eq!(DistanceUnit::Km.format(5000.0), "5.0km");
+    assert_eq!(DistanceUnit::Mi.format(16_093.44), "10.0mi");
+    assert_eq!(DistanceUnit::Mi.format(40_000.0), "25mi");
+    assert_eq!(DistanceUnit::Mi.format(0.0), "--");
+}
@@ -90,7 +90,12 @@ fn derive_nowcast_insight_returns_rain_action_when_precip_signal_is_high() {
             8_000.0,
         ),
     ];
-    let insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
+    let insight = derive_nowcast_insight(
+        &bundle,
+        Units::Celsius,
+        MeasureUnits::default(),
+        &fresh_meta(),
+    );
     assert_eq!(insight.action, ActionCue::CarryUmbrella);
     assert!(insight.action_text.contains("precipitation gear"));
 }
@@ -113,7 +118,12 @@ fn derive_nowcast_insight_ignores_distant_light_precip_for_now_action() {
             )
         })
         .collect();
-    let insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
+    let insight = derive_nowcast_insight(
+        &bundle,
+        Units::Celsius,
+        MeasureUnits::default(),
+        &fresh_meta(),
+    );
     assert_eq!(insight.action, ActionCue::Comfortable);
 }
 
@@ -135,7 +145,12 @@ fn derive_nowcast_insight_uses_winter_action_for_snow_signal() {
             8_000.0,
         ),
     ];
-    let insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
+    let insight = derive_nowcast_insight(
+        &bundle,
+        Units::Celsius,
+        MeasureUnits::default(),
+        &fresh_meta(),
+    );
     assert_eq!(insight.action, ActionCue::WinterTraction);
     assert!(insight.action_text.contains("winter traction"));
 }
@@ -160,7 +175,12 @@ fn derive_nowcast_insight_uses_rain_action_for_nonfreezing_thunder() {
     ];
     bundle.hourly[0].snowfall_cm = Some(0.2);
     bundle.hourly[1].snowfall_cm = Some(0.2);
-    let insight = derive_nowcast_insight(&bundle, Units::Celsius, &fresh_meta());
+    let insight = derive_nowcast_insight(
+        &bundle,
+        Units::Celsius,
+        MeasureUnits::default(),
+        &fresh_meta(),
+    );
     assert_eq!(insight.action, ActionCue::CarryUmbrella);
 }
 
@@ -189,7 +209,8 @@ fn next_notable_change_prefers_precip_start_over_later_changes() {
         ),
     ];
 
-    let change = next_notable_change(&hourly, Units::Celsius).expect("notable change");
+    let change =
+        next_notable_change(&hourly, Units::Celsius, WindUnit::Ms).expect("notable change");
     assert_eq!(change.kind, ChangeKind::PrecipStart);
     assert_eq!(change.hours_from_now, 1);
 }
@@ -204,6 +225,11 @@ fn derive_nowcast_insight_confidence_drops_to_low_when_offline() {
         ..RefreshMetadata::default()
     };
 
-    let insight = derive_nowcast_insight(&bundle, Units::Celsius, &refresh_meta);
+    let insight = derive_nowcast_insight(
+        &bundle,
+        Units::Celsius,
+        MeasureUnits::default(),
+        &refresh_meta,
+    );
     assert_eq!(insight.confidence, InsightConfidence::Low);
 }
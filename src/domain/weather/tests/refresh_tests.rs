@@ -28,3 +28,18 @@ fn refresh_metadata_schedule_retry_sets_next_retry() {
     meta.schedule_retry_in(30);
     assert!(meta.next_retry_at.is_some());
 }
+
+#[test]
+fn age_label_scales_units_with_data_age() {
+    let label_for = |minutes: i64| {
+        RefreshMetadata {
+            last_success: Some(Utc::now() - Duration::minutes(minutes)),
+            ..RefreshMetadata::default()
+        }
+        .age_label()
+    };
+    assert_eq!(RefreshMetadata::default().age_label(), None);
+    assert_eq!(label_for(45).as_deref(), Some("45m"));
+    assert_eq!(label_for(3 * 60 + 5).as_deref(), Some("3h"));
+    assert_eq!(label_for(3 * 24 * 60).as_deref(), Some("3d"));
+}
@@ -76,7 +76,7 @@ pub struct DaypartSummary {
     pub sample_count: usize,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Location {
     pub name: String,
     pub latitude: f64,
@@ -111,7 +111,7 @@ impl Location {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentConditions {
     pub temperature_2m_c: f32,
     pub relative_humidity_2m: f32,
@@ -130,7 +130,7 @@ pub struct CurrentConditions {
     pub low_today_c: Option<f32>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HourlyForecast {
     pub time: NaiveDateTime,
     pub temperature_2m_c: Option<f32>,
@@ -151,7 +151,7 @@ pub struct HourlyForecast {
     pub cloud_cover_high: Option<f32>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyForecast {
     pub date: NaiveDate,
     pub weather_code: Option<u8>,
@@ -170,7 +170,7 @@ pub struct DailyForecast {
     pub sunshine_duration_s: Option<f32>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForecastBundle {
     pub location: Location,
     pub current: CurrentConditions,
@@ -178,6 +178,9 @@ pub struct ForecastBundle {
     pub daily: Vec<DailyForecast>,
     pub air_quality: Option<AirQualityReading>,
     pub fetched_at: DateTime<Utc>,
+    /// Offset from UTC of the local wall-clock times in the forecast.
+    #[serde(default)]
+    pub utc_offset_seconds: i32,
 }
 
 impl ForecastBundle {
@@ -218,7 +221,7 @@ impl PrecipWindowSummary {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AirQualityCategory {
     Good,
     Moderate,
@@ -244,7 +247,7 @@ impl AirQualityCategory {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AirQualityReading {
     pub us_aqi: Option<u16>,
     pub european_aqi: Option<u16>,
@@ -369,6 +372,16 @@ impl RefreshMetadata {
         self.last_success.map(|ts| (Utc::now() - ts).num_minutes())
     }
 
+    /// Compact data age such as `45m`, `3h` or `2d`.
+    #[must_use]
+    pub fn age_label(&self) -> Option<String> {
+        self.age_minutes().map(|minutes| match minutes.max(0) {
+            m if m < 60 => format!("{m}m"),
+            m if m < 48 * 60 => format!("{}h", m / 60),
+            m => format!("{}d", m / (24 * 60)),
+        })
+    }
+
     #[must_use]
     pub fn retry_in_seconds(&self) -> Option<i64> {
         self.retry_in_seconds_at(Utc::now())
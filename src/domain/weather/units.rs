@@ -0,0 +1,232 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+// Forecast data stays in the provider's units (km/h, hPa, mm, metres); these
+// preferences only apply when a value is formatted for display.
+
+const KMH_PER_MS: f32 = 3.6;
+const KMH_PER_MPH: f32 = 1.609_344;
+const KMH_PER_KNOT: f32 = 1.852;
+const INHG_PER_HPA: f32 = 0.029_529_983;
+const MMHG_PER_HPA: f32 = 0.750_061_7;
+const MM_PER_INCH: f32 = 25.4;
+const METRES_PER_MILE: f32 = 1_609.344;
+
+/// Upper bounds (m/s, exclusive) of Beaufort forces 0..=11; anything above is 12.
+const BEAUFORT_LIMITS_MS: [f32; 12] = [
+    0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lower")]
+pub enum WindUnit {
+    Kmh,
+    #[default]
+    Ms,
+    Mph,
+    Knots,
+    Beaufort,
+}
+
+impl WindUnit {
+    pub const ALL: [Self; 5] = [Self::Kmh, Self::Ms, Self::Mph, Self::Knots, Self::Beaufort];
+
+    /// Converts a km/h speed; Beaufort yields the force number.
+    #[must_use]
+    pub fn convert(self, kmh: f32) -> f32 {
+        match self {
+            Self::Kmh => kmh,
+            Self::Ms => kmh / KMH_PER_MS,
+            Self::Mph => kmh / KMH_PER_MPH,
+            Self::Knots => kmh / KMH_PER_KNOT,
+            Self::Beaufort => f32::from(beaufort_force(kmh)),
+        }
+    }
+
+    #[must_use]
+    pub fn round(self, kmh: f32) -> i32 {
+        self.convert(kmh).round() as i32
+    }
+
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Kmh => "km/h",
+            Self::Ms => "m/s",
+            Self::Mph => "mph",
+            Self::Knots => "kn",
+            Self::Beaufort => "Bft",
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Kmh => "km/h",
+            Self::Ms => "m/s",
+            Self::Mph => "mph",
+            Self::Knots => "Knots",
+            Self::Beaufort => "Beaufort",
+        }
+    }
+
+    /// Rounded speed with its unit, e.g. `12 mph`.
+    #[must_use]
+    pub fn format(self, kmh: f32) -> String {
+        format!("{} {}", self.round(kmh), self.symbol())
+    }
+}
+
+#[must_use]
+pub fn beaufort_force(kmh: f32) -> u8 {
+    let ms = kmh.max(0.0) / KMH_PER_MS;
+    BEAUFORT_LIMITS_MS
+        .iter()
+        .position(|limit| ms < *limit)
+        .map_or(12, |force| force as u8)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lower")]
+pub enum PressureUnit {
+    #[default]
+    Hpa,
+    InHg,
+    MmHg,
+}
+
+impl PressureUnit {
+    pub const ALL: [Self; 3] = [Self::Hpa, Self::InHg, Self::MmHg];
+
+    #[must_use]
+    pub fn convert(self, hpa: f32) -> f32 {
+        match self {
+            Self::Hpa => hpa,
+            Self::InHg => hpa * INHG_PER_HPA,
+            Self::MmHg => hpa * MMHG_PER_HPA,
+        }
+    }
+
+    /// The converted value at the precision the unit is usually read at.
+    #[must_use]
+    pub fn format_value(self, hpa: f32) -> String {
+        let value = self.convert(hpa);
+        match self {
+            Self::InHg => format!("{value:.2}"),
+            Self::Hpa | Self::MmHg => format!("{value:.0}"),
+        }
+    }
+
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Hpa => "hPa",
+            Self::InHg => "inHg",
+            Self::MmHg => "mmHg",
+        }
+    }
+
+    #[must_use]
+    pub fn format(self, hpa: f32) -> String {
+        format!("{}{}", self.format_value(hpa), self.symbol())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lower")]
+pub enum PrecipUnit {
+    #[default]
+    Mm,
+    In,
+}
+
+impl PrecipUnit {
+    pub const ALL: [Self; 2] = [Self::Mm, Self::In];
+
+    #[must_use]
+    pub fn convert(self, mm: f32) -> f32 {
+        match self {
+            Self::Mm => mm,
+            Self::In => mm / MM_PER_INCH,
+        }
+    }
+
+    /// One decimal for millimetres, two for inches.
+    #[must_use]
+    pub fn format_value(self, mm: f32) -> String {
+        let value = self.convert(mm);
+        match self {
+            Self::Mm => format!("{value:.1}"),
+            Self::In => format!("{value:.2}"),
+        }
+    }
+
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Mm => "mm",
+            Self::In => "in",
+        }
+    }
+
+    #[must_use]
+    pub fn format(self, mm: f32) -> String {
+        format!("{}{}", self.format_value(mm), self.symbol())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lower")]
+pub enum DistanceUnit {
+    #[default]
+    Km,
+    Mi,
+}
+
+impl DistanceUnit {
+    pub const ALL: [Self; 2] = [Self::Km, Self::Mi];
+
+    #[must_use]
+    pub fn convert(self, metres: f32) -> f32 {
+        match self {
+            Self::Km => metres / 1000.0,
+            Self::Mi => metres / METRES_PER_MILE,
+        }
+    }
+
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Km => "km",
+            Self::Mi => "mi",
+        }
+    }
+
+    /// Visibility-style distance: whole units from 20 up, one decimal below.
+    #[must_use]
+    pub fn format(self, metres: f32) -> String {
+        if !metres.is_finite() || metres <= 0.0 {
+            return "--".to_string();
+        }
+        let value = self.convert(metres);
+        if value >= 20.0 {
+            format!("{value:.0}{}", self.symbol())
+        } else {
+            format!("{value:.1}{}", self.symbol())
+        }
+    }
+}
+
+/// The non-temperature display units, passed together wherever a view
+/// formats wind, pressure, precipitation or distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MeasureUnits {
+    pub wind: WindUnit,
+    pub pressure: PressureUnit,
+    pub precip: PrecipUnit,
+    pub distance: DistanceUnit,
+}
@@ -0,0 +1,25 @@
+//! Machine-readable renderings of a [`ForecastBundle`](crate::domain::weather::ForecastBundle)
+//! for scripts and other tools.
+
+use crate::domain::weather::{Units, convert_temp, convert_wind_speed};
+
+pub mod csv;
+pub mod ical;
+pub mod json;
+pub mod openmetrics;
+pub mod statusbar;
+pub mod template;
+
+/// Temperature in the selected units, rounded to a tenth of a degree.
+fn export_temp(celsius: f32, units: Units) -> f32 {
+    round_tenth(convert_temp(celsius, units))
+}
+
+/// Wind speed in m/s, the fixed unit of the JSON and CSV exports, rounded to a tenth.
+fn export_wind(kmh: f32) -> f32 {
+    round_tenth(convert_wind_speed(kmh))
+}
+
+fn round_tenth(value: f32) -> f32 {
+    (value * 10.0).round() / 10.0
+}
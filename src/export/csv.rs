@@ -0,0 +1,290 @@
+use std::fmt::Display;
+
+use crate::domain::weather::{
+    DailyForecast, DistanceUnit, ForecastBundle, HourlyForecast, MeasureUnits, PrecipUnit,
+    PressureUnit, Units, WindUnit,
+};
+use crate::export::{export_temp, round_tenth};
+
+/// Which forecast series `--format csv` writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Series {
+    Hourly,
+    Daily,
+}
+
+/// Renders one series of `bundle` as CSV with a header row. Temperatures follow
+/// `units`, the other measurements follow `measures`, and the unit of each
+/// column is part of its name.
+#[must_use]
+pub fn render(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+    series: Series,
+) -> String {
+    let mut out = String::new();
+    match series {
+        Series::Hourly => {
+            push_row(&mut out, &hourly_header(units, measures));
+            for hour in &bundle.hourly {
+                push_row(&mut out, &hourly_row(hour, units, measures));
+            }
+        }
+        Series::Daily => {
+            push_row(&mut out, &daily_header(units, measures));
+            for day in &bundle.daily {
+                push_row(&mut out, &daily_row(day, units, measures));
+            }
+        }
+    }
+    out
+}
+
+fn hourly_header(units: Units, measures: MeasureUnits) -> Vec<String> {
+    let temp = temp_suffix(units);
+    let precip = precip_suffix(measures.precip);
+    let snow = snow_suffix(measures.precip);
+    let wind = wind_suffix(measures.wind);
+    let pressure = pressure_suffix(measures.pressure);
+    let distance = distance_suffix(measures.distance);
+    [
+        "time",
+        &format!("temperature_2m_{temp}"),
+        "weather_code",
+        "is_day",
+        "relative_humidity_2m_pct",
+        "precipitation_probability_pct",
+        &format!("precipitation_{precip}"),
+        &format!("rain_{precip}"),
+        &format!("snowfall_{snow}"),
+        &format!("wind_speed_10m_{wind}"),
+        &format!("wind_gusts_10m_{wind}"),
+        &format!("pressure_msl_{pressure}"),
+        &format!("visibility_{distance}"),
+        "cloud_cover_pct",
+        "cloud_cover_low_pct",
+        "cloud_cover_mid_pct",
+        "cloud_cover_high_pct",
+    ]
+    .map(str::to_string)
+    .to_vec()
+}
+
+fn hourly_row(hour: &HourlyForecast, units: Units, measures: MeasureUnits) -> Vec<String> {
+    let precip = |mm: Option<f32>| cell(mm.map(|mm| measures.precip.format_value(mm)));
+    let wind = |kmh: Option<f32>| cell(kmh.map(|kmh| round_tenth(measures.wind.convert(kmh))));
+    vec![
+        hour.time.format("%Y-%m-%dT%H:%M").to_string(),
+        cell(hour.temperature_2m_c.map(|c| export_temp(c, units))),
+        cell(hour.weather_code),
+        cell(hour.is_day),
+        cell(hour.relative_humidity_2m),
+        cell(hour.precipitation_probability),
+        precip(hour.precipitation_mm),
+        precip(hour.rain_mm),
+        cell(hour.snowfall_cm.map(|cm| snow_value(cm, measures.precip))),
+        wind(hour.wind_speed_10m),
+        wind(hour.wind_gusts_10m),
+        cell(
+            hour.pressure_msl_hpa
+                .map(|hpa| measures.pressure.format_value(hpa)),
+        ),
+        cell(
+            hour.visibility_m
+                .map(|m| round_tenth(measures.distance.convert(m))),
+        ),
+        cell(hour.cloud_cover),
+        cell(hour.cloud_cover_low),
+        cell(hour.cloud_cover_mid),
+        cell(hour.cloud_cover_high),
+    ]
+}
+
+fn daily_header(units: Units, measures: MeasureUnits) -> Vec<String> {
+    let temp = temp_suffix(units);
+    let precip = precip_suffix(measures.precip);
+    let snow = snow_suffix(measures.precip);
+    let wind = wind_suffix(measures.wind);
+    [
+        "date",
+        "weather_code",
+        &format!("temperature_max_{temp}"),
+        &format!("temperature_min_{temp}"),
+        "sunrise",
+        "sunset",
+        "uv_index_max",
+        "precipitation_probability_max_pct",
+        &format!("precipitation_sum_{precip}"),
+        &format!("rain_sum_{precip}"),
+        &format!("snowfall_sum_{snow}"),
+        "precipitation_hours",
+        &format!("wind_gusts_10m_max_{wind}"),
+        "daylight_duration_s",
+        "sunshine_duration_s",
+    ]
+    .map(str::to_string)
+    .to_vec()
+}
+
+fn daily_row(day: &DailyForecast, units: Units, measures: MeasureUnits) -> Vec<String> {
+    let precip = |mm: Option<f32>| cell(mm.map(|mm| measures.precip.format_value(mm)));
+    vec![
+        day.date.format("%Y-%m-%d").to_string(),
+        cell(day.weather_code),
+        cell(day.temperature_max_c.map(|c| export_temp(c, units))),
+        cell(day.temperature_min_c.map(|c| export_temp(c, units))),
+        cell(day.sunrise.map(|t| t.format("%Y-%m-%dT%H:%M"))),
+        cell(day.sunset.map(|t| t.format("%Y-%m-%dT%H:%M"))),
+        cell(day.uv_index_max),
+        cell(day.precipitation_probability_max),
+        precip(day.precipitation_sum_mm),
+        precip(day.rain_sum_mm),
+        cell(
+            day.snowfall_sum_cm
+                .map(|cm| snow_value(cm, measures.precip)),
+        ),
+        cell(day.precipitation_hours),
+        cell(
+            day.wind_gusts_10m_max
+                .map(|kmh| round_tenth(measures.wind.convert(kmh))),
+        ),
+        cell(day.daylight_duration_s),
+        cell(day.sunshine_duration_s),
+    ]
+}
+
+const fn temp_suffix(units: Units) -> &'static str {
+    match units {
+        Units::Celsius => "c",
+        Units::Fahrenheit => "f",
+    }
+}
+
+const fn wind_suffix(unit: WindUnit) -> &'static str {
+    match unit {
+        WindUnit::Kmh => "kmh",
+        WindUnit::Ms => "ms",
+        WindUnit::Mph => "mph",
+        WindUnit::Knots => "kn",
+        WindUnit::Beaufort => "bft",
+    }
+}
+
+const fn pressure_suffix(unit: PressureUnit) -> &'static str {
+    match unit {
+        PressureUnit::Hpa => "hpa",
+        PressureUnit::InHg => "inhg",
+        PressureUnit::MmHg => "mmhg",
+    }
+}
+
+const fn precip_suffix(unit: PrecipUnit) -> &'static str {
+    match unit {
+        PrecipUnit::Mm => "mm",
+        PrecipUnit::In => "in",
+    }
+}
+
+const fn distance_suffix(unit: DistanceUnit) -> &'static str {
+    match unit {
+        DistanceUnit::Km => "km",
+        DistanceUnit::Mi => "mi",
+    }
+}
+
+/// Snowfall stays in centimetres with metric precipitation, and is in inches
+/// alongside inches of rain.
+const fn snow_suffix(unit: PrecipUnit) -> &'static str {
+    match unit {
+        PrecipUnit::Mm => "cm",
+        PrecipUnit::In => "in",
+    }
+}
+
+fn snow_value(cm: f32, unit: PrecipUnit) -> String {
+    match unit {
+        PrecipUnit::Mm => cm.to_string(),
+        PrecipUnit::In => unit.format_value(cm * 10.0),
+    }
+}
+
+/// Missing values become empty cells so spreadsheets read them as blanks.
+fn cell(value: Option<impl Display>) -> String {
+    value.map_or_else(String::new, |v| v.to_string())
+}
+
+fn push_row(out: &mut String, fields: &[String]) {
+    out.push_str(&fields.join(","));
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_bundle;
+
+    #[test]
+    fn hourly_csv_has_header_and_converted_rows() {
+        let csv = render(
+            &sample_bundle(),
+            Units::Fahrenheit,
+            MeasureUnits::default(),
+            Series::Hourly,
+        );
+        let mut lines = csv.lines();
+        let header = lines.next().expect("header");
+        let row = lines.next().expect("row");
+
+        assert!(header.starts_with("time,temperature_2m_f,weather_code"));
+        assert_eq!(header.split(',').count(), row.split(',').count());
+        assert!(row.starts_with("2026-02-12T10:00,44.6,3,true,72,35,0"));
+        assert!(row.contains(",2.8,4.2,1008,"));
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn daily_csv_leaves_missing_values_blank() {
+        let csv = render(
+            &sample_bundle(),
+            Units::Celsius,
+            MeasureUnits::default(),
+            Series::Daily,
+        );
+        let row = csv.lines().nth(1).expect("row");
+        let cells = row.split(',').collect::<Vec<_>>();
+
+        assert_eq!(cells[0], "2026-02-12");
+        assert_eq!(cells[2], "8");
+        assert_eq!(cells[4], "");
+        assert_eq!(cells[5], "");
+        assert_eq!(
+            cells.len(),
+            daily_header(Units::Celsius, MeasureUnits::default()).len()
+        );
+    }
+
+    #[test]
+    fn measure_units_convert_values_and_name_columns() {
+        let measures = MeasureUnits {
+            wind: WindUnit::Kmh,
+            pressure: PressureUnit::InHg,
+            precip: PrecipUnit::In,
+            distance: DistanceUnit::Mi,
+        };
+        let csv = render(&sample_bundle(), Units::Celsius, measures, Series::Hourly);
+        let mut lines = csv.lines();
+        let header = lines.next().expect("header").split(',').collect::<Vec<_>>();
+        let row = lines.next().expect("row").split(',').collect::<Vec<_>>();
+        let column = |name: &str| {
+            let idx = header.iter().position(|h| *h == name);
+            row[idx.unwrap_or_else(|| panic!("no {name} column"))]
+        };
+
+        assert_eq!(column("wind_speed_10m_kmh"), "10");
+        assert_eq!(column("pressure_msl_inhg"), "29.77");
+        assert_eq!(column("precipitation_in"), "0.00");
+        assert_eq!(column("snowfall_in"), "0.00");
+        assert_eq!(column("visibility_mi"), "6.2");
+    }
+}
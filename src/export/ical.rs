@@ -0,0 +1,275 @@
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+
+use crate::cli::IconMode;
+use crate::domain::alerts::{AlertRule, AlertWindow, WeatherAlert, scan_alerts};
+use crate::domain::weather::{
+    DailyForecast, ForecastBundle, MeasureUnits, PrecipUnit, Units, convert_temp, round_temp,
+    weather_icon, weather_label,
+};
+
+/// Content type for `.ics` responses.
+pub const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Renders an iCalendar feed with one all-day event per forecast day and one
+/// event per alert spanning its window. Event UIDs depend only on the location
+/// and date or alert start, so subscribed calendars update events in place.
+#[must_use]
+pub fn render(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+    icon_mode: IconMode,
+    rules: &[AlertRule],
+) -> String {
+    let mut cal = Calendar::default();
+    let place = bundle.location.display_name();
+    cal.line("BEGIN:VCALENDAR");
+    cal.line("VERSION:2.0");
+    cal.line("PRODID:-//terminal-weather//forecast//EN");
+    cal.line("CALSCALE:GREGORIAN");
+    cal.line("METHOD:PUBLISH");
+    cal.property("X-WR-CALNAME", &format!("Weather · {place}"));
+    if let Some(tz) = &bundle.location.timezone {
+        cal.property("X-WR-TIMEZONE", tz);
+    }
+    cal.line("REFRESH-INTERVAL;VALUE=DURATION:PT1H");
+
+    let uid_base = format!(
+        "{:.4}_{:.4}",
+        bundle.location.latitude, bundle.location.longitude
+    );
+    let stamp = ical_utc(bundle.fetched_at);
+    for day in &bundle.daily {
+        cal.daily_event(day, units, measures.precip, icon_mode, &uid_base, &stamp);
+    }
+    for alert in scan_alerts(bundle, units, measures, rules) {
+        let Some(window) = alert.window else {
+            continue;
+        };
+        cal.alert_event(&alert, window, bundle.utc_offset_seconds, &uid_base, &stamp);
+    }
+    cal.line("END:VCALENDAR");
+    cal.out
+}
+
+#[derive(Default)]
+struct Calendar {
+    out: String,
+}
+
+impl Calendar {
+    fn daily_event(
+        &mut self,
+        day: &DailyForecast,
+        units: Units,
+        precip_unit: PrecipUnit,
+        icon_mode: IconMode,
+        uid_base: &str,
+        stamp: &str,
+    ) {
+        let symbol = unit_symbol(units);
+        let degrees = |celsius: Option<f32>| {
+            celsius.map_or_else(
+                || "--".to_string(),
+                |c| format!("{}°", round_temp(convert_temp(c, units))),
+            )
+        };
+        let (low, high) = (
+            degrees(day.temperature_min_c),
+            degrees(day.temperature_max_c),
+        );
+        let (icon, condition) = day.weather_code.map_or(("", "Forecast"), |code| {
+            (weather_icon(code, icon_mode, true), weather_label(code))
+        });
+
+        let mut description = vec![format!("Low {low}{symbol}, high {high}{symbol}")];
+        if let Some(precip) = day.precipitation_sum_mm {
+            let chance = day
+                .precipitation_probability_max
+                .map_or_else(String::new, |p| format!(" ({p:.0}% chance)"));
+            description.push(format!(
+                "Precipitation {} {}{chance}",
+                precip_unit.format_value(precip),
+                precip_unit.symbol()
+            ));
+        }
+        if let (Some(sunrise), Some(sunset)) = (day.sunrise, day.sunset) {
+            description.push(format!(
+                "Sunrise {}, sunset {}",
+                sunrise.format("%H:%M"),
+                sunset.format("%H:%M")
+            ));
+        }
+
+        self.line("BEGIN:VEVENT");
+        self.property(
+            "UID",
+            &format!(
+                "day-{}-{uid_base}@terminal-weather",
+                day.date.format("%Y%m%d")
+            ),
+        );
+        self.line(&format!("DTSTAMP:{stamp}"));
+        self.line(&format!("DTSTART;VALUE=DATE:{}", day.date.format("%Y%m%d")));
+        self.line(&format!(
+            "DTEND;VALUE=DATE:{}",
+            (day.date + Duration::days(1)).format("%Y%m%d")
+        ));
+        self.property(
+            "SUMMARY",
+            format!("{icon} {condition} {low}/{high}{symbol}").trim_start(),
+        );
+        self.property("DESCRIPTION", &description.join("\n"));
+        self.line("TRANSP:TRANSPARENT");
+        self.line("END:VEVENT");
+    }
+
+    fn alert_event(
+        &mut self,
+        alert: &WeatherAlert,
+        window: AlertWindow,
+        utc_offset_seconds: i32,
+        uid_base: &str,
+        stamp: &str,
+    ) {
+        let start_text = window.start.format("%Y%m%dT%H%M%S");
+        let severity = alert.severity.label();
+        self.line("BEGIN:VEVENT");
+        self.property(
+            "UID",
+            &format!("alert-{severity}-{start_text}-{uid_base}@terminal-weather"),
+        );
+        self.line(&format!("DTSTAMP:{stamp}"));
+        if window.is_whole_days() {
+            self.line(&format!(
+                "DTSTART;VALUE=DATE:{}",
+                window.start.format("%Y%m%d")
+            ));
+            self.line(&format!("DTEND;VALUE=DATE:{}", window.end.format("%Y%m%d")));
+        } else {
+            let to_utc = |time: NaiveDateTime| {
+                (time - Duration::seconds(i64::from(utc_offset_seconds)))
+                    .format("%Y%m%dT%H%M%SZ")
+                    .to_string()
+            };
+            self.line(&format!("DTSTART:{}", to_utc(window.start)));
+            self.line(&format!("DTEND:{}", to_utc(window.end)));
+        }
+        self.property("SUMMARY", &format!("{} {}", alert.icon, alert.message));
+        self.property("CATEGORIES", severity);
+        self.line("TRANSP:TRANSPARENT");
+        self.line("END:VEVENT");
+    }
+
+    fn property(&mut self, name: &str, value: &str) {
+        self.line(&format!("{name}:{}", escape_text(value)));
+    }
+
+    /// Appends a content line, folded at 75 octets as RFC 5545 requires.
+    fn line(&mut self, line: &str) {
+        let mut width = 0;
+        for ch in line.chars() {
+            let len = ch.len_utf8();
+            if width + len > MAX_LINE_OCTETS {
+                self.out.push_str("\r\n ");
+                width = 1;
+            }
+            self.out.push(ch);
+            width += len;
+        }
+        self.out.push_str("\r\n");
+    }
+}
+
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn ical_utc(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+const fn unit_symbol(units: Units) -> &'static str {
+    match units {
+        Units::Celsius => "C",
+        Units::Fahrenheit => "F",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_bundle;
+
+    fn unfold(ics: &str) -> String {
+        ics.replace("\r\n ", "")
+    }
+
+    #[test]
+    fn daily_forecast_becomes_all_day_events() {
+        let ics = render(
+            &sample_bundle(),
+            Units::Celsius,
+            MeasureUnits::default(),
+            IconMode::Ascii,
+            &[],
+        );
+        let text = unfold(&ics);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(text.contains("DTSTART;VALUE=DATE:20260212\r\n"));
+        assert!(text.contains("DTEND;VALUE=DATE:20260213\r\n"));
+        assert!(text.contains("UID:day-20260212-59.3293_18.0686@terminal-weather"));
+        assert!(text.contains("Overcast"));
+        assert!(text.contains("Low "));
+        assert_eq!(text.matches("BEGIN:VEVENT").count(), 1);
+    }
+
+    #[test]
+    fn alerts_become_events_over_their_window() {
+        let mut bundle = sample_bundle();
+        bundle.hourly[0].wind_gusts_10m = Some(95.0);
+        bundle.utc_offset_seconds = 3600;
+        let text = unfold(&render(
+            &bundle,
+            Units::Celsius,
+            MeasureUnits::default(),
+            IconMode::Ascii,
+            &[],
+        ));
+
+        assert!(text.contains("DTSTART:20260212T090000Z\r\n"));
+        assert!(text.contains("DTEND:20260212T100000Z\r\n"));
+        assert!(text.contains("CATEGORIES:danger"));
+
+        bundle.daily[0].uv_index_max = Some(9.0);
+        let text = unfold(&render(
+            &bundle,
+            Units::Celsius,
+            MeasureUnits::default(),
+            IconMode::Ascii,
+            &[],
+        ));
+        assert!(text.contains("SUMMARY:☀ UV index very high (9)"));
+        assert_eq!(text.matches("DTEND;VALUE=DATE:20260213\r\n").count(), 2);
+    }
+
+    #[test]
+    fn long_lines_are_folded_and_text_escaped() {
+        let mut cal = Calendar::default();
+        cal.property("DESCRIPTION", &format!("a,b;c\n{}", "x".repeat(100)));
+        let mut lines = cal.out.split("\r\n");
+        let first = lines.next().expect("first line");
+
+        assert!(first.starts_with("DESCRIPTION:a\\,b\\;c\\nxx"));
+        assert_eq!(first.len(), MAX_LINE_OCTETS);
+        assert!(lines.next().expect("continuation").starts_with(' '));
+    }
+}
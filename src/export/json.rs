@@ -0,0 +1,437 @@
+#![allow(clippy::missing_errors_doc)]
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::alerts::{AlertRule, AlertSeverity, scan_alerts};
+use crate::domain::weather::{
+    ActionCue, AirQualityCategory, ChangeEvent, CurrentConditions, DailyForecast, ForecastBundle,
+    HourlyForecast, InsightConfidence, MeasureUnits, RefreshMetadata, Units,
+    derive_nowcast_insight, weather_label,
+};
+use crate::export::{export_temp as temp, export_wind as wind};
+use crate::resilience::freshness::FreshnessState;
+
+/// Bumped whenever a field is renamed, removed or changes meaning. Adding fields
+/// does not bump the version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Top-level `--format json` document.
+#[derive(Debug, Serialize)]
+pub struct ForecastDocument {
+    pub schema_version: u32,
+    pub generated_at: DateTime<Utc>,
+    pub fetched_at: DateTime<Utc>,
+    pub units: UnitsMeta,
+    pub location: LocationJson,
+    pub current: CurrentJson,
+    pub hourly: Vec<HourlyJson>,
+    pub daily: Vec<DailyJson>,
+    pub air_quality: Option<AirQualityJson>,
+    pub alerts: Vec<AlertJson>,
+    pub nowcast: NowcastJson,
+}
+
+/// Units used by every numeric field in the document. Text such as alert
+/// messages and the nowcast is worded in the display units instead.
+#[derive(Debug, Serialize)]
+pub struct UnitsMeta {
+    pub temperature: &'static str,
+    pub wind_speed: &'static str,
+    pub precipitation: &'static str,
+    pub snowfall: &'static str,
+    pub pressure: &'static str,
+    pub visibility: &'static str,
+    pub time: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LocationJson {
+    pub name: String,
+    pub display_name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub country: Option<String>,
+    pub admin1: Option<String>,
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CurrentJson {
+    pub temperature: f32,
+    pub apparent_temperature: f32,
+    pub dew_point: f32,
+    pub humidity_pct: f32,
+    pub weather_code: u8,
+    pub condition: &'static str,
+    pub is_day: bool,
+    pub precipitation: f32,
+    pub cloud_cover_pct: f32,
+    pub pressure: f32,
+    pub visibility: f32,
+    pub wind_speed: f32,
+    pub wind_gusts: f32,
+    pub wind_direction_deg: f32,
+    pub high_today: Option<f32>,
+    pub low_today: Option<f32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HourlyJson {
+    pub time: NaiveDateTime,
+    pub temperature: Option<f32>,
+    pub weather_code: Option<u8>,
+    pub condition: Option<&'static str>,
+    pub is_day: Option<bool>,
+    pub humidity_pct: Option<f32>,
+    pub precipitation_probability_pct: Option<f32>,
+    pub precipitation: Option<f32>,
+    pub rain: Option<f32>,
+    pub snowfall: Option<f32>,
+    pub wind_speed: Option<f32>,
+    pub wind_gusts: Option<f32>,
+    pub pressure: Option<f32>,
+    pub visibility: Option<f32>,
+    pub cloud_cover_pct: Option<f32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DailyJson {
+    pub date: NaiveDate,
+    pub weather_code: Option<u8>,
+    pub condition: Option<&'static str>,
+    pub temperature_max: Option<f32>,
+    pub temperature_min: Option<f32>,
+    pub sunrise: Option<NaiveDateTime>,
+    pub sunset: Option<NaiveDateTime>,
+    pub uv_index_max: Option<f32>,
+    pub precipitation_probability_max_pct: Option<f32>,
+    pub precipitation_sum: Option<f32>,
+    pub rain_sum: Option<f32>,
+    pub snowfall_sum: Option<f32>,
+    pub precipitation_hours: Option<f32>,
+    pub wind_gusts_max: Option<f32>,
+    pub daylight_duration_s: Option<f32>,
+    pub sunshine_duration_s: Option<f32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AirQualityJson {
+    pub us_aqi: Option<u16>,
+    pub european_aqi: Option<u16>,
+    pub category: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AlertJson {
+    pub severity: AlertSeverity,
+    pub message: String,
+    pub eta_hours: Option<usize>,
+    /// Local time the alert's window opens, and closes (exclusive).
+    pub starts_at: Option<NaiveDateTime>,
+    pub ends_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NowcastJson {
+    pub action: ActionCue,
+    pub action_text: String,
+    pub next_change: Option<ChangeEvent>,
+    pub next_6h_summary: String,
+    pub confidence: InsightConfidence,
+    pub freshness: FreshnessState,
+    pub age_minutes: Option<i64>,
+}
+
+impl ForecastDocument {
+    #[must_use]
+    pub fn new(
+        bundle: &ForecastBundle,
+        units: Units,
+        measures: MeasureUnits,
+        refresh_meta: &RefreshMetadata,
+        rules: &[AlertRule],
+    ) -> Self {
+        let insight = derive_nowcast_insight(bundle, units, measures, refresh_meta);
+        Self {
+            schema_version: SCHEMA_VERSION,
+            generated_at: Utc::now(),
+            fetched_at: bundle.fetched_at,
+            units: UnitsMeta::for_units(units),
+            location: LocationJson {
+                name: bundle.location.name.clone(),
+                display_name: bundle.location.display_name(),
+                latitude: bundle.location.latitude,
+                longitude: bundle.location.longitude,
+                country: bundle.location.country.clone(),
+                admin1: bundle.location.admin1.clone(),
+                timezone: bundle.location.timezone.clone(),
+            },
+            current: CurrentJson::new(&bundle.current, units),
+            hourly: bundle
+                .hourly
+                .iter()
+                .map(|hour| HourlyJson::new(hour, units))
+                .collect(),
+            daily: bundle
+                .daily
+                .iter()
+                .map(|day| DailyJson::new(day, units))
+                .collect(),
+            air_quality: bundle.air_quality.as_ref().map(|reading| AirQualityJson {
+                us_aqi: reading.us_aqi,
+                european_aqi: reading.european_aqi,
+                category: air_quality_key(reading.category),
+            }),
+            alerts: scan_alerts(bundle, units, measures, rules)
+                .into_iter()
+                .map(|alert| AlertJson {
+                    severity: alert.severity,
+                    message: alert.message,
+                    eta_hours: alert.eta_hours,
+                    starts_at: alert.window.map(|window| window.start),
+                    ends_at: alert.window.map(|window| window.end),
+                })
+                .collect(),
+            nowcast: NowcastJson {
+                action: insight.action,
+                action_text: insight.action_text,
+                next_change: insight.next_change,
+                next_6h_summary: insight.next_6h_summary,
+                confidence: insight.confidence,
+                freshness: insight.reliability.state,
+                age_minutes: insight.reliability.age_minutes,
+            },
+        }
+    }
+}
+
+impl UnitsMeta {
+    #[must_use]
+    pub const fn for_units(units: Units) -> Self {
+        Self {
+            temperature: match units {
+                Units::Celsius => "C",
+                Units::Fahrenheit => "F",
+            },
+            wind_speed: "m/s",
+            precipitation: "mm",
+            snowfall: "cm",
+            pressure: "hPa",
+            visibility: "m",
+            time: "location-local",
+        }
+    }
+}
+
+impl CurrentJson {
+    fn new(current: &CurrentConditions, units: Units) -> Self {
+        Self {
+            temperature: temp(current.temperature_2m_c, units),
+            apparent_temperature: temp(current.apparent_temperature_c, units),
+            dew_point: temp(current.dew_point_2m_c, units),
+            humidity_pct: current.relative_humidity_2m,
+            weather_code: current.weather_code,
+            condition: weather_label(current.weather_code),
+            is_day: current.is_day,
+            precipitation: current.precipitation_mm,
+            cloud_cover_pct: current.cloud_cover,
+            pressure: current.pressure_msl_hpa,
+            visibility: current.visibility_m,
+            wind_speed: wind(current.wind_speed_10m),
+            wind_gusts: wind(current.wind_gusts_10m),
+            wind_direction_deg: current.wind_direction_10m,
+            high_today: current.high_today_c.map(|c| temp(c, units)),
+            low_today: current.low_today_c.map(|c| temp(c, units)),
+        }
+    }
+}
+
+impl HourlyJson {
+    fn new(hour: &HourlyForecast, units: Units) -> Self {
+        Self {
+            time: hour.time,
+            temperature: hour.temperature_2m_c.map(|c| temp(c, units)),
+            weather_code: hour.weather_code,
+            condition: hour.weather_code.map(weather_label),
+            is_day: hour.is_day,
+            humidity_pct: hour.relative_humidity_2m,
+            precipitation_probability_pct: hour.precipitation_probability,
+            precipitation: hour.precipitation_mm,
+            rain: hour.rain_mm,
+            snowfall: hour.snowfall_cm,
+            wind_speed: hour.wind_speed_10m.map(wind),
+            wind_gusts: hour.wind_gusts_10m.map(wind),
+            pressure: hour.pressure_msl_hpa,
+            visibility: hour.visibility_m,
+            cloud_cover_pct: hour.cloud_cover,
+        }
+    }
+}
+
+impl DailyJson {
+    fn new(day: &DailyForecast, units: Units) -> Self {
+        Self {
+            date: day.date,
+            weather_code: day.weather_code,
+            condition: day.weather_code.map(weather_label),
+            temperature_max: day.temperature_max_c.map(|c| temp(c, units)),
+            temperature_min: day.temperature_min_c.map(|c| temp(c, units)),
+            sunrise: day.sunrise,
+            sunset: day.sunset,
+            uv_index_max: day.uv_index_max,
+            precipitation_probability_max_pct: day.precipitation_probability_max,
+            precipitation_sum: day.precipitation_sum_mm,
+            rain_sum: day.rain_sum_mm,
+            snowfall_sum: day.snowfall_sum_cm,
+            precipitation_hours: day.precipitation_hours,
+            wind_gusts_max: day.wind_gusts_10m_max.map(wind),
+            daylight_duration_s: day.daylight_duration_s,
+            sunshine_duration_s: day.sunshine_duration_s,
+        }
+    }
+}
+
+/// Serializes the full forecast document as pretty-printed JSON.
+pub fn render(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+    refresh_meta: &RefreshMetadata,
+    rules: &[AlertRule],
+) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&ForecastDocument::new(
+        bundle,
+        units,
+        measures,
+        refresh_meta,
+        rules,
+    ))
+}
+
+/// A document with the metadata keys, the freshness state and one top-level
+/// section (`current`, `hourly`, `daily` or `alerts`) of the full document.
+pub fn render_section(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+    refresh_meta: &RefreshMetadata,
+    rules: &[AlertRule],
+    section: &str,
+) -> serde_json::Result<serde_json::Value> {
+    let mut value = serde_json::to_value(ForecastDocument::new(
+        bundle,
+        units,
+        measures,
+        refresh_meta,
+        rules,
+    ))?;
+    let mut body = serde_json::Map::new();
+    for key in [
+        "schema_version",
+        "generated_at",
+        "fetched_at",
+        "units",
+        "location",
+    ] {
+        body.insert(key.to_string(), value[key].take());
+    }
+    body.insert(
+        "freshness".to_string(),
+        value["nowcast"]["freshness"].take(),
+    );
+    body.insert(section.to_string(), value[section].take());
+    Ok(serde_json::Value::Object(body))
+}
+
+const fn air_quality_key(category: AirQualityCategory) -> &'static str {
+    match category {
+        AirQualityCategory::Good => "good",
+        AirQualityCategory::Moderate => "moderate",
+        AirQualityCategory::UnhealthySensitive => "unhealthy_sensitive",
+        AirQualityCategory::Unhealthy => "unhealthy",
+        AirQualityCategory::VeryUnhealthy => "very_unhealthy",
+        AirQualityCategory::Hazardous => "hazardous",
+        AirQualityCategory::Unknown => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_bundle;
+
+    fn fresh_meta() -> RefreshMetadata {
+        let mut meta = RefreshMetadata::default();
+        meta.mark_success();
+        meta
+    }
+
+    #[test]
+    fn document_converts_units_and_declares_them() {
+        let bundle = sample_bundle();
+        let doc = ForecastDocument::new(
+            &bundle,
+            Units::Fahrenheit,
+            MeasureUnits::default(),
+            &fresh_meta(),
+            &[],
+        );
+
+        assert_eq!(doc.schema_version, SCHEMA_VERSION);
+        assert_eq!(doc.units.temperature, "F");
+        assert!((doc.current.temperature - 44.6).abs() < f32::EPSILON);
+        assert!((doc.current.wind_speed - 2.8).abs() < f32::EPSILON);
+        assert_eq!(doc.current.condition, "Overcast");
+        assert_eq!(doc.hourly.len(), bundle.hourly.len());
+        assert_eq!(doc.daily.len(), bundle.daily.len());
+        assert_eq!(doc.nowcast.freshness, FreshnessState::Fresh);
+    }
+
+    #[test]
+    fn messages_follow_measure_units_while_numbers_stay_fixed() {
+        let mut bundle = sample_bundle();
+        bundle.hourly[0].wind_gusts_10m = Some(95.0);
+        let measures = MeasureUnits {
+            wind: crate::domain::weather::WindUnit::Kmh,
+            ..MeasureUnits::default()
+        };
+        let doc = ForecastDocument::new(&bundle, Units::Celsius, measures, &fresh_meta(), &[]);
+
+        assert_eq!(doc.units.wind_speed, "m/s");
+        assert_eq!(doc.hourly[0].wind_gusts, Some(26.4));
+        assert_eq!(doc.alerts[0].message, "Forecast gusts up to 95 km/h");
+    }
+
+    #[test]
+    fn rendered_json_has_stable_top_level_keys() {
+        let rendered = render(
+            &sample_bundle(),
+            Units::Celsius,
+            MeasureUnits::default(),
+            &fresh_meta(),
+            &[],
+        )
+        .expect("json");
+        let value: serde_json::Value = serde_json::from_str(&rendered).expect("parse json");
+
+        for key in [
+            "schema_version",
+            "generated_at",
+            "fetched_at",
+            "units",
+            "location",
+            "current",
+            "hourly",
+            "daily",
+            "air_quality",
+            "alerts",
+            "nowcast",
+        ] {
+            assert!(value.get(key).is_some(), "missing key {key}");
+        }
+        assert_eq!(value["hourly"][0]["time"], "2026-02-12T10:00:00");
+        assert_eq!(value["nowcast"]["freshness"], "fresh");
+        assert!(value["air_quality"].is_null());
+    }
+}
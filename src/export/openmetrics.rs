@@ -0,0 +1,295 @@
+use std::fmt::Write as _;
+
+use chrono::Utc;
+
+use crate::domain::alerts::{AlertRule, AlertSeverity, scan_alerts};
+use crate::domain::weather::{
+    ForecastBundle, MeasureUnits, RefreshMetadata, Units, convert_wind_speed,
+};
+
+const PREFIX: &str = "terminal_weather";
+
+/// Content type for OpenMetrics responses.
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Renders current conditions, next-hour values, air quality and alert counts as
+/// OpenMetrics gauges labelled with the location. Values use Prometheus base
+/// units (Celsius, meters, m/s) regardless of `--units`.
+#[must_use]
+pub fn render(
+    bundle: &ForecastBundle,
+    refresh_meta: &RefreshMetadata,
+    rules: &[AlertRule],
+) -> String {
+    let mut out = Exposition::new(&bundle.location.display_name());
+    let current = &bundle.current;
+
+    for (metric, unit, help, value) in [
+        (
+            "temperature",
+            "celsius",
+            "Air temperature at 2m",
+            current.temperature_2m_c,
+        ),
+        (
+            "apparent_temperature",
+            "celsius",
+            "Feels-like temperature",
+            current.apparent_temperature_c,
+        ),
+        (
+            "dew_point",
+            "celsius",
+            "Dew point at 2m",
+            current.dew_point_2m_c,
+        ),
+        (
+            "relative_humidity",
+            "percent",
+            "Relative humidity",
+            current.relative_humidity_2m,
+        ),
+        (
+            "precipitation",
+            "millimeters",
+            "Precipitation this hour",
+            current.precipitation_mm,
+        ),
+        (
+            "cloud_cover",
+            "percent",
+            "Total cloud cover",
+            current.cloud_cover,
+        ),
+        (
+            "pressure",
+            "hectopascals",
+            "Mean sea-level pressure",
+            current.pressure_msl_hpa,
+        ),
+        (
+            "visibility",
+            "meters",
+            "Horizontal visibility",
+            current.visibility_m,
+        ),
+        (
+            "wind_speed",
+            "meters_per_second",
+            "Wind speed at 10m",
+            convert_wind_speed(current.wind_speed_10m),
+        ),
+        (
+            "wind_gusts",
+            "meters_per_second",
+            "Wind gusts at 10m",
+            convert_wind_speed(current.wind_gusts_10m),
+        ),
+        (
+            "wind_direction",
+            "degrees",
+            "Wind direction at 10m",
+            current.wind_direction_10m,
+        ),
+        (
+            "weather_code",
+            "",
+            "WMO weather code",
+            f32::from(current.weather_code),
+        ),
+        (
+            "is_day",
+            "",
+            "1 during daylight",
+            f32::from(u8::from(current.is_day)),
+        ),
+    ] {
+        out.gauge(metric, unit, help, Some(value));
+    }
+
+    if let Some(next) = bundle.hourly.get(1) {
+        out.gauge(
+            "next_hour_temperature",
+            "celsius",
+            "Forecast air temperature for the next hour",
+            next.temperature_2m_c,
+        );
+        out.gauge(
+            "next_hour_precipitation_probability",
+            "percent",
+            "Forecast precipitation probability for the next hour",
+            next.precipitation_probability,
+        );
+        out.gauge(
+            "next_hour_precipitation",
+            "millimeters",
+            "Forecast precipitation for the next hour",
+            next.precipitation_mm,
+        );
+        out.gauge(
+            "next_hour_wind_gusts",
+            "meters_per_second",
+            "Forecast wind gusts for the next hour",
+            next.wind_gusts_10m.map(convert_wind_speed),
+        );
+    }
+
+    if let Some(reading) = &bundle.air_quality {
+        out.gauge(
+            "air_quality_us_aqi",
+            "",
+            "US air quality index",
+            reading.us_aqi.map(f32::from),
+        );
+        out.gauge(
+            "air_quality_european_aqi",
+            "",
+            "European air quality index",
+            reading.european_aqi.map(f32::from),
+        );
+    }
+
+    out.alert_counts(bundle, rules);
+    out.refresh_gauges(bundle, refresh_meta);
+    out.finish()
+}
+
+struct Exposition {
+    body: String,
+    location: String,
+}
+
+impl Exposition {
+    fn new(location: &str) -> Self {
+        Self {
+            body: String::new(),
+            location: escape_label(location),
+        }
+    }
+
+    fn header(&mut self, name: &str, unit: &str, help: &str) {
+        let _ = writeln!(self.body, "# TYPE {name} gauge");
+        if !unit.is_empty() {
+            let _ = writeln!(self.body, "# UNIT {name} {unit}");
+        }
+        let _ = writeln!(self.body, "# HELP {name} {help}.");
+    }
+
+    fn gauge(&mut self, metric: &str, unit: &str, help: &str, value: Option<f32>) {
+        let Some(value) = value.filter(|v| v.is_finite()) else {
+            return;
+        };
+        let name = metric_name(metric, unit);
+        self.header(&name, unit, help);
+        let _ = writeln!(
+            self.body,
+            "{name}{{location=\"{}\"}} {value}",
+            self.location
+        );
+    }
+
+    fn alert_counts(&mut self, bundle: &ForecastBundle, rules: &[AlertRule]) {
+        let name = format!("{PREFIX}_alerts");
+        self.header(&name, "", "Weather alerts in the forecast by severity");
+        let alerts = scan_alerts(bundle, Units::Celsius, MeasureUnits::default(), rules);
+        for (severity, label) in [
+            (AlertSeverity::Info, "info"),
+            (AlertSeverity::Warning, "warning"),
+            (AlertSeverity::Danger, "danger"),
+        ] {
+            let count = alerts
+                .iter()
+                .filter(|alert| alert.severity == severity)
+                .count();
+            let _ = writeln!(
+                self.body,
+                "{name}{{location=\"{}\",severity=\"{label}\"}} {count}",
+                self.location
+            );
+        }
+    }
+
+    fn refresh_gauges(&mut self, bundle: &ForecastBundle, refresh_meta: &RefreshMetadata) {
+        let fetched = format!("{PREFIX}_forecast_fetched_timestamp_seconds");
+        self.header(&fetched, "seconds", "Unix time the forecast was fetched");
+        let _ = writeln!(
+            self.body,
+            "{fetched}{{location=\"{}\"}} {}",
+            self.location,
+            bundle.fetched_at.timestamp()
+        );
+
+        let age = format!("{PREFIX}_forecast_age_seconds");
+        self.header(&age, "seconds", "Age of the forecast data");
+        let _ = writeln!(
+            self.body,
+            "{age}{{location=\"{}\"}} {}",
+            self.location,
+            (Utc::now() - bundle.fetched_at).num_seconds().max(0)
+        );
+
+        let failures = format!("{PREFIX}_refresh_failures");
+        self.header(&failures, "", "Consecutive failed refreshes");
+        let _ = writeln!(
+            self.body,
+            "{failures}{{location=\"{}\"}} {}",
+            self.location, refresh_meta.consecutive_failures
+        );
+    }
+
+    fn finish(mut self) -> String {
+        self.body.push_str("# EOF\n");
+        self.body
+    }
+}
+
+fn metric_name(metric: &str, unit: &str) -> String {
+    if unit.is_empty() {
+        format!("{PREFIX}_{metric}")
+    } else {
+        format!("{PREFIX}_{metric}_{unit}")
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::weather::AirQualityReading;
+    use crate::test_support::{sample_bundle, sample_hourly};
+
+    #[test]
+    fn renders_gauges_with_units_and_location_label() {
+        let mut bundle = sample_bundle();
+        bundle.hourly.push(sample_hourly());
+        bundle.air_quality = AirQualityReading::from_indices(Some(42.0), None);
+        let text = render(&bundle, &RefreshMetadata::default(), &[]);
+
+        assert!(text.contains("# TYPE terminal_weather_temperature_celsius gauge"));
+        assert!(text.contains("# UNIT terminal_weather_temperature_celsius celsius"));
+        assert!(text.contains(
+            "terminal_weather_temperature_celsius{location=\"Stockholm, Stockholm, Sweden\"} 7"
+        ));
+        assert!(text.contains("terminal_weather_next_hour_temperature_celsius{"));
+        assert!(text.contains("terminal_weather_air_quality_us_aqi{"));
+        assert!(!text.contains("terminal_weather_air_quality_european_aqi{"));
+        assert!(text.contains("severity=\"danger\"} 0"));
+        assert!(text.ends_with("# EOF\n"));
+    }
+
+    #[test]
+    fn omits_next_hour_without_forecast_and_escapes_labels() {
+        let mut bundle = sample_bundle();
+        bundle.location.name = "Quote \"Town\"".to_string();
+        let text = render(&bundle, &RefreshMetadata::default(), &[]);
+
+        assert!(!text.contains("next_hour"));
+        assert!(text.contains("location=\"Quote \\\"Town\\\""));
+    }
+}
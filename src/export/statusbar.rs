@@ -0,0 +1,254 @@
+use serde::Serialize;
+
+use crate::cli::IconMode;
+use crate::domain::alerts::{AlertSeverity, WeatherAlert, scan_alerts};
+use crate::domain::weather::{
+    DailyForecast, PrecipUnit, Units, convert_temp, round_temp, weather_icon,
+};
+use crate::export::template::{Template, TemplateContext};
+use crate::resilience::freshness::FreshnessState;
+
+const BAR_TEXT: &str = "%i %t";
+const BAR_SHORT_TEXT: &str = "%t";
+const TOOLTIP_HEADLINE: &str = "%l · %C · feels %f · wind %w";
+
+/// Sent once before the first i3bar status line.
+pub const I3BAR_HEADER: &str = "{\"version\":1}\n[";
+
+/// One waybar `custom` module update (`return-type: json`).
+#[derive(Debug, Serialize)]
+pub struct WaybarOutput {
+    pub text: String,
+    pub tooltip: String,
+    /// Highest alert severity (`clear`, `info`, `warning`, `danger`) followed by
+    /// the data freshness (`fresh`, `stale`, `offline`).
+    pub class: Vec<&'static str>,
+    /// Precipitation probability for the next hour, for `format-icons`.
+    pub percentage: u8,
+}
+
+/// One i3bar protocol block.
+#[derive(Debug, Serialize)]
+pub struct I3barBlock {
+    pub name: &'static str,
+    pub full_text: String,
+    pub short_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<&'static str>,
+    pub urgent: bool,
+}
+
+impl WaybarOutput {
+    #[must_use]
+    pub fn new(ctx: &TemplateContext<'_>) -> Self {
+        let alerts = scan_alerts(ctx.bundle, ctx.units, ctx.measures, ctx.alert_rules);
+        Self {
+            text: render(BAR_TEXT, ctx),
+            tooltip: tooltip(ctx, &alerts),
+            class: vec![
+                severity_class(alerts.first().map(|alert| alert.severity)),
+                freshness_class(ctx.refresh_meta.state),
+            ],
+            percentage: next_hour_precipitation(ctx),
+        }
+    }
+}
+
+impl I3barBlock {
+    #[must_use]
+    pub fn new(ctx: &TemplateContext<'_>) -> Self {
+        let severity = scan_alerts(ctx.bundle, ctx.units, ctx.measures, ctx.alert_rules)
+            .first()
+            .map(|alert| alert.severity);
+        let color = match (severity, ctx.refresh_meta.state) {
+            (Some(AlertSeverity::Danger), _) => Some("#ff5555"),
+            (Some(AlertSeverity::Warning), _) => Some("#f1fa8c"),
+            (_, FreshnessState::Stale | FreshnessState::Offline) => Some("#888888"),
+            _ => None,
+        };
+        Self {
+            name: "weather",
+            full_text: render(BAR_TEXT, ctx),
+            short_text: render(BAR_SHORT_TEXT, ctx),
+            color,
+            urgent: severity == Some(AlertSeverity::Danger),
+        }
+    }
+}
+
+/// A single waybar JSON line.
+pub fn waybar_line(ctx: &TemplateContext<'_>) -> serde_json::Result<String> {
+    serde_json::to_string(&WaybarOutput::new(ctx))
+}
+
+/// A single i3bar status line. Every line after the first starts with a comma so
+/// the stream stays one infinite JSON array.
+pub fn i3bar_line(ctx: &TemplateContext<'_>, first: bool) -> serde_json::Result<String> {
+    let blocks = serde_json::to_string(&[I3barBlock::new(ctx)])?;
+    Ok(if first { blocks } else { format!(",{blocks}") })
+}
+
+/// `Thu 12   ☁      3° / 8°    1.2mm`, shared by the text snapshot and tooltips.
+#[must_use]
+pub fn daily_line(
+    day: &DailyForecast,
+    units: Units,
+    precip_unit: PrecipUnit,
+    icon_mode: IconMode,
+) -> String {
+    let day_name = day.date.format("%a %d").to_string();
+    let icon = day
+        .weather_code
+        .map_or("--", |code| weather_icon(code, icon_mode, true));
+    let degrees = |celsius: Option<f32>| {
+        celsius.map_or_else(
+            || "--".to_string(),
+            |c| format!("{}°", round_temp(convert_temp(c, units))),
+        )
+    };
+    let min = degrees(day.temperature_min_c);
+    let max = degrees(day.temperature_max_c);
+    let precip = day
+        .precipitation_sum_mm
+        .map_or_else(|| "--".to_string(), |p| precip_unit.format(p));
+    format!("{day_name:<8} {icon:<4} {min:>4} / {max:<4}  {precip}")
+}
+
+fn tooltip(ctx: &TemplateContext<'_>, alerts: &[WeatherAlert]) -> String {
+    let mut lines = vec![render(TOOLTIP_HEADLINE, ctx)];
+    lines.extend(alerts.iter().map(|alert| {
+        format!(
+            "{} {} ({})",
+            alert.icon,
+            alert.message,
+            alert.timing_label()
+        )
+    }));
+    if ctx.refresh_meta.state != FreshnessState::Fresh {
+        let age = ctx
+            .refresh_meta
+            .age_label()
+            .unwrap_or_else(|| "--".to_string());
+        lines.push(format!("Data is {age} old"));
+    }
+    lines.push(String::new());
+    lines.extend(
+        ctx.bundle
+            .daily
+            .iter()
+            .map(|day| daily_line(day, ctx.units, ctx.measures.precip, ctx.icon_mode)),
+    );
+    lines.join("\n")
+}
+
+fn render(source: &str, ctx: &TemplateContext<'_>) -> String {
+    Template::parse(source)
+        .map(|template| template.render(ctx))
+        .unwrap_or_default()
+}
+
+const fn severity_class(severity: Option<AlertSeverity>) -> &'static str {
+    match severity {
+        None => "clear",
+        Some(AlertSeverity::Info) => "info",
+        Some(AlertSeverity::Warning) => "warning",
+        Some(AlertSeverity::Danger) => "danger",
+    }
+}
+
+const fn freshness_class(state: FreshnessState) -> &'static str {
+    match state {
+        FreshnessState::Fresh => "fresh",
+        FreshnessState::Stale => "stale",
+        FreshnessState::Offline => "offline",
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn next_hour_precipitation(ctx: &TemplateContext<'_>) -> u8 {
+    ctx.bundle
+        .hourly
+        .first()
+        .and_then(|hour| hour.precipitation_probability)
+        .map_or(0, |pct| pct.clamp(0.0, 100.0).round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::weather::RefreshMetadata;
+    use crate::test_support::sample_bundle;
+
+    fn with_ctx<T>(
+        bundle: &crate::domain::weather::ForecastBundle,
+        state: FreshnessState,
+        f: impl FnOnce(&TemplateContext<'_>) -> T,
+    ) -> T {
+        let mut refresh_meta = RefreshMetadata::default();
+        refresh_meta.mark_success();
+        refresh_meta.state = state;
+        f(&TemplateContext {
+            bundle,
+            units: Units::Celsius,
+            measures: crate::domain::weather::MeasureUnits::default(),
+            icon_mode: IconMode::Ascii,
+            refresh_meta: &refresh_meta,
+            alert_rules: &[],
+        })
+    }
+
+    #[test]
+    fn waybar_output_has_text_tooltip_class_and_percentage() {
+        let bundle = sample_bundle();
+        let line = with_ctx(&bundle, FreshnessState::Fresh, waybar_line).expect("json");
+        let value: serde_json::Value = serde_json::from_str(&line).expect("parse");
+
+        assert!(!line.contains('\n'));
+        assert!(value["text"].as_str().expect("text").ends_with("7°C"));
+        assert_eq!(value["class"], serde_json::json!(["clear", "fresh"]));
+        assert_eq!(value["percentage"], 35);
+        let tooltip = value["tooltip"].as_str().expect("tooltip");
+        assert!(tooltip.starts_with("Stockholm · Overcast"));
+        assert!(tooltip.contains("Thu 12"));
+    }
+
+    #[test]
+    fn class_reflects_alert_severity_and_staleness() {
+        let mut bundle = sample_bundle();
+        bundle.hourly[0].wind_gusts_10m = Some(95.0);
+        let output = with_ctx(&bundle, FreshnessState::Stale, WaybarOutput::new);
+
+        assert_eq!(output.class, vec!["danger", "stale"]);
+        assert!(output.tooltip.contains("old"));
+    }
+
+    #[test]
+    fn i3bar_lines_continue_the_infinite_array() {
+        let bundle = sample_bundle();
+        let first = with_ctx(&bundle, FreshnessState::Offline, |ctx| {
+            i3bar_line(ctx, true)
+        })
+        .expect("first line");
+        let next = with_ctx(&bundle, FreshnessState::Offline, |ctx| {
+            i3bar_line(ctx, false)
+        })
+        .expect("next line");
+
+        assert!(first.starts_with("[{\"name\":\"weather\""));
+        assert!(first.contains("\"color\":\"#888888\""));
+        assert_eq!(next, format!(",{first}"));
+    }
+
+    #[test]
+    fn daily_line_handles_missing_values() {
+        let mut day = sample_bundle().daily[0].clone();
+        assert!(
+            daily_line(&day, Units::Celsius, PrecipUnit::Mm, IconMode::Ascii).starts_with("Thu 12")
+        );
+        day.temperature_min_c = None;
+        day.precipitation_sum_mm = None;
+        let line = daily_line(&day, Units::Celsius, PrecipUnit::Mm, IconMode::Ascii);
+        assert!(line.contains("-- /"));
+        assert!(line.ends_with("--"));
+    }
+}
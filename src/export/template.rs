@@ -0,0 +1,228 @@
+use std::fmt;
+
+use crate::cli::IconMode;
+use crate::domain::alerts::{AlertRule, scan_alerts};
+use crate::domain::weather::{
+    ForecastBundle, MeasureUnits, RefreshMetadata, Units, convert_temp, derive_nowcast_insight,
+    round_temp, weather_icon, weather_label,
+};
+use crate::ui::widgets::landmark::shared::compass_arrow;
+
+/// Placeholders understood by `--format` templates, with the value each expands to.
+pub const PLACEHOLDERS: &[(char, &str)] = &[
+    ('i', "weather icon"),
+    ('C', "condition"),
+    ('t', "temperature"),
+    ('f', "feels-like temperature"),
+    ('d', "dew point"),
+    ('h', "humidity"),
+    ('w', "wind direction and speed"),
+    ('g', "wind gusts"),
+    ('p', "precipitation"),
+    ('P', "pressure"),
+    ('v', "visibility"),
+    ('k', "cloud cover"),
+    ('H', "today's high"),
+    ('L', "today's low"),
+    ('l', "location"),
+    ('a', "most severe alert"),
+    ('A', "suggested action"),
+    ('%', "a literal %"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Placeholder(char),
+}
+
+/// A parsed wttr.in-style template such as `"%i %t (%f) %w"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    UnknownPlaceholder(char),
+    TrailingPercent,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPlaceholder(ch) => {
+                write!(f, "unknown placeholder %{ch}; known placeholders are")?;
+                for (key, meaning) in PLACEHOLDERS {
+                    write!(f, " %{key} ({meaning})")?;
+                }
+                Ok(())
+            }
+            Self::TrailingPercent => write!(f, "template ends with a lone %"),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Inputs a template is rendered against.
+pub struct TemplateContext<'a> {
+    pub bundle: &'a ForecastBundle,
+    pub units: Units,
+    pub measures: MeasureUnits,
+    pub icon_mode: IconMode,
+    pub refresh_meta: &'a RefreshMetadata,
+    pub alert_rules: &'a [AlertRule],
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Result<Self, TemplateError> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = source.chars();
+        while let Some(ch) = chars.next() {
+            if ch != '%' {
+                literal.push(ch);
+                continue;
+            }
+            let key = chars.next().ok_or(TemplateError::TrailingPercent)?;
+            if !PLACEHOLDERS.iter().any(|(known, _)| *known == key) {
+                return Err(TemplateError::UnknownPlaceholder(key));
+            }
+            if key == '%' {
+                literal.push('%');
+                continue;
+            }
+            if !literal.is_empty() {
+                segments.push(Segment::Literal(std::mem::take(&mut literal)));
+            }
+            segments.push(Segment::Placeholder(key));
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    #[must_use]
+    pub fn render(&self, ctx: &TemplateContext<'_>) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.clone(),
+                Segment::Placeholder(key) => expand(*key, ctx),
+            })
+            .collect()
+    }
+}
+
+fn expand(key: char, ctx: &TemplateContext<'_>) -> String {
+    let current = &ctx.bundle.current;
+    match key {
+        'i' => weather_icon(current.weather_code, ctx.icon_mode, current.is_day).to_string(),
+        'C' => weather_label(current.weather_code).to_string(),
+        't' => temperature(current.temperature_2m_c, ctx.units),
+        'f' => temperature(current.apparent_temperature_c, ctx.units),
+        'd' => temperature(current.dew_point_2m_c, ctx.units),
+        'h' => format!("{:.0}%", current.relative_humidity_2m),
+        'w' => format!(
+            "{}{}",
+            compass_arrow(current.wind_direction_10m),
+            wind_speed(current.wind_speed_10m, ctx.measures)
+        ),
+        'g' => wind_speed(current.wind_gusts_10m, ctx.measures),
+        'p' => ctx.measures.precip.format(current.precipitation_mm),
+        'P' => ctx.measures.pressure.format(current.pressure_msl_hpa),
+        'v' => ctx.measures.distance.format(current.visibility_m),
+        'k' => format!("{:.0}%", current.cloud_cover),
+        'H' => current
+            .high_today_c
+            .map_or_else(|| "--".to_string(), |c| temperature(c, ctx.units)),
+        'L' => current
+            .low_today_c
+            .map_or_else(|| "--".to_string(), |c| temperature(c, ctx.units)),
+        'l' => ctx.bundle.location.name.clone(),
+        'a' => scan_alerts(ctx.bundle, ctx.units, ctx.measures, ctx.alert_rules)
+            .into_iter()
+            .next()
+            .map(|alert| format!("{} {}", alert.icon, alert.message))
+            .unwrap_or_default(),
+        'A' => {
+            derive_nowcast_insight(ctx.bundle, ctx.units, ctx.measures, ctx.refresh_meta)
+                .action_text
+        }
+        _ => String::new(),
+    }
+}
+
+/// `12m/s`, without the space the hero panel puts before the unit.
+fn wind_speed(kmh: f32, measures: MeasureUnits) -> String {
+    format!("{}{}", measures.wind.round(kmh), measures.wind.symbol())
+}
+
+fn temperature(celsius: f32, units: Units) -> String {
+    let symbol = match units {
+        Units::Celsius => "C",
+        Units::Fahrenheit => "F",
+    };
+    format!("{}°{symbol}", round_temp(convert_temp(celsius, units)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_bundle;
+
+    fn render(source: &str, bundle: &ForecastBundle, units: Units) -> String {
+        let refresh_meta = RefreshMetadata::default();
+        Template::parse(source)
+            .expect("valid template")
+            .render(&TemplateContext {
+                bundle,
+                units,
+                measures: MeasureUnits::default(),
+                icon_mode: IconMode::Ascii,
+                refresh_meta: &refresh_meta,
+                alert_rules: &[],
+            })
+    }
+
+    #[test]
+    fn renders_current_conditions_placeholders() {
+        let bundle = sample_bundle();
+        assert_eq!(
+            render("%t (%f) %h %p", &bundle, Units::Celsius),
+            "7°C (5°C) 72% 0.0mm"
+        );
+        assert_eq!(render("%t", &bundle, Units::Fahrenheit), "45°F");
+        assert!(render("%w", &bundle, Units::Celsius).ends_with("3m/s"));
+        assert_eq!(
+            render("%C in %l", &bundle, Units::Celsius),
+            "Overcast in Stockholm"
+        );
+    }
+
+    #[test]
+    fn percent_escape_and_literals_pass_through() {
+        let bundle = sample_bundle();
+        assert_eq!(render("rh=%h %%", &bundle, Units::Celsius), "rh=72% %");
+        assert_eq!(render("plain", &bundle, Units::Celsius), "plain");
+    }
+
+    #[test]
+    fn rejects_unknown_and_dangling_placeholders() {
+        assert_eq!(
+            Template::parse("%t %q"),
+            Err(TemplateError::UnknownPlaceholder('q'))
+        );
+        assert_eq!(Template::parse("%t %"), Err(TemplateError::TrailingPercent));
+    }
+
+    #[test]
+    fn alert_and_action_placeholders_render_text() {
+        let mut bundle = sample_bundle();
+        bundle.hourly[0].wind_gusts_10m = Some(80.0);
+        assert!(!render("%a", &bundle, Units::Celsius).is_empty());
+        assert!(!render("%A", &bundle, Units::Celsius).is_empty());
+    }
+}
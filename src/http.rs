@@ -0,0 +1,180 @@
+//! Minimal HTTP/1.1 responder for the headless modes. It answers `GET` and
+//! `HEAD` with one response per connection and nothing else.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::{sleep, timeout};
+
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+/// How long a client may take to send its request head, and to read the
+/// response, before the connection is dropped.
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+/// Pause after a failed `accept`, such as when file descriptors run out, so
+/// the loop does not spin while connections drain.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    #[must_use]
+    pub fn ok(content_type: &'static str, body: String) -> Self {
+        Self {
+            status: 200,
+            content_type,
+            body,
+        }
+    }
+
+    #[must_use]
+    pub fn not_found() -> Self {
+        Self::plain(404, "not found\n")
+    }
+
+    #[must_use]
+    pub fn unavailable(message: &str) -> Self {
+        Self::plain(503, &format!("{message}\n"))
+    }
+
+    fn plain(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: body.to_string(),
+        }
+    }
+}
+
+/// Accepts connections forever, answering each request with `handler(path)`.
+/// Failed accepts are logged and retried after a short pause, so running out
+/// of file descriptors only delays new connections.
+pub async fn serve<F>(listener: TcpListener, handler: F)
+where
+    F: Fn(&str) -> Response + Send + Sync + 'static,
+{
+    let handler = Arc::new(handler);
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let handler = Arc::clone(&handler);
+                tokio::spawn(async move {
+                    let _ = handle_connection(stream, handler.as_ref()).await;
+                });
+            }
+            Err(err) => {
+                eprintln!("terminal-weather: could not accept connection: {err}");
+                sleep(ACCEPT_BACKOFF).await;
+            }
+        }
+    }
+}
+
+async fn handle_connection<F>(mut stream: TcpStream, handler: &F) -> Result<()>
+where
+    F: Fn(&str) -> Response,
+{
+    let head = timeout(IO_TIMEOUT, read_request_head(&mut stream)).await??;
+    let (response, include_body) = match parse_request_line(&head) {
+        Some(("GET", path)) => (handler(path), true),
+        Some(("HEAD", path)) => (handler(path), false),
+        Some(_) => (Response::plain(405, "method not allowed\n"), true),
+        None => (Response::plain(400, "bad request\n"), true),
+    };
+    let bytes = encode_response(&response, include_body);
+    timeout(IO_TIMEOUT, async {
+        stream.write_all(&bytes).await?;
+        stream.shutdown().await
+    })
+    .await??;
+    Ok(())
+}
+
+async fn read_request_head(stream: &mut TcpStream) -> Result<String> {
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0_u8; 1024];
+    while !buf.windows(4).any(|w| w == b"\r\n\r\n") && buf.len() < MAX_REQUEST_BYTES {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..read]);
+    }
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Returns the method and path (without query string) of a request head.
+fn parse_request_line(head: &str) -> Option<(&str, &str)> {
+    let mut parts = head.lines().next()?.split_whitespace();
+    let method = parts.next()?;
+    let target = parts.next()?;
+    parts.next()?.starts_with("HTTP/").then_some(())?;
+    let path = target.split('?').next().unwrap_or(target);
+    Some((method, path))
+}
+
+fn encode_response(response: &Response, include_body: bool) -> Vec<u8> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Service Unavailable",
+    };
+    let mut out = format!(
+        "HTTP/1.1 {} {reason}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
+    )
+    .into_bytes();
+    if include_body {
+        out.extend_from_slice(response.body.as_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_method_and_path_without_query() {
+        assert_eq!(
+            parse_request_line("GET /metrics?x=1 HTTP/1.1\r\nHost: a\r\n\r\n"),
+            Some(("GET", "/metrics"))
+        );
+        assert_eq!(parse_request_line("garbage"), None);
+    }
+
+    #[tokio::test]
+    async fn serves_handler_responses_over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        tokio::spawn(serve(listener, |path| match path {
+            "/hello" => Response::ok("text/plain", "hi".to_string()),
+            _ => Response::not_found(),
+        }));
+
+        let body = reqwest::get(format!("http://{addr}/hello"))
+            .await
+            .expect("request")
+            .text()
+            .await
+            .expect("body");
+        assert_eq!(body, "hi");
+
+        let status = reqwest::get(format!("http://{addr}/missing"))
+            .await
+            .expect("request")
+            .status();
+        assert_eq!(status, 404);
+    }
+}
@@ -0,0 +1,113 @@
+//! `--inline`: runs the app below the shell prompt in a ratatui inline viewport
+//! instead of the alternate screen, and leaves the last frame in the scrollback.
+
+use std::io::{self, IsTerminal, Stdout};
+use std::time::Duration;
+
+use anyhow::Result;
+use crossterm::event::{Event, KeyEventKind};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use futures::StreamExt;
+use ratatui::{Terminal, TerminalOptions, Viewport, backend::CrosstermBackend};
+use tokio::sync::mpsc;
+use tokio::time::{Instant, sleep_until};
+
+use crate::app::events::{AppEvent, spawn_input_task};
+use crate::app::state::{AppMode, AppState};
+use crate::cli::Cli;
+use crate::ui::inline::{INLINE_HEIGHT, render};
+
+pub(crate) async fn run(cli: &Cli) -> Result<()> {
+    if !io::stdout().is_terminal() {
+        anyhow::bail!("--inline requires an interactive TTY; use --one-shot for piped output");
+    }
+    crate::install_panic_hook();
+    enable_raw_mode()?;
+    let mut terminal = Terminal::with_options(
+        CrosstermBackend::new(io::stdout()),
+        TerminalOptions {
+            viewport: Viewport::Inline(INLINE_HEIGHT),
+        },
+    )?;
+    let result = run_inner(&mut terminal, cli).await;
+    disable_raw_mode()?;
+    terminal.show_cursor()?;
+    println!();
+    result
+}
+
+/// Animates until a key is pressed, or until `--inline-seconds` after the first
+/// fetch settles, then draws one last frame with motion stopped.
+async fn run_inner(terminal: &mut Terminal<CrosstermBackend<Stdout>>, cli: &Cli) -> Result<()> {
+    let (tx, mut rx) = mpsc::channel::<AppEvent>(256);
+    let input_stream = spawn_input_task();
+    tokio::pin!(input_stream);
+    let mut app = AppState::new(cli);
+    app.handle_event(AppEvent::Bootstrap, &tx, cli).await?;
+    let mut deadline = None;
+
+    while app.running {
+        tokio::select! {
+            maybe_input = input_stream.next() => match maybe_input {
+                Some(Event::Key(key)) if key.kind == KeyEventKind::Press => break,
+                Some(input) => app.handle_event(AppEvent::Input(input), &tx, cli).await?,
+                None => {}
+            },
+            maybe_event = rx.recv() => {
+                if let Some(event) = maybe_event {
+                    app.handle_event(event, &tx, cli).await?;
+                }
+            }
+            () = sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => break,
+        }
+
+        if app.mode == AppMode::SelectingLocation
+            && let Some(first) = app.pending_locations.first().cloned()
+        {
+            app.switch_to_location(&tx, first);
+        }
+        if deadline.is_none() && cli.inline_seconds > 0 && first_fetch_settled(&app) {
+            deadline = Some(Instant::now() + Duration::from_secs(cli.inline_seconds));
+        }
+        app.viewport_width = terminal.size()?.width;
+        terminal.draw(|frame| render(frame, &app))?;
+        if app.mode == AppMode::Quit {
+            app.running = false;
+        }
+    }
+
+    app.animate_ui = false;
+    app.particles.reset();
+    terminal.draw(|frame| render(frame, &app))?;
+    Ok(())
+}
+
+fn first_fetch_settled(app: &AppState) -> bool {
+    !app.fetch_in_flight && matches!(app.mode, AppMode::Ready | AppMode::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn run_errors_without_tty() {
+        let mut cli = crate::test_support::state_test_cli();
+        cli.inline = true;
+        let err = run(&cli)
+            .await
+            .expect_err("tests have no interactive stdout");
+        assert!(err.to_string().contains("interactive TTY"));
+    }
+
+    #[test]
+    fn first_fetch_settles_once_ready_and_idle() {
+        let mut app = AppState::new(&crate::test_support::state_test_cli());
+        assert!(!first_fetch_settled(&app));
+        app.mode = AppMode::Ready;
+        app.fetch_in_flight = true;
+        assert!(!first_fetch_settled(&app));
+        app.fetch_in_flight = false;
+        assert!(first_fetch_settled(&app));
+    }
+}
@@ -1,9 +1,11 @@
+use std::process::ExitCode;
+
 use anyhow::Result;
 use clap::Parser;
 use terminal_weather::cli::Cli;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
     let cli = Cli::parse();
     cli.validate()?;
     terminal_weather::run(cli).await
@@ -0,0 +1,113 @@
+//! Headless Prometheus exporter: `--metrics-file` and `--metrics-listen`.
+
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use anyhow::{Context, Result};
+use tokio::net::TcpListener;
+
+use crate::cli::Cli;
+use crate::export::openmetrics;
+use crate::http::{self, Response};
+use crate::one_shot::watch::watch_loop;
+
+type Latest = Arc<RwLock<Option<String>>>;
+
+/// Refreshes every `--refresh-interval` seconds (with the `--watch` jitter and
+/// retry backoff), rewriting the textfile and/or updating the body served on
+/// `/metrics`. Failed refreshes keep exporting the last forecast with a growing
+/// `refresh_failures` gauge.
+pub(crate) async fn run(cli: &Cli) -> Result<()> {
+    let latest = Latest::default();
+    let listener = match cli.metrics_listen {
+        Some(addr) => Some(
+            TcpListener::bind(addr)
+                .await
+                .with_context(|| format!("Could not listen on {addr}"))?,
+        ),
+        None => None,
+    };
+    let server = async {
+        match listener {
+            Some(listener) => http::serve(listener, metrics_handler(Arc::clone(&latest))).await,
+            None => std::future::pending().await,
+        }
+    };
+
+    let refresh = watch_loop(cli, |watcher, settings| {
+        if let Some((bundle, refresh_meta)) = watcher.latest() {
+            let body = openmetrics::render(bundle, refresh_meta, &settings.alert_rules);
+            if let Some(path) = &cli.metrics_file
+                && let Err(err) = write_textfile(path, &body)
+            {
+                eprintln!("terminal-weather: {err:#}");
+            }
+            if let Ok(mut slot) = latest.write() {
+                *slot = Some(body);
+            }
+        }
+        Ok(())
+    });
+
+    tokio::select! {
+        () = server => unreachable!("http::serve keeps accepting"),
+        result = refresh => result,
+    }
+}
+
+fn metrics_handler(latest: Latest) -> impl Fn(&str) -> Response + Send + Sync + 'static {
+    move |path| match path {
+        "/metrics" => latest
+            .read()
+            .ok()
+            .and_then(|body| body.clone())
+            .map_or_else(
+                || Response::unavailable("no forecast fetched yet"),
+                |body| Response::ok(openmetrics::CONTENT_TYPE, body),
+            ),
+        _ => Response::not_found(),
+    }
+}
+
+/// Writes via a temporary file and rename so the textfile collector never reads
+/// a partial file.
+fn write_textfile(path: &Path, body: &str) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    std::fs::write(&tmp, body)
+        .with_context(|| format!("Could not write metrics to {}", Path::new(&tmp).display()))?;
+    std::fs::rename(&tmp, path).with_context(|| format!("Could not replace {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_textfile_replaces_existing_file() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("weather.prom");
+        std::fs::write(&path, "old").expect("seed");
+
+        write_textfile(&path, "new\n# EOF\n").expect("write");
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("read"),
+            "new\n# EOF\n"
+        );
+        assert!(!dir.path().join("weather.prom.tmp").exists());
+    }
+
+    #[test]
+    fn handler_serves_latest_body_or_503() {
+        let latest = Latest::default();
+        let handler = metrics_handler(Arc::clone(&latest));
+        assert_eq!(handler("/metrics").status, 503);
+        assert_eq!(handler("/").status, 404);
+
+        *latest.write().expect("lock") = Some("# EOF\n".to_string());
+        let response = handler("/metrics");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.content_type, openmetrics::CONTENT_TYPE);
+    }
+}
@@ -0,0 +1,251 @@
+#![allow(clippy::missing_errors_doc)]
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+
+use crate::app::{
+    forecast_store::{ForecastStore, rebase_to_now},
+    offline,
+    settings::{RuntimeSettings, load_runtime_settings},
+};
+use crate::cli::{Cli, FormatArg, SeriesArg};
+use crate::data::geocode::GeocodeClient;
+use crate::data::provider::{ForecastProvider, WeatherProvider};
+use crate::domain::weather::{ForecastBundle, RefreshMetadata};
+use crate::export::template::{Template, TemplateContext};
+use crate::export::{csv, ical, json, openmetrics, statusbar};
+use crate::resilience::freshness::FreshnessState;
+
+pub(crate) mod watch;
+
+pub(crate) async fn run(cli: &Cli) -> Result<()> {
+    if cli.watch {
+        return watch::run(cli).await;
+    }
+    let (settings, settings_path) = load_runtime_settings(cli, !cfg!(test));
+    let store = settings_path
+        .as_deref()
+        .and_then(ForecastStore::beside_settings);
+    let bundle = load_bundle(cli, &settings, store.as_ref()).await?;
+    let refresh_meta = one_shot_refresh_meta(&bundle, cli.offline);
+    print!(
+        "{}",
+        render_snapshot(cli, &bundle, &refresh_meta, &settings, true)?
+    );
+    Ok(())
+}
+
+pub(crate) async fn load_bundle(
+    cli: &Cli,
+    settings: &RuntimeSettings,
+    store: Option<&ForecastStore>,
+) -> Result<ForecastBundle> {
+    if cli.offline {
+        load_offline_bundle(cli, settings, store)
+    } else {
+        fetch_bundle(cli, settings, store).await
+    }
+}
+
+/// Renders `bundle` in the selected `--format`, in the units from `settings`
+/// and with its `alert_rules` raising alerts next to the built-in ones. `first`
+/// is false for the later updates of `--watch`, which must not repeat stream
+/// headers.
+pub(crate) fn render_snapshot(
+    cli: &Cli,
+    bundle: &ForecastBundle,
+    refresh_meta: &RefreshMetadata,
+    settings: &RuntimeSettings,
+    first: bool,
+) -> Result<String> {
+    let units = settings.units;
+    let unit_symbol = temperature_symbol(units);
+    let alert_rules = settings.alert_rules.as_slice();
+    let ctx = TemplateContext {
+        bundle,
+        units,
+        measures: settings.measure_units(),
+        icon_mode: one_shot_icon_mode(cli),
+        refresh_meta,
+        alert_rules,
+    };
+    Ok(match &cli.format {
+        FormatArg::Text => {
+            let display_name = bundle.location.display_name();
+            format!(
+                "{}{}{}",
+                freshness_notice(refresh_meta),
+                one_shot_current_text(bundle, &display_name, units, unit_symbol, ctx.measures),
+                one_shot_daily_text(bundle, units, ctx.measures, ctx.icon_mode)
+            )
+        }
+        FormatArg::Json => format!(
+            "{}\n",
+            json::render(bundle, units, ctx.measures, refresh_meta, alert_rules)?
+        ),
+        FormatArg::Csv => {
+            let series = match cli.series {
+                SeriesArg::Hourly => csv::Series::Hourly,
+                SeriesArg::Daily => csv::Series::Daily,
+            };
+            csv::render(bundle, units, ctx.measures, series)
+        }
+        FormatArg::Template(source) => format!("{}\n", Template::parse(source)?.render(&ctx)),
+        FormatArg::Ics => ical::render(bundle, units, ctx.measures, ctx.icon_mode, alert_rules),
+        FormatArg::OpenMetrics => openmetrics::render(bundle, refresh_meta, alert_rules),
+        FormatArg::Waybar => format!("{}\n", statusbar::waybar_line(&ctx)?),
+        FormatArg::I3bar => {
+            let line = statusbar::i3bar_line(&ctx, first)?;
+            if first {
+                format!("{}\n{line}\n", statusbar::I3BAR_HEADER)
+            } else {
+                format!("{line}\n")
+            }
+        }
+    })
+}
+
+/// Refresh metadata for a bundle that was just fetched, or read from the cache
+/// under `--offline`, so freshness reporting matches the interactive app.
+pub(crate) fn one_shot_refresh_meta(bundle: &ForecastBundle, offline: bool) -> RefreshMetadata {
+    RefreshMetadata {
+        last_success: Some(bundle.fetched_at),
+        state: if offline {
+            FreshnessState::Offline
+        } else {
+            FreshnessState::Fresh
+        },
+        ..RefreshMetadata::default()
+    }
+}
+
+async fn fetch_bundle(
+    cli: &Cli,
+    settings: &RuntimeSettings,
+    store: Option<&ForecastStore>,
+) -> Result<ForecastBundle> {
+    let geocoder = GeocodeClient::new()?;
+    let location = resolve_one_shot_location(cli, &geocoder).await?;
+    let provider = ForecastProvider::build(
+        settings.provider,
+        cli.forecast_url.as_deref(),
+        cli.air_quality_url.as_deref(),
+    )?
+    .with_forecast_days(settings.forecast_days);
+    let bundle = provider.fetch(location).await?;
+    if let Some(store) = store {
+        let _ = store.save(&bundle);
+    }
+    Ok(bundle)
+}
+
+fn load_offline_bundle(
+    cli: &Cli,
+    settings: &RuntimeSettings,
+    store: Option<&ForecastStore>,
+) -> Result<ForecastBundle> {
+    let location = offline::resolve_offline_location(cli, settings)
+        .ok_or_else(|| anyhow::anyhow!(offline::missing_location_message(cli)))?;
+    let bundle = offline::load_offline_forecast(store, &location)?;
+    Ok(rebase_to_now(bundle, Utc::now()))
+}
+
+/// A heading line when the forecast is not fresh: read from the cache under
+/// `--offline`, or kept from an earlier refresh by `--watch`.
+pub(crate) fn freshness_notice(refresh_meta: &RefreshMetadata) -> String {
+    let age = refresh_meta.age_label().unwrap_or_else(|| "--".to_string());
+    match refresh_meta.state {
+        FreshnessState::Fresh => String::new(),
+        FreshnessState::Stale => format!("  Stale · last updated {age} ago\n"),
+        FreshnessState::Offline => format!("  Offline · cached forecast from {age} ago\n"),
+    }
+}
+
+pub(crate) const fn temperature_symbol(units: crate::domain::weather::Units) -> &'static str {
+    use crate::domain::weather::Units;
+
+    match units {
+        Units::Celsius => "C",
+        Units::Fahrenheit => "F",
+    }
+}
+
+pub(crate) async fn resolve_one_shot_location(
+    cli: &Cli,
+    geocoder: &crate::data::geocode::GeocodeClient,
+) -> Result<crate::domain::weather::Location> {
+    use crate::domain::weather::{GeocodeResolution, Location};
+
+    if let (Some(lat), Some(lon)) = (cli.lat, cli.lon) {
+        return Ok(match geocoder.reverse_resolve(lat, lon).await {
+            Ok(Some(location)) => location,
+            Ok(None) | Err(_) => Location::from_coords(lat, lon),
+        });
+    }
+
+    let city = cli.default_city();
+    match geocoder.resolve(city, cli.country_code.clone()).await? {
+        GeocodeResolution::Selected(loc) => Ok(loc),
+        GeocodeResolution::NeedsDisambiguation(locs) => {
+            locs.into_iter().next().context("no locations found")
+        }
+        GeocodeResolution::NotFound(name) => anyhow::bail!("City not found: {name}"),
+    }
+}
+
+pub(crate) fn one_shot_icon_mode(cli: &Cli) -> crate::cli::IconMode {
+    use crate::cli::IconMode;
+
+    if cli.ascii_icons {
+        IconMode::Ascii
+    } else if cli.emoji_icons {
+        IconMode::Emoji
+    } else {
+        IconMode::Unicode
+    }
+}
+
+pub(crate) fn one_shot_current_text(
+    bundle: &crate::domain::weather::ForecastBundle,
+    display_name: &str,
+    units: crate::domain::weather::Units,
+    unit_symbol: &str,
+    measures: crate::domain::weather::MeasureUnits,
+) -> String {
+    use crate::domain::weather::{convert_temp, round_temp, weather_label};
+
+    let temp = round_temp(convert_temp(bundle.current.temperature_2m_c, units));
+    let feels = round_temp(convert_temp(bundle.current.apparent_temperature_c, units));
+    let condition = weather_label(bundle.current.weather_code);
+    let wind = measures.wind.round(bundle.current.wind_speed_10m);
+    let gust = measures.wind.round(bundle.current.wind_gusts_10m);
+    let wind_unit = measures.wind.symbol();
+    let humidity = format!("{:.0}", bundle.current.relative_humidity_2m);
+    let pressure = measures.pressure.format(bundle.current.pressure_msl_hpa);
+    let visibility = measures.distance.format(bundle.current.visibility_m);
+
+    format!(
+        "  {display_name}\n  {temp}°{unit_symbol}  {condition}\n  \
+Feels {feels}°{unit_symbol}  Humidity {humidity}%  Wind {wind}/{gust} {wind_unit}\n  \
+Pressure {pressure}  Visibility {visibility}\n\n"
+    )
+}
+
+pub(crate) fn one_shot_daily_text(
+    bundle: &crate::domain::weather::ForecastBundle,
+    units: crate::domain::weather::Units,
+    measures: crate::domain::weather::MeasureUnits,
+    icon_mode: crate::cli::IconMode,
+) -> String {
+    let mut out = format!("  {}-Day Forecast\n", bundle.daily.len());
+    for day in &bundle.daily {
+        out.push_str(&format!(
+            "  {}\n",
+            statusbar::daily_line(day, units, measures.precip, icon_mode)
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,252 @@
+use super::*;
+use crate::cli::IconMode;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn one_shot_cli() -> Cli {
+    let mut cli = crate::test_support::settings_default_test_cli();
+    cli.one_shot = true;
+    cli
+}
+
+#[test]
+fn temperature_symbol_maps_units() {
+    assert_eq!(
+        temperature_symbol(crate::domain::weather::Units::Celsius),
+        "C"
+    );
+    assert_eq!(
+        temperature_symbol(crate::domain::weather::Units::Fahrenheit),
+        "F"
+    );
+}
+
+#[test]
+fn one_shot_icon_mode_has_expected_precedence() {
+    let mut cli = one_shot_cli();
+    assert_eq!(one_shot_icon_mode(&cli), IconMode::Unicode);
+
+    cli.emoji_icons = true;
+    assert_eq!(one_shot_icon_mode(&cli), IconMode::Emoji);
+
+    cli.ascii_icons = true;
+    assert_eq!(one_shot_icon_mode(&cli), IconMode::Ascii);
+}
+
+#[tokio::test]
+async fn resolve_one_shot_location_uses_reverse_geocode_for_coordinates() {
+    let mut cli = one_shot_cli();
+    cli.lat = Some(59.3293);
+    cli.lon = Some(18.0686);
+    let server = MockServer::start().await;
+    let payload = serde_json::json!({
+        "address": {
+            "city": "Stockholm",
+            "state": "Stockholm County",
+            "country": "Sweden"
+        }
+    });
+    Mock::given(method("GET"))
+        .and(path("/v1/reverse"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payload))
+        .mount(&server)
+        .await;
+    let geocoder =
+        crate::data::geocode::GeocodeClient::with_base_url(format!("{}/v1/search", server.uri()))
+            .expect("test");
+    let location = resolve_one_shot_location(&cli, &geocoder)
+        .await
+        .expect("coords resolve");
+    assert_eq!(location.name, "Stockholm");
+}
+
+#[tokio::test]
+async fn resolve_one_shot_location_falls_back_to_raw_coordinates_on_reverse_failure() {
+    let mut cli = one_shot_cli();
+    cli.lat = Some(59.3293);
+    cli.lon = Some(18.0686);
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/reverse"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+    let geocoder =
+        crate::data::geocode::GeocodeClient::with_base_url(format!("{}/v1/search", server.uri()))
+            .expect("test");
+    let location = resolve_one_shot_location(&cli, &geocoder)
+        .await
+        .expect("coords fallback resolve");
+    assert_eq!(location.name, "59.3293, 18.0686");
+}
+
+#[tokio::test]
+async fn resolve_one_shot_location_uses_geocoder_selection() {
+    let server = MockServer::start().await;
+    let payload = serde_json::json!({
+        "results": [{
+            "name": "Stockholm",
+            "latitude": 59.3293,
+            "longitude": 18.0686,
+            "country": "Sweden",
+            "country_code": "SE",
+            "admin1": "Stockholm",
+            "timezone": "Europe/Stockholm",
+            "population": 975_000
+        }]
+    });
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payload))
+        .mount(&server)
+        .await;
+
+    let mut cli = one_shot_cli();
+    cli.city = Some("Stockholm".to_string());
+    let geocoder = crate::data::geocode::GeocodeClient::with_base_url(server.uri()).expect("test");
+    let location = resolve_one_shot_location(&cli, &geocoder)
+        .await
+        .expect("selection resolve");
+    assert_eq!(location.name, "Stockholm");
+}
+
+#[tokio::test]
+async fn resolve_one_shot_location_errors_on_not_found() {
+    let server = MockServer::start().await;
+    let payload = serde_json::json!({ "results": [] });
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payload))
+        .mount(&server)
+        .await;
+
+    let mut cli = one_shot_cli();
+    cli.city = Some("Missing".to_string());
+    let geocoder = crate::data::geocode::GeocodeClient::with_base_url(server.uri()).expect("test");
+    let err = resolve_one_shot_location(&cli, &geocoder)
+        .await
+        .expect_err("not found should error");
+    assert!(format!("{err}").contains("City not found"));
+}
+
+#[tokio::test]
+async fn resolve_one_shot_location_disambiguation_picks_first() {
+    let server = MockServer::start().await;
+    let payload = serde_json::json!({
+        "results": [
+            {
+                "name": "Springfield",
+                "latitude": 39.78,
+                "longitude": -89.65,
+                "country": "United States",
+                "country_code": "US",
+                "admin1": "Illinois",
+                "timezone": "America/Chicago",
+                "population": 100_000
+            },
+            {
+                "name": "Springfield",
+                "latitude": 37.21,
+                "longitude": -93.29,
+                "country": "United States",
+                "country_code": "US",
+                "admin1": "Missouri",
+                "timezone": "America/Chicago",
+                "population": 105_000
+            }
+        ]
+    });
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payload))
+        .mount(&server)
+        .await;
+
+    let mut cli = one_shot_cli();
+    cli.city = Some("Springfield".to_string());
+    let geocoder = crate::data::geocode::GeocodeClient::with_base_url(server.uri()).expect("test");
+    let location = resolve_one_shot_location(&cli, &geocoder)
+        .await
+        .expect("disambiguation should pick first");
+    assert_eq!(location.name, "Springfield");
+    assert!((location.latitude - 37.21).abs() < 0.01);
+}
+
+#[test]
+fn text_helpers_render_sample_bundle() {
+    let bundle = crate::test_support::sample_bundle();
+    let current = one_shot_current_text(
+        &bundle,
+        "Stockholm, Sweden",
+        crate::domain::weather::Units::Celsius,
+        "C",
+        crate::domain::weather::MeasureUnits::default(),
+    );
+    assert!(current.starts_with("  Stockholm, Sweden\n  7°C  Overcast\n"));
+    assert!(current.contains("Feels 5°C  Humidity 72%"));
+
+    let daily = one_shot_daily_text(
+        &bundle,
+        crate::domain::weather::Units::Celsius,
+        crate::domain::weather::MeasureUnits::default(),
+        IconMode::Unicode,
+    );
+    assert!(daily.starts_with("  1-Day Forecast\n  Thu 12"));
+}
+
+#[test]
+fn one_shot_icon_mode_ascii_takes_precedence() {
+    let mut cli = one_shot_cli();
+    cli.ascii_icons = true;
+    cli.emoji_icons = true;
+    assert_eq!(one_shot_icon_mode(&cli), IconMode::Ascii);
+}
+
+#[test]
+fn load_offline_bundle_reads_store_for_recent_location() {
+    let cli = one_shot_cli();
+    let mut settings = RuntimeSettings::default();
+    let location = crate::test_support::stockholm_location();
+    settings
+        .recent_locations
+        .push(crate::app::settings::RecentLocation::from_location(
+            &location,
+        ));
+    let dir = tempfile::tempdir().expect("temp dir");
+    let store = ForecastStore::new(dir.path());
+
+    let err = load_offline_bundle(&cli, &settings, Some(&store)).expect_err("empty cache");
+    assert!(err.to_string().contains("offline mode"));
+
+    store
+        .save(&crate::test_support::sample_bundle())
+        .expect("save bundle");
+    let bundle = load_offline_bundle(&cli, &settings, Some(&store)).expect("cached bundle");
+    assert_eq!(bundle.location.name, "Stockholm");
+    assert!(freshness_notice(&one_shot_refresh_meta(&bundle, true)).starts_with("  Offline"));
+    assert!(freshness_notice(&one_shot_refresh_meta(&bundle, false)).is_empty());
+}
+
+#[test]
+fn render_snapshot_uses_saved_wind_unit() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("settings.json");
+    let saved = RuntimeSettings {
+        wind_unit: crate::domain::weather::WindUnit::Kmh,
+        ..RuntimeSettings::default()
+    };
+    crate::app::settings::save_runtime_settings(&path, &saved).expect("save settings");
+    let settings = crate::app::settings::read_saved_settings(&path).expect("read settings");
+
+    let text = render_snapshot(
+        &one_shot_cli(),
+        &crate::test_support::sample_bundle(),
+        &RefreshMetadata::default(),
+        &settings,
+        true,
+    )
+    .expect("render");
+    assert!(text.contains(" km/h\n"), "{text}");
+}
@@ -0,0 +1,180 @@
+use std::io::{IsTerminal, Write};
+use std::sync::{Arc, atomic::AtomicU64};
+
+use anyhow::Result;
+use crossterm::{
+    cursor::MoveToPreviousLine,
+    queue,
+    terminal::{Clear, ClearType},
+};
+use ratatui::text::Line;
+use tokio::sync::mpsc;
+
+use super::{load_bundle, one_shot_refresh_meta, render_snapshot};
+use crate::app::{
+    events::{AppEvent, schedule_retry, start_refresh_task},
+    forecast_store::ForecastStore,
+    settings::{RuntimeSettings, load_runtime_settings},
+};
+use crate::cli::{Cli, FormatArg};
+use crate::domain::weather::{ForecastBundle, RefreshMetadata, evaluate_freshness};
+use crate::resilience::backoff::Backoff;
+
+/// Same retry schedule as the interactive app.
+const RETRY_BASE_SECS: u64 = 10;
+const RETRY_MAX_SECS: u64 = 300;
+
+/// Reprints the snapshot after every refresh. Text on a terminal is redrawn in
+/// place; every other format appends, so status bars and pipes see a stream.
+pub(super) async fn run(cli: &Cli) -> Result<()> {
+    let in_place = cli.format == FormatArg::Text && std::io::stdout().is_terminal();
+    let mut printed_rows = None;
+    watch_loop(cli, |watcher, settings| {
+        let Some((bundle, refresh_meta)) = watcher.latest() else {
+            return Ok(());
+        };
+        let text = render_snapshot(cli, bundle, refresh_meta, settings, printed_rows.is_none())?;
+        let mut stdout = std::io::stdout().lock();
+        if in_place {
+            if let Some(rows) = printed_rows.filter(|rows| *rows > 0) {
+                queue!(
+                    stdout,
+                    MoveToPreviousLine(rows),
+                    Clear(ClearType::FromCursorDown)
+                )?;
+            }
+            printed_rows = Some(terminal_rows(&text));
+        } else {
+            printed_rows = Some(0);
+        }
+        stdout.write_all(text.as_bytes())?;
+        stdout.flush()?;
+        Ok(())
+    })
+    .await
+}
+
+/// Refreshes on `start_refresh_task`'s jittered interval, every saved
+/// `refresh_interval_secs`, and retries failures with the app's `Backoff`,
+/// calling `on_update` with the latest forecast and the saved settings after
+/// every attempt. Failed refreshes keep the last forecast, with freshness
+/// degrading to stale and then offline.
+pub(crate) async fn watch_loop(
+    cli: &Cli,
+    mut on_update: impl FnMut(&Watcher, &RuntimeSettings) -> Result<()>,
+) -> Result<()> {
+    let (settings, settings_path) = load_runtime_settings(cli, !cfg!(test));
+    let store = settings_path
+        .as_deref()
+        .and_then(ForecastStore::beside_settings);
+    let (tx, mut rx) = mpsc::channel::<AppEvent>(8);
+    start_refresh_task(
+        tx.clone(),
+        Arc::new(AtomicU64::new(settings.refresh_interval_secs)),
+    );
+    let mut backoff = Backoff::new(RETRY_BASE_SECS, RETRY_MAX_SECS);
+    let mut watcher = Watcher::default();
+    loop {
+        if watcher.refresh(cli, &settings, store.as_ref()).await {
+            backoff.reset();
+        } else {
+            let delay = backoff.next_delay();
+            watcher.refresh_meta.schedule_retry_in(delay);
+            schedule_retry(tx.clone(), delay);
+        }
+        on_update(&watcher, &settings)?;
+        if rx.recv().await.is_none() {
+            return Ok(());
+        }
+    }
+}
+
+/// Rows `text` occupies once long lines wrap at the terminal width.
+fn terminal_rows(text: &str) -> u16 {
+    let columns = crossterm::terminal::size().map_or(usize::MAX, |(cols, _)| usize::from(cols));
+    let rows: usize = text
+        .lines()
+        .map(|line| Line::raw(line).width().max(1).div_ceil(columns.max(1)))
+        .sum();
+    u16::try_from(rows).unwrap_or(u16::MAX)
+}
+
+/// Keeps the last good forecast across refreshes and tracks how stale it is.
+#[derive(Default)]
+pub(crate) struct Watcher {
+    bundle: Option<ForecastBundle>,
+    refresh_meta: RefreshMetadata,
+}
+
+impl Watcher {
+    /// Returns whether the refresh succeeded.
+    async fn refresh(
+        &mut self,
+        cli: &Cli,
+        settings: &RuntimeSettings,
+        store: Option<&ForecastStore>,
+    ) -> bool {
+        match load_bundle(cli, settings, store).await {
+            Ok(bundle) => {
+                self.record_success(bundle, cli.offline);
+                true
+            }
+            Err(err) => {
+                if self.bundle.is_none() {
+                    eprintln!("terminal-weather: {err:#}");
+                }
+                self.record_failure();
+                false
+            }
+        }
+    }
+
+    fn record_success(&mut self, bundle: ForecastBundle, offline: bool) {
+        self.refresh_meta = one_shot_refresh_meta(&bundle, offline);
+        self.bundle = Some(bundle);
+    }
+
+    fn record_failure(&mut self) {
+        self.refresh_meta.mark_failure();
+        self.refresh_meta.state = evaluate_freshness(
+            self.refresh_meta.last_success,
+            self.refresh_meta.consecutive_failures,
+        );
+    }
+
+    pub(crate) fn latest(&self) -> Option<(&ForecastBundle, &RefreshMetadata)> {
+        self.bundle
+            .as_ref()
+            .map(|bundle| (bundle, &self.refresh_meta))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resilience::freshness::FreshnessState;
+    use crate::test_support::sample_bundle;
+
+    #[test]
+    fn failures_keep_last_bundle_and_degrade_freshness() {
+        let mut watcher = Watcher::default();
+        watcher.record_failure();
+        assert!(watcher.bundle.is_none());
+
+        watcher.record_success(sample_bundle(), false);
+        assert_eq!(watcher.refresh_meta.state, FreshnessState::Fresh);
+
+        watcher.record_failure();
+        assert!(watcher.bundle.is_some());
+        assert_eq!(watcher.refresh_meta.state, FreshnessState::Stale);
+        for _ in 0..2 {
+            watcher.record_failure();
+        }
+        assert_eq!(watcher.refresh_meta.state, FreshnessState::Offline);
+    }
+
+    #[test]
+    fn terminal_rows_counts_lines() {
+        assert_eq!(terminal_rows("  Oslo\n\n  7°C\n"), 3);
+    }
+}
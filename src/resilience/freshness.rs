@@ -1,6 +1,8 @@
 use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum FreshnessState {
     Fresh,
     Stale,
@@ -0,0 +1,209 @@
+//! `--serve`: runs the app's refresh loop headless and shares the latest
+//! forecast over HTTP as JSON endpoints and a small HTML page.
+
+use std::sync::{Arc, RwLock};
+
+use anyhow::{Context, Result};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+use crate::app::events::AppEvent;
+use crate::app::state::{AppMode, AppState};
+use crate::cli::{Cli, IconMode};
+use crate::domain::alerts::AlertRule;
+use crate::domain::weather::{ForecastBundle, MeasureUnits, RefreshMetadata, Units};
+use crate::export::{ical, json};
+use crate::http::{self, Response};
+
+mod html;
+
+const JSON_CONTENT_TYPE: &str = "application/json";
+const HTML_CONTENT_TYPE: &str = "text/html; charset=utf-8";
+const SECTIONS: [&str; 4] = ["current", "hourly", "daily", "alerts"];
+
+/// What the HTTP handlers see of the app state.
+#[derive(Debug, Clone)]
+struct Snapshot {
+    bundle: Option<ForecastBundle>,
+    refresh_meta: RefreshMetadata,
+    units: Units,
+    measures: MeasureUnits,
+    icon_mode: IconMode,
+    alert_rules: Vec<AlertRule>,
+    last_error: Option<String>,
+}
+
+type Shared = Arc<RwLock<Option<Snapshot>>>;
+
+pub(crate) async fn run(cli: &Cli) -> Result<()> {
+    let addr = cli.serve.context("--serve needs an address")?;
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Could not listen on {addr}"))?;
+    let shared = Shared::default();
+    eprintln!("terminal-weather: serving on http://{addr}/");
+
+    tokio::select! {
+        () = http::serve(listener, routes(Arc::clone(&shared))) => {
+            unreachable!("http::serve keeps accepting")
+        }
+        result = refresh_loop(cli, &shared) => result,
+    }
+}
+
+async fn refresh_loop(cli: &Cli, shared: &Shared) -> Result<()> {
+    let (tx, mut rx) = mpsc::channel::<AppEvent>(256);
+    let mut app = AppState::new(cli);
+    app.handle_headless_bootstrap(&tx, cli).await?;
+    publish(&app, shared);
+    while let Some(event) = rx.recv().await {
+        app.handle_event(event, &tx, cli).await?;
+        if app.mode == AppMode::SelectingLocation
+            && let Some(first) = app.pending_locations.first().cloned()
+        {
+            app.switch_to_location(&tx, first);
+        }
+        publish(&app, shared);
+    }
+    Ok(())
+}
+
+fn publish(app: &AppState, shared: &Shared) {
+    let mut refresh_meta = app.refresh_meta.clone();
+    refresh_meta.state = app.current_freshness();
+    let snapshot = Snapshot {
+        bundle: app.weather.clone(),
+        refresh_meta,
+        units: app.units,
+        measures: app.settings.measure_units(),
+        icon_mode: app.settings.icon_mode,
+        alert_rules: app.settings.alert_rules.clone(),
+        last_error: app.last_error.clone(),
+    };
+    if let Ok(mut slot) = shared.write() {
+        *slot = Some(snapshot);
+    }
+}
+
+fn routes(shared: Shared) -> impl Fn(&str) -> Response + Send + Sync + 'static {
+    move |path| {
+        let snapshot = shared.read().ok().and_then(|slot| slot.clone());
+        let Some(snapshot) = snapshot else {
+            return Response::unavailable("starting up");
+        };
+        match path {
+            "/" | "/index.html" => Response::ok(HTML_CONTENT_TYPE, html::render(&snapshot)),
+            "/forecast.ics" => snapshot.bundle.as_ref().map_or_else(
+                || Response::unavailable("no forecast fetched yet"),
+                |bundle| {
+                    Response::ok(
+                        ical::CONTENT_TYPE,
+                        ical::render(
+                            bundle,
+                            snapshot.units,
+                            snapshot.measures,
+                            snapshot.icon_mode,
+                            &snapshot.alert_rules,
+                        ),
+                    )
+                },
+            ),
+            _ => path
+                .strip_prefix('/')
+                .filter(|section| SECTIONS.contains(section))
+                .map_or_else(Response::not_found, |section| {
+                    section_response(&snapshot, section)
+                }),
+        }
+    }
+}
+
+/// A JSON document holding the `--format json` metadata plus one section.
+fn section_response(snapshot: &Snapshot, section: &str) -> Response {
+    let Some(bundle) = &snapshot.bundle else {
+        return Response::unavailable(
+            snapshot
+                .last_error
+                .as_deref()
+                .unwrap_or("no forecast fetched yet"),
+        );
+    };
+    match json::render_section(
+        bundle,
+        snapshot.units,
+        snapshot.measures,
+        &snapshot.refresh_meta,
+        &snapshot.alert_rules,
+        section,
+    ) {
+        Ok(body) => Response::ok(JSON_CONTENT_TYPE, body.to_string()),
+        Err(_) => Response::unavailable("could not encode forecast"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_bundle;
+
+    fn shared_with(bundle: Option<ForecastBundle>) -> Shared {
+        let mut refresh_meta = RefreshMetadata::default();
+        refresh_meta.mark_success();
+        Arc::new(RwLock::new(Some(Snapshot {
+            bundle,
+            refresh_meta,
+            units: Units::Celsius,
+            measures: MeasureUnits::default(),
+            icon_mode: IconMode::Unicode,
+            alert_rules: Vec::new(),
+            last_error: Some("Network down".to_string()),
+        })))
+    }
+
+    #[test]
+    fn json_sections_carry_metadata_and_one_section() {
+        let handler = routes(shared_with(Some(sample_bundle())));
+        for section in SECTIONS {
+            let response = handler(&format!("/{section}"));
+            assert_eq!(response.status, 200);
+            let value: serde_json::Value =
+                serde_json::from_str(&response.body).expect("valid json");
+            assert!(value.get(section).is_some(), "missing {section}");
+            assert_eq!(value["freshness"], "fresh");
+            assert_eq!(value["location"]["name"], "Stockholm");
+            assert!(value.get("nowcast").is_none());
+        }
+        assert_eq!(handler("/weather").status, 404);
+        assert_eq!(handler("/").content_type, HTML_CONTENT_TYPE);
+        assert!(handler("/forecast.ics").body.starts_with("BEGIN:VCALENDAR"));
+    }
+
+    #[tokio::test]
+    async fn silent_client_does_not_block_other_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        tokio::spawn(http::serve(
+            listener,
+            routes(shared_with(Some(sample_bundle()))),
+        ));
+
+        let _silent = tokio::net::TcpStream::connect(addr).await.expect("connect");
+        let status = reqwest::get(format!("http://{addr}/current"))
+            .await
+            .expect("request")
+            .status();
+        assert_eq!(status, 200);
+    }
+
+    #[test]
+    fn reports_unavailable_until_first_forecast() {
+        let handler = routes(Shared::default());
+        assert_eq!(handler("/current").status, 503);
+
+        let handler = routes(shared_with(None));
+        let response = handler("/current");
+        assert_eq!(response.status, 503);
+        assert!(response.body.contains("Network down"));
+        assert_eq!(handler("/").status, 200);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::Write as _;
+
+use super::Snapshot;
+use crate::domain::alerts::scan_alerts;
+use crate::domain::weather::{ForecastBundle, Units, convert_temp, round_temp, weather_label};
+use crate::resilience::freshness::FreshnessState;
+
+const HOURS_SHOWN: usize = 12;
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:44rem;margin:2rem auto;\
+padding:0 1rem;color:#222}h1{margin-bottom:0}.temp{font-size:3rem;margin:.5rem 0}\
+.badge{padding:.1rem .5rem;border-radius:.5rem;background:#eee}.stale{background:#fde68a}\
+.offline{background:#fca5a5}table{border-collapse:collapse;width:100%}\
+td,th{padding:.25rem .5rem;text-align:left;border-bottom:1px solid #ddd}";
+
+/// A self-refreshing page with current conditions, alerts, the next hours and
+/// the daily outlook.
+pub(super) fn render(snapshot: &Snapshot) -> String {
+    let mut page = String::from("<!doctype html><html><head><meta charset=\"utf-8\">");
+    page.push_str("<meta http-equiv=\"refresh\" content=\"60\">");
+    page.push_str("<meta name=\"viewport\" content=\"width=device-width,initial-scale=1\">");
+    let _ = write!(
+        page,
+        "<title>terminal-weather</title><style>{STYLE}</style></head><body>"
+    );
+    match &snapshot.bundle {
+        Some(bundle) => push_forecast(&mut page, bundle, snapshot),
+        None => {
+            let message = snapshot
+                .last_error
+                .as_deref()
+                .unwrap_or("Fetching the first forecast…");
+            let _ = write!(page, "<h1>terminal-weather</h1><p>{}</p>", escape(message));
+        }
+    }
+    page.push_str("</body></html>");
+    page
+}
+
+fn push_forecast(page: &mut String, bundle: &ForecastBundle, snapshot: &Snapshot) {
+    let units = snapshot.units;
+    let wind = snapshot.measures.wind;
+    let current = &bundle.current;
+    let (badge_class, badge) = match snapshot.refresh_meta.state {
+        FreshnessState::Fresh => ("fresh", "Fresh"),
+        FreshnessState::Stale => ("stale", "Stale"),
+        FreshnessState::Offline => ("offline", "Offline"),
+    };
+    let age = snapshot
+        .refresh_meta
+        .age_label()
+        .unwrap_or_else(|| "--".to_string());
+    let _ = write!(
+        page,
+        "<h1>{}</h1><p><span class=\"badge {badge_class}\">{badge}</span> updated {age} ago</p>\
+<p class=\"temp\">{}</p><p>{} · feels {} · wind {} {} (gusts {}) · humidity {:.0}%</p>",
+        escape(&bundle.location.display_name()),
+        temperature(Some(current.temperature_2m_c), units),
+        weather_label(current.weather_code),
+        temperature(Some(current.apparent_temperature_c), units),
+        wind.round(current.wind_speed_10m),
+        wind.symbol(),
+        wind.round(current.wind_gusts_10m),
+        current.relative_humidity_2m,
+    );
+
+    let alerts = scan_alerts(bundle, units, snapshot.measures, &snapshot.alert_rules);
+    if !alerts.is_empty() {
+        page.push_str("<h2>Alerts</h2><ul>");
+        for alert in alerts {
+            let _ = write!(
+                page,
+                "<li>{} {} <small>{}</small></li>",
+                alert.icon,
+                escape(&alert.message),
+                alert.timing_label()
+            );
+        }
+        page.push_str("</ul>");
+    }
+
+    page.push_str(
+        "<h2>Next hours</h2><table><tr><th>Time</th><th>Temp</th><th>Sky</th><th>Rain</th></tr>",
+    );
+    for hour in bundle.hourly.iter().take(HOURS_SHOWN) {
+        let _ = write!(
+            page,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            hour.time.format("%H:%M"),
+            temperature(hour.temperature_2m_c, units),
+            hour.weather_code.map_or("--", weather_label),
+            hour.precipitation_probability
+                .map_or_else(|| "--".to_string(), |p| format!("{p:.0}%")),
+        );
+    }
+    page.push_str("</table>");
+
+    page.push_str(
+        "<h2>Daily</h2><table><tr><th>Day</th><th>Low / High</th><th>Sky</th><th>Precip</th></tr>",
+    );
+    for day in &bundle.daily {
+        let _ = write!(
+            page,
+            "<tr><td>{}</td><td>{} / {}</td><td>{}</td><td>{}</td></tr>",
+            day.date.format("%a %d"),
+            temperature(day.temperature_min_c, units),
+            temperature(day.temperature_max_c, units),
+            day.weather_code.map_or("--", weather_label),
+            day.precipitation_sum_mm
+                .map_or_else(|| "--".to_string(), |p| snapshot.measures.precip.format(p)),
+        );
+    }
+    page.push_str("</table>");
+}
+
+fn temperature(celsius: Option<f32>, units: Units) -> String {
+    celsius.map_or_else(
+        || "--".to_string(),
+        |c| format!("{}°", round_temp(convert_temp(c, units))),
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::weather::{MeasureUnits, RefreshMetadata};
+    use crate::test_support::sample_bundle;
+
+    #[test]
+    fn page_shows_location_tables_and_escapes_names() {
+        let mut bundle = sample_bundle();
+        bundle.location.name = "<Town>".to_string();
+        let page = render(&Snapshot {
+            bundle: Some(bundle),
+            refresh_meta: RefreshMetadata::default(),
+            units: Units::Celsius,
+            measures: MeasureUnits::default(),
+            icon_mode: crate::cli::IconMode::Unicode,
+            alert_rules: Vec::new(),
+            last_error: None,
+        });
+
+        assert!(page.contains("&lt;Town&gt;"));
+        assert!(!page.contains("<Town>"));
+        assert!(page.contains("<h2>Next hours</h2>"));
+        assert!(page.contains("7°"));
+    }
+}
@@ -1,7 +1,7 @@
 use chrono::{NaiveDate, NaiveDateTime, Utc};
 
 use crate::{
-    cli::{Cli, ColorArg, HeroVisualArg, ThemeArg, UnitsArg},
+    cli::{Cli, ColorArg, FormatArg, HeroVisualArg, SeriesArg, ThemeArg, UnitsArg},
     domain::weather::{CurrentConditions, DailyForecast, ForecastBundle, HourlyForecast, Location},
     ui::animation::{MotionMode, SeededMotion, UiMotionContext},
 };
@@ -19,6 +19,12 @@ fn base_test_cli() -> Cli {
         reduced_motion: false,
         motion: None,
         no_flash: true,
+        no_mouse: false,
+        forecast_days: None,
+        wind_unit: None,
+        pressure_unit: None,
+        precip_unit: None,
+        distance_unit: None,
         ascii_icons: false,
         emoji_icons: false,
         nerd_font: false,
@@ -30,11 +36,25 @@ fn base_test_cli() -> Cli {
         country_code: None,
         lat: None,
         lon: None,
+        location: None,
+        provider: None,
         forecast_url: None,
         air_quality_url: None,
         refresh_interval: 600,
         demo: false,
+        offline: false,
         one_shot: false,
+        format: FormatArg::Text,
+        series: SeriesArg::Hourly,
+        watch: false,
+        metrics_file: None,
+        metrics_listen: None,
+        serve: None,
+        dashboard: false,
+        compare: None,
+        inline: false,
+        inline_seconds: 5,
+        command: None,
     }
 }
 
@@ -137,6 +157,7 @@ pub(crate) fn sample_bundle() -> ForecastBundle {
         daily: vec![sample_daily()],
         air_quality: None,
         fetched_at: Utc::now(),
+        utc_offset_seconds: 0,
     }
 }
 
@@ -0,0 +1,257 @@
+//! Compact `--inline` view: a few-line hero and a next-hours strip drawn in an
+//! inline viewport below the shell prompt.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+
+use crate::{
+    app::state::{AppMode, AppState},
+    domain::weather::{
+        ForecastBundle, HourlyForecast, Units, convert_temp, round_temp, weather_code_to_category,
+        weather_icon, weather_label_for_time,
+    },
+    resilience::freshness::FreshnessState,
+    ui::{
+        narrative::build_narrative,
+        spinner,
+        theme::{Theme, icon_color, temp_color},
+        widgets::hero::{hero_context, render_hero_background},
+    },
+};
+
+/// Rows the inline viewport reserves: borders plus four lines of content.
+pub const INLINE_HEIGHT: u16 = 6;
+
+const HERO_WIDTH: u16 = 34;
+const HOUR_COLUMN_WIDTH: u16 = 7;
+const MAX_HOURS: usize = 12;
+
+pub fn render(frame: &mut Frame, state: &AppState) {
+    let area = frame.area();
+    let (_, _, theme) = hero_context(state);
+    render_hero_background(frame, area, state, theme);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title_line(state, theme))
+        .title_top(status_line(state, theme).right_aligned())
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(bundle) = &state.weather else {
+        frame.render_widget(
+            Paragraph::new(placeholder_text(state)).style(Style::default().fg(theme.muted_text)),
+            inner,
+        );
+        return;
+    };
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(HERO_WIDTH), Constraint::Min(0)])
+        .split(inner);
+    frame.render_widget(Paragraph::new(hero_lines(state, bundle, theme)), columns[0]);
+    render_hours_strip(frame, columns[1], state, bundle, theme);
+}
+
+fn title_line(state: &AppState, theme: Theme) -> Line<'static> {
+    let name = state
+        .weather
+        .as_ref()
+        .map(|bundle| bundle.location.display_name())
+        .or_else(|| {
+            state
+                .selected_location
+                .as_ref()
+                .map(crate::domain::weather::Location::display_name)
+        })
+        .unwrap_or_else(|| "terminal-weather".to_string());
+    Line::from(Span::styled(
+        format!(" {name} "),
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn status_line(state: &AppState, theme: Theme) -> Line<'static> {
+    let (text, color) = match state.refresh_meta.state {
+        FreshnessState::Offline => ("offline".to_string(), theme.danger),
+        FreshnessState::Stale => ("stale".to_string(), theme.warning),
+        FreshnessState::Fresh if state.fetch_in_flight => {
+            (format!("{} syncing", spinner(state.frame_tick)), theme.info)
+        }
+        FreshnessState::Fresh => (String::new(), theme.muted_text),
+    };
+    if text.is_empty() {
+        return Line::default();
+    }
+    Line::from(Span::styled(
+        format!(" {text} "),
+        Style::default().fg(color),
+    ))
+}
+
+fn placeholder_text(state: &AppState) -> String {
+    match (&state.mode, &state.last_error) {
+        (AppMode::Error, Some(err)) => format!(" {err}"),
+        _ => format!(" {}", state.loading_message),
+    }
+}
+
+fn hero_lines(state: &AppState, bundle: &ForecastBundle, theme: Theme) -> Vec<Line<'static>> {
+    let units = state.units;
+    let symbol = unit_symbol(units);
+    let current = &bundle.current;
+    let category = weather_code_to_category(current.weather_code);
+    let icon = weather_icon(
+        current.weather_code,
+        state.settings.icon_mode,
+        current.is_day,
+    );
+    let temp = bundle.current_temp(units);
+    let feels = round_temp(convert_temp(current.apparent_temperature_c, units));
+    let high_low = bundle
+        .high_low(units)
+        .map_or_else(String::new, |(high, low)| format!("  H {high}° L {low}°"));
+
+    vec![
+        Line::from(vec![
+            Span::styled(
+                format!(" {icon} "),
+                Style::default().fg(icon_color(&theme, category)),
+            ),
+            Span::styled(
+                format!("{temp}°{symbol}"),
+                Style::default()
+                    .fg(temp_color(&theme, current.temperature_2m_c))
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "  {}",
+                    weather_label_for_time(current.weather_code, current.is_day)
+                ),
+                Style::default().fg(theme.text),
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!(" Feels {feels}°{high_low}"),
+            Style::default().fg(theme.text),
+        )),
+        Line::from(Span::styled(
+            format!(
+                " Wind {}  Humidity {:.0}%",
+                state.settings.wind_unit.format(current.wind_speed_10m),
+                current.relative_humidity_2m
+            ),
+            Style::default().fg(theme.text),
+        )),
+        Line::from(Span::styled(
+            format!(" {}", build_narrative(state, bundle).now_action),
+            Style::default().fg(theme.muted_text),
+        )),
+    ]
+}
+
+fn render_hours_strip(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    bundle: &ForecastBundle,
+    theme: Theme,
+) {
+    let hours = strip_hours(bundle, area.width);
+    if hours.is_empty() {
+        return;
+    }
+    let rows = vec![
+        Row::new(hours.iter().map(|hour| {
+            Cell::from(hour.time.format("%H:%M").to_string())
+                .style(Style::default().fg(theme.muted_text))
+        })),
+        Row::new(hours.iter().map(|hour| {
+            let code = hour.weather_code.unwrap_or(bundle.current.weather_code);
+            let is_day = hour.is_day.unwrap_or(bundle.current.is_day);
+            Cell::from(weather_icon(code, state.settings.icon_mode, is_day))
+                .style(Style::default().fg(icon_color(&theme, weather_code_to_category(code))))
+        })),
+        Row::new(hours.iter().map(|hour| {
+            hour.temperature_2m_c.map_or_else(
+                || Cell::from("--"),
+                |value| {
+                    Cell::from(format!("{}°", round_temp(convert_temp(value, state.units))))
+                        .style(Style::default().fg(temp_color(&theme, value)))
+                },
+            )
+        })),
+        Row::new(hours.iter().map(|hour| {
+            Cell::from(
+                hour.precipitation_probability
+                    .map_or_else(|| "--".to_string(), |value| format!("{value:.0}%")),
+            )
+            .style(Style::default().fg(theme.info))
+        })),
+    ];
+    let widths = vec![Constraint::Length(HOUR_COLUMN_WIDTH); hours.len()];
+    frame.render_widget(Table::new(rows, widths).column_spacing(0), area);
+}
+
+/// As many upcoming hours as fit in `width`, capped at a half day.
+fn strip_hours(bundle: &ForecastBundle, width: u16) -> Vec<&HourlyForecast> {
+    let fits = usize::from(width / HOUR_COLUMN_WIDTH).min(MAX_HOURS);
+    bundle.hourly.iter().take(fits).collect()
+}
+
+const fn unit_symbol(units: Units) -> &'static str {
+    match units {
+        Units::Celsius => "C",
+        Units::Fahrenheit => "F",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn rendered_text(state: &AppState, width: u16) -> String {
+        let mut terminal =
+            Terminal::new(TestBackend::new(width, INLINE_HEIGHT)).expect("test terminal");
+        terminal.draw(|frame| render(frame, state)).expect("draw");
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(usize::from(width))
+            .map(|row| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn renders_hero_and_hours_strip() {
+        let mut state = AppState::new(&crate::test_support::state_test_cli());
+        state.weather = Some(crate::test_support::sample_bundle());
+        state.mode = AppMode::Ready;
+        let text = rendered_text(&state, 80);
+        assert!(text.contains("Stockholm"));
+        assert!(text.contains("7°C"));
+        assert!(text.contains("10:00"));
+        assert!(text.contains("35%"));
+    }
+
+    #[test]
+    fn renders_loading_message_without_forecast() {
+        let mut state = AppState::new(&crate::test_support::state_test_cli());
+        state.loading_message = "Fetching weather...".to_string();
+        assert!(rendered_text(&state, 60).contains("Fetching weather..."));
+    }
+}
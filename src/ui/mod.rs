@@ -2,6 +2,7 @@
 
 pub mod animation;
 mod footer;
+pub mod inline;
 pub mod layout;
 pub mod narrative;
 pub mod particles;
@@ -67,17 +68,104 @@ pub fn render(frame: &mut Frame, state: &AppState, cli: &Cli) {
         return;
     }
 
-    let content_area = content_area_with_footer(frame, area, state);
-    let alerts = state
-        .weather
-        .as_ref()
-        .map(|bundle| crate::domain::alerts::scan_alerts(bundle, state.units))
-        .unwrap_or_default();
-    render_main_panels(frame, content_area, state, cli, &alerts);
+    let (content_area, footer_area) = split_footer(area, state);
+    if let Some(footer_area) = footer_area {
+        render_bottom_bar(frame, footer_area, state);
+    }
+    if state.dashboard.open {
+        widgets::dashboard::render(frame, content_area, state);
+        render_status_badge(frame, content_area, state);
+        render_modal_overlay(frame, area, state, cli);
+        return;
+    }
+    if state.compare.open {
+        widgets::compare::render(frame, content_area, state);
+        render_status_badge(frame, content_area, state);
+        render_modal_overlay(frame, area, state, cli);
+        return;
+    }
+    let (tabs_area, content_area) = split_tabs(content_area, state);
+    if let Some(tabs_area) = tabs_area {
+        widgets::location_tabs::render(frame, tabs_area, state);
+    }
+    let alerts = current_alerts(state);
+    let panels = main_panel_areas(content_area, state, &alerts);
+    render_main_panels(frame, &panels, state, cli, &alerts);
     render_status_badge(frame, content_area, state);
     render_modal_overlay(frame, area, state, cli);
 }
 
+/// Where the hero, alert row, hourly and daily panels sit on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MainPanelAreas {
+    pub hero: Rect,
+    pub alerts: Option<Rect>,
+    pub hourly: Rect,
+    pub daily: Rect,
+}
+
+/// The main view's panel layout for a terminal of `area`, computed the same
+/// way `render` does, or `None` when something else covers the panels.
+#[must_use]
+pub fn screen_panel_areas(area: Rect, state: &AppState) -> Option<MainPanelAreas> {
+    if area.width < MIN_RENDER_WIDTH
+        || area.height < MIN_RENDER_HEIGHT
+        || state.dashboard.open
+        || state.compare.open
+    {
+        return None;
+    }
+    let (content_area, _) = split_footer(area, state);
+    let (_, content_area) = split_tabs(content_area, state);
+    Some(main_panel_areas(
+        content_area,
+        state,
+        &current_alerts(state),
+    ))
+}
+
+/// The overlay on top of the main view and the area it is drawn in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalOverlay {
+    Selector(Rect),
+    Settings(Rect),
+    CityPicker(Rect),
+    Help(Rect),
+    DayDetail(Rect),
+}
+
+#[must_use]
+pub fn modal_overlay(area: Rect, state: &AppState) -> Option<ModalOverlay> {
+    if state.mode == AppMode::SelectingLocation {
+        Some(ModalOverlay::Selector(centered_rect(70, 60, area)))
+    } else if state.settings_open {
+        Some(ModalOverlay::Settings(centered_rect(68, 74, area)))
+    } else if state.city_picker_open {
+        Some(ModalOverlay::CityPicker(centered_rect(74, 74, area)))
+    } else if state.help_open {
+        Some(ModalOverlay::Help(centered_rect(82, 84, area)))
+    } else if state.day_detail_open {
+        Some(ModalOverlay::DayDetail(centered_rect(80, 86, area)))
+    } else {
+        None
+    }
+}
+
+fn current_alerts(state: &AppState) -> Vec<crate::domain::alerts::WeatherAlert> {
+    state
+        .weather
+        .as_ref()
+        .map(|bundle| {
+            crate::domain::alerts::scan_alerts(
+                bundle,
+                state.units,
+                state.settings.measure_units(),
+                &state.settings.alert_rules,
+            )
+        })
+        .unwrap_or_default()
+}
+
 fn render_small_terminal_hint(
     frame: &mut Frame,
     area: Rect,
@@ -156,29 +244,37 @@ fn small_terminal_tip_line(theme: crate::ui::theme::Theme) -> Line<'static> {
     ])
 }
 
-fn content_area_with_footer(frame: &mut Frame, area: Rect, state: &AppState) -> Rect {
+fn split_footer(area: Rect, state: &AppState) -> (Rect, Option<Rect>) {
     let overlays_open = has_modal_overlay(state);
     let show_footer = !overlays_open && area.height > MIN_RENDER_HEIGHT;
     if !show_footer {
-        return area;
+        return (area, None);
     }
     let sections = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(1), Constraint::Length(1)])
         .split(area);
-    render_bottom_bar(frame, sections[1], state);
-    sections[0]
+    (sections[0], Some(sections[1]))
 }
 
-fn render_main_panels(
-    frame: &mut Frame,
+fn split_tabs(area: Rect, state: &AppState) -> (Option<Rect>, Rect) {
+    if !widgets::location_tabs::is_visible(state) || area.height <= MIN_RENDER_HEIGHT {
+        return (None, area);
+    }
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(area);
+    (Some(sections[0]), sections[1])
+}
+
+fn main_panel_areas(
     content_area: Rect,
     state: &AppState,
-    cli: &Cli,
     alerts: &[crate::domain::alerts::WeatherAlert],
-) {
+) -> MainPanelAreas {
     let constraints = panel_constraints(content_area, state.hourly_view_mode);
-    let alert_height = crate::ui::widgets::alerts::alert_row_height(alerts);
+    let alert_height = crate::ui::widgets::alerts::alert_row_height(alerts, content_area);
 
     if alert_height > 0 {
         let chunks = Layout::default()
@@ -190,32 +286,49 @@ fn render_main_panels(
                 constraints[2],
             ])
             .split(content_area);
-
-        widgets::hero::render(frame, chunks[0], state, cli);
-        widgets::alerts::render(frame, chunks[1], alerts, state);
-        widgets::hourly::render(frame, chunks[2], state, cli);
-        widgets::daily::render(frame, chunks[3], state, cli);
-        return;
+        return MainPanelAreas {
+            hero: chunks[0],
+            alerts: Some(chunks[1]),
+            hourly: chunks[2],
+            daily: chunks[3],
+        };
     }
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(constraints)
         .split(content_area);
-    widgets::hero::render(frame, chunks[0], state, cli);
-    widgets::hourly::render(frame, chunks[1], state, cli);
-    widgets::daily::render(frame, chunks[2], state, cli);
+    MainPanelAreas {
+        hero: chunks[0],
+        alerts: None,
+        hourly: chunks[1],
+        daily: chunks[2],
+    }
+}
+
+fn render_main_panels(
+    frame: &mut Frame,
+    panels: &MainPanelAreas,
+    state: &AppState,
+    cli: &Cli,
+    alerts: &[crate::domain::alerts::WeatherAlert],
+) {
+    widgets::hero::render(frame, panels.hero, state, cli);
+    if let Some(alerts_area) = panels.alerts {
+        widgets::alerts::render(frame, alerts_area, alerts, state);
+    }
+    widgets::hourly::render(frame, panels.hourly, state, cli);
+    widgets::daily::render(frame, panels.daily, state, cli);
 }
 
 fn render_modal_overlay(frame: &mut Frame, area: Rect, state: &AppState, cli: &Cli) {
-    if state.mode == AppMode::SelectingLocation {
-        widgets::selector::render(frame, centered_rect(70, 60, area), state);
-    } else if state.settings_open {
-        widgets::settings::render(frame, centered_rect(68, 74, area), state);
-    } else if state.city_picker_open {
-        widgets::city_picker::render(frame, centered_rect(74, 74, area), state);
-    } else if state.help_open {
-        widgets::help::render(frame, centered_rect(82, 84, area), state, cli);
+    match modal_overlay(area, state) {
+        Some(ModalOverlay::Selector(popup)) => widgets::selector::render(frame, popup, state),
+        Some(ModalOverlay::Settings(popup)) => widgets::settings::render(frame, popup, state),
+        Some(ModalOverlay::CityPicker(popup)) => widgets::city_picker::render(frame, popup, state),
+        Some(ModalOverlay::Help(popup)) => widgets::help::render(frame, popup, state, cli),
+        Some(ModalOverlay::DayDetail(popup)) => widgets::daily::detail::render(frame, popup, state),
+        None => {}
     }
 }
 
@@ -261,10 +374,13 @@ fn render_status_badge(frame: &mut Frame, area: Rect, state: &AppState) {
 }
 
 fn freshness_badge_text(label: &str, state: &AppState) -> String {
-    state.refresh_meta.retry_in_seconds().map_or_else(
-        || label.to_string(),
-        |secs| format!("{label} · retry {secs}s"),
-    )
+    if let Some(secs) = state.refresh_meta.retry_in_seconds() {
+        return format!("{label} · retry {secs}s");
+    }
+    match state.refresh_meta.age_label() {
+        Some(age) if state.offline => format!("{label} · {age} old"),
+        _ => label.to_string(),
+    }
 }
 
 fn panel_constraints(content_area: Rect, requested_hourly_mode: HourlyViewMode) -> [Constraint; 3] {
@@ -366,6 +482,7 @@ fn has_modal_overlay(state: &AppState) -> bool {
         || state.settings_open
         || state.city_picker_open
         || state.help_open
+        || state.day_detail_open
 }
 
 fn spinner(frame_tick: u64) -> &'static str {
@@ -37,7 +37,7 @@ impl UiNarrativeState {
                 format!("Hourly focus: {}  ·  {}", self.next_6h, self.next_change)
             }
             PanelFocus::Daily => {
-                format!("Week focus: {}  ·  {}", self.next_change, self.now_action)
+                format!("Week focus: {}  ·  Enter: day detail", self.next_change)
             }
         }
     }
@@ -45,7 +45,12 @@ impl UiNarrativeState {
 
 #[must_use]
 pub fn build_narrative(state: &AppState, weather: &ForecastBundle) -> UiNarrativeState {
-    let insight = derive_nowcast_insight(weather, state.units, &state.refresh_meta);
+    let insight = derive_nowcast_insight(
+        weather,
+        state.units,
+        state.settings.measure_units(),
+        &state.refresh_meta,
+    );
     let next_change = insight.next_change.map_or_else(
         || "Next change: none notable in next 24h".to_string(),
         |change| {
@@ -1,8 +1,9 @@
 #![allow(clippy::must_use_candidate)]
 
+use chrono::{Duration, NaiveDateTime, NaiveTime};
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
@@ -10,15 +11,35 @@ use ratatui::{
 
 use crate::{
     app::state::AppState,
-    domain::alerts::{AlertSeverity, WeatherAlert},
+    domain::{
+        alerts::{AlertSeverity, WeatherAlert},
+        weather::HourlyForecast,
+    },
     ui::theme::resolved_theme,
 };
 
+/// Content height and width from which alerts get a timeline instead of the
+/// one-line ticker.
+const TIMELINE_MIN_HEIGHT: u16 = 34;
+const TIMELINE_MIN_WIDTH: u16 = 70;
+/// Alerts drawn on the timeline; the rest are counted on the axis row.
+const TIMELINE_MAX_ALERTS: usize = 3;
+
 pub fn render(frame: &mut Frame, area: Rect, alerts: &[WeatherAlert], state: &AppState) {
     if alerts.is_empty() || area.height == 0 || area.width < 10 {
         return;
     }
     let theme = resolved_theme(state);
+    if area.height > 1
+        && let Some(hourly) = state
+            .weather
+            .as_ref()
+            .map(|bundle| bundle.hourly.as_slice())
+        && !hourly.is_empty()
+    {
+        render_timeline(frame, area, alerts, hourly, theme);
+        return;
+    }
 
     let available_width = area.width as usize;
     let mut spans = Vec::new();
@@ -90,8 +111,137 @@ fn push_alert_span(
     true
 }
 
+/// One row per alert with its window drawn as a bar across the hourly
+/// forecast, under an axis marking where each day starts.
+fn render_timeline(
+    frame: &mut Frame,
+    area: Rect,
+    alerts: &[WeatherAlert],
+    hourly: &[HourlyForecast],
+    theme: crate::ui::theme::Theme,
+) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Min(10)])
+        .split(area);
+    let (label_area, track_area) = (columns[0], columns[1]);
+    let track = Track {
+        now: hourly[0].time,
+        hours: hourly.len() as f32,
+        width: usize::from(track_area.width.saturating_sub(1)),
+    };
+    let muted = Style::default().fg(theme.muted_text);
+
+    let shown = alerts.len().min(usize::from(area.height - 1));
+    let hidden = alerts.len() - shown;
+    let heading = if hidden > 0 {
+        format!(" Alerts (+{hidden} more)")
+    } else {
+        " Alerts".to_string()
+    };
+    let mut labels = vec![Line::from(Span::styled(heading, muted))];
+    let mut bars = vec![Line::from(Span::styled(track.axis(), muted))];
+    for alert in &alerts[..shown] {
+        let color = alert_color(theme, alert.severity);
+        let when = alert.window_label().unwrap_or_else(|| alert.eta_label());
+        let entry = format!(" {} {when} · {}", alert.icon, alert.message);
+        labels.push(Line::from(Span::styled(
+            fit_to_width(&entry, usize::from(label_area.width.saturating_sub(1))),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+        bars.push(Line::from(
+            track
+                .cells(alert)
+                .into_iter()
+                .map(|covered| {
+                    if covered {
+                        Span::styled("█", Style::default().fg(color))
+                    } else {
+                        Span::styled("·", muted)
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ));
+    }
+
+    let style = Style::default().bg(theme.surface_alt);
+    frame.render_widget(Paragraph::new(labels).style(style), label_area);
+    frame.render_widget(Paragraph::new(bars).style(style), track_area);
+}
+
+/// The hourly forecast squeezed into `width` columns.
+struct Track {
+    now: NaiveDateTime,
+    hours: f32,
+    width: usize,
+}
+
+impl Track {
+    fn hour_of(&self, time: NaiveDateTime) -> f32 {
+        (time - self.now).num_minutes() as f32 / 60.0
+    }
+
+    fn column_of(&self, hour: f32) -> usize {
+        (hour / self.hours * self.width as f32) as usize
+    }
+
+    /// `now` at the left edge and weekday names where each day starts.
+    fn axis(&self) -> String {
+        let mut axis = vec![' '; self.width];
+        let mut next_free = 0;
+        let mut put = |column: usize, label: &str| {
+            let len = label.chars().count();
+            if column >= next_free && column + len <= self.width {
+                for (offset, ch) in label.chars().enumerate() {
+                    axis[column + offset] = ch;
+                }
+                next_free = column + len + 1;
+            }
+        };
+        put(0, "now");
+        let mut midnight = (self.now.date() + Duration::days(1)).and_time(NaiveTime::MIN);
+        while self.hour_of(midnight) < self.hours {
+            let column = self.column_of(self.hour_of(midnight));
+            put(column, &format!("┊{}", midnight.format("%a")));
+            midnight += Duration::days(1);
+        }
+        axis.into_iter().collect()
+    }
+
+    /// Whether each column overlaps the alert's window.
+    fn cells(&self, alert: &WeatherAlert) -> Vec<bool> {
+        let Some(window) = alert.window else {
+            return vec![false; self.width];
+        };
+        let (start, end) = (self.hour_of(window.start), self.hour_of(window.end));
+        let per_column = self.hours / self.width as f32;
+        (0..self.width)
+            .map(|column| {
+                let from = column as f32 * per_column;
+                start < from + per_column && end > from
+            })
+            .collect()
+    }
+}
+
+/// `value` cut to `width` terminal cells, ending in `…` when shortened.
+fn fit_to_width(value: &str, width: usize) -> String {
+    if Line::raw(value).width() <= width {
+        return value.to_string();
+    }
+    let mut out = String::new();
+    for ch in value.chars() {
+        if Line::raw(format!("{out}{ch}…")).width() > width {
+            break;
+        }
+        out.push(ch);
+    }
+    out.push('…');
+    out
+}
+
 fn format_alert_entry(alert: &WeatherAlert) -> String {
-    let horizon = alert_horizon_label(alert.eta_hours);
+    let horizon = alert.timing_label();
     format!(
         "{} Do: {} · Why: {} · Details: timing {horizon}",
         alert.icon,
@@ -100,19 +250,6 @@ fn format_alert_entry(alert: &WeatherAlert) -> String {
     )
 }
 
-fn alert_horizon_label(eta_hours: Option<usize>) -> String {
-    eta_hours.map_or_else(
-        || "today".to_string(),
-        |hours| {
-            if hours == 0 {
-                "now".to_string()
-            } else {
-                format!("in {hours}h")
-            }
-        },
-    )
-}
-
 fn alert_decision(alert: &WeatherAlert) -> &'static str {
     let message = alert.message.to_ascii_lowercase();
     let keyword_table: &[(&[&str], &str)] = &[
@@ -153,15 +290,31 @@ fn truncate_alert_entry(value: &str, max_chars: usize) -> String {
     value.to_string()
 }
 
+/// Rows the alerts take in `content_area`: none without alerts, a timeline
+/// when there is room for one, and otherwise a single ticker line.
 #[must_use]
-pub fn alert_row_height(alerts: &[WeatherAlert]) -> u16 {
-    u16::from(!alerts.is_empty())
+pub fn alert_row_height(alerts: &[WeatherAlert], content_area: Rect) -> u16 {
+    if alerts.is_empty() {
+        return 0;
+    }
+    if content_area.height >= TIMELINE_MIN_HEIGHT && content_area.width >= TIMELINE_MIN_WIDTH {
+        return 1 + alerts.len().min(TIMELINE_MAX_ALERTS) as u16;
+    }
+    1
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{alert_color, alert_row_height, format_alert_entry, push_alert_span};
-    use crate::domain::alerts::{AlertSeverity, WeatherAlert};
+    use chrono::Duration;
+    use ratatui::layout::Rect;
+
+    use super::{
+        Track, alert_color, alert_row_height, fit_to_width, format_alert_entry, push_alert_span,
+    };
+    use crate::domain::alerts::{AlertSeverity, AlertWindow, WeatherAlert};
+    use crate::test_support::sample_hourly;
+
+    const COMPACT: Rect = Rect::new(0, 0, 80, 24);
 
     fn dummy_alert() -> WeatherAlert {
         WeatherAlert {
@@ -169,6 +322,7 @@ mod tests {
             message: "Test alert".to_string(),
             eta_hours: Some(1),
             severity: AlertSeverity::Info,
+            window: None,
         }
     }
 
@@ -180,13 +334,59 @@ mod tests {
 
     #[test]
     fn alert_row_height_zero_for_empty() {
-        assert_eq!(alert_row_height(&[]), 0);
+        assert_eq!(alert_row_height(&[], COMPACT), 0);
     }
 
     #[test]
     fn alert_row_height_one_for_non_empty() {
-        assert_eq!(alert_row_height(&[dummy_alert()]), 1);
-        assert_eq!(alert_row_height(&[dummy_alert(), dummy_alert()]), 1);
+        assert_eq!(alert_row_height(&[dummy_alert()], COMPACT), 1);
+        assert_eq!(
+            alert_row_height(&[dummy_alert(), dummy_alert()], COMPACT),
+            1
+        );
+    }
+
+    #[test]
+    fn alert_row_height_fits_a_timeline_on_tall_screens() {
+        let tall = Rect::new(0, 0, 100, 40);
+        assert_eq!(alert_row_height(&[dummy_alert()], tall), 2);
+        assert_eq!(alert_row_height(&vec![dummy_alert(); 5], tall), 4);
+    }
+
+    #[test]
+    fn timeline_track_marks_days_and_the_alert_window() {
+        // Thu 10:00 to Sat 10:00, one column per hour.
+        let now = sample_hourly().time;
+        let track = Track {
+            now,
+            hours: 48.0,
+            width: 48,
+        };
+        let axis = track.axis();
+        assert!(axis.starts_with("now"));
+        assert_eq!(axis.chars().position(|ch| ch == '┊'), Some(14));
+        assert!(axis.contains("┊Fri") && axis.contains("┊Sat"));
+
+        let alert = WeatherAlert {
+            window: Some(AlertWindow {
+                start: now + Duration::hours(4),
+                end: now + Duration::hours(11),
+            }),
+            ..dummy_alert()
+        };
+        let covered: Vec<usize> = track
+            .cells(&alert)
+            .iter()
+            .enumerate()
+            .filter_map(|(column, covered)| covered.then_some(column))
+            .collect();
+        assert_eq!(covered, (4..11).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn fit_to_width_counts_wide_glyphs() {
+        assert_eq!(fit_to_width("💨 Gusts", 20), "💨 Gusts");
+        assert_eq!(fit_to_width("💨 Gusts", 5), "💨 G…");
     }
 
     #[test]
@@ -200,6 +400,7 @@ mod tests {
             message: "This is a very long alert message".to_string(),
             eta_hours: Some(2),
             severity: AlertSeverity::Warning,
+            window: None,
         };
 
         let result = push_alert_span(
@@ -224,6 +425,7 @@ mod tests {
             message: "Test".to_string(),
             eta_hours: Some(0),
             severity: AlertSeverity::Warning,
+            window: None,
         };
 
         let result = push_alert_span(
@@ -265,6 +467,7 @@ mod tests {
             message: "Low visibility: 0.8km".to_string(),
             eta_hours: Some(2),
             severity: AlertSeverity::Warning,
+            window: None,
         };
 
         let entry = format_alert_entry(&alert);
@@ -280,6 +483,7 @@ mod tests {
             message: "Thunderstorms expected".to_string(),
             eta_hours: Some(0),
             severity: AlertSeverity::Warning,
+            window: None,
         };
 
         let entry = format_alert_entry(&alert);
@@ -1,17 +1,20 @@
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Layout, Rect},
+    layout::{Alignment, Constraint, Layout, Margin, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 
 use crate::{
-    app::{settings::RecentLocation, state::AppState},
+    app::{
+        settings::{PinnedLocation, RecentLocation},
+        state::AppState,
+    },
     ui::theme::{Theme, resolved_theme},
 };
 
-use super::shared::{popup_block, popup_panel_style};
+use super::shared::{list_item_at, popup_block, popup_panel_style};
 
 const SEARCH_LABEL: &str = "Search: ";
 const CITY_QUERY_MAX: usize = 50;
@@ -26,12 +29,7 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let chunks = Layout::vertical([
-        Constraint::Length(3),
-        Constraint::Min(6),
-        Constraint::Length(2),
-    ])
-    .split(inner);
+    let chunks = picker_chunks(inner);
 
     render_query_line(frame, chunks[0], state, theme);
     let items = recent_city_items(state, theme);
@@ -43,6 +41,33 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
     render_status_line(frame, chunks[2], state, theme);
 }
 
+fn picker_chunks(inner: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(6),
+        Constraint::Length(2),
+    ])
+    .split(inner)
+}
+
+/// The picker row under terminal `row` for a picker drawn in `area`: a location
+/// index, or the "clear all" action index.
+#[must_use]
+pub fn entry_at(area: Rect, state: &AppState, row: u16) -> Option<usize> {
+    let list_area = picker_chunks(area.inner(Margin::new(1, 1)))[1];
+    // The list block's title takes the first line.
+    let rows = Rect {
+        y: list_area.y + 1,
+        height: list_area.height.saturating_sub(1),
+        ..list_area
+    };
+    let len = state
+        .city_picker_action_index()
+        .map_or(0, |action| action + 1);
+    let selected = state.city_history_selected.min(len.saturating_sub(1));
+    list_item_at(rows, row, selected, len)
+}
+
 fn render_query_line(frame: &mut Frame, area: Rect, state: &AppState, theme: Theme) {
     let inner_area = render_query_block(frame, area, theme);
     let [input_area, count_area] = query_line_areas(inner_area);
@@ -139,12 +164,12 @@ fn query_cursor_x(state: &AppState, input_area: Rect) -> u16 {
 
 fn recent_city_items(state: &AppState, theme: Theme) -> Vec<ListItem<'static>> {
     let mut items = state
-        .settings
-        .recent_locations
-        .iter()
-        .take(9)
+        .city_picker_entries()
+        .into_iter()
         .enumerate()
-        .map(|(idx, saved)| ListItem::new(format_recent_location(idx, saved, state)))
+        .map(|(idx, (saved, pinned))| {
+            ListItem::new(format_recent_location(idx, saved, pinned, state))
+        })
         .collect::<Vec<_>>();
     if !items.is_empty() {
         items.push(ListItem::new(Line::from(vec![Span::styled(
@@ -194,7 +219,10 @@ fn recent_city_list(
         .block(
             Block::default()
                 .title(Line::from(vec![
-                    Span::styled("Recent (", Style::default().fg(theme.popup_muted_text)),
+                    Span::styled(
+                        "★ Pinned, then recent (",
+                        Style::default().fg(theme.popup_muted_text),
+                    ),
                     Span::styled(
                         "1-9",
                         Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
@@ -218,7 +246,12 @@ fn render_status_line(frame: &mut Frame, area: Rect, state: &AppState, theme: Th
     frame.render_widget(status, area);
 }
 
-fn format_recent_location(index: usize, saved: &RecentLocation, state: &AppState) -> String {
+fn format_recent_location(
+    index: usize,
+    saved: &RecentLocation,
+    pinned: Option<&PinnedLocation>,
+    state: &AppState,
+) -> String {
     let timezone = saved.timezone.as_deref().unwrap_or("--");
     let region = match (&saved.country, &saved.admin1) {
         (Some(country), Some(admin)) => format!("{country} · {admin}"),
@@ -231,9 +264,15 @@ fn format_recent_location(index: usize, saved: &RecentLocation, state: &AppState
     } else {
         ""
     };
+    let favorite = match pinned.and_then(|pinned| pinned.alias.as_deref()) {
+        Some(alias) => format!("★ {alias} · "),
+        None if pinned.is_some() => "★ ".to_string(),
+        None => String::new(),
+    };
     format!(
-        "{}. {}{} · {} · TZ {} · {:.2}, {:.2}",
+        "{}. {}{}{} · {} · TZ {} · {:.2}, {:.2}",
         index + 1,
+        favorite,
         marker,
         saved.display_name(),
         region,
@@ -34,7 +34,7 @@ fn sample_recent(name: &str, lat: f64, lon: f64) -> RecentLocation {
 fn format_recent_location_includes_all_fields() {
     let loc = sample_recent("Stockholm", 59.33, 18.07);
     let state = make_state();
-    let text = format_recent_location(0, &loc, &state);
+    let text = format_recent_location(0, &loc, None, &state);
     assert!(text.contains("Stockholm"), "got: {text}");
     assert!(text.contains("59.33"), "got: {text}");
     assert!(text.contains("18.07"), "got: {text}");
@@ -46,7 +46,7 @@ fn format_recent_location_includes_all_fields() {
 fn format_recent_location_second_entry_has_index_two() {
     let loc = sample_recent("Gothenburg", 57.70, 11.97);
     let state = make_state();
-    let text = format_recent_location(1, &loc, &state);
+    let text = format_recent_location(1, &loc, None, &state);
     assert!(text.starts_with("2."), "got: {text}");
 }
 
@@ -134,10 +134,22 @@ fn format_recent_location_with_marker_when_selected() {
     let mut state = make_state();
     state.selected_location = Some(crate::test_support::stockholm_location());
     let recent = RecentLocation::from_location(&state.selected_location.clone().unwrap());
-    let text = format_recent_location(0, &recent, &state);
+    let text = format_recent_location(0, &recent, None, &state);
     assert!(text.contains('*'));
 }
 
+#[test]
+fn format_recent_location_marks_pinned_entries_with_alias() {
+    let state = make_state();
+    let loc = sample_recent("Stockholm", 59.33, 18.07);
+    let pinned = crate::app::settings::PinnedLocation {
+        alias: Some("home".to_string()),
+        place: loc.clone(),
+    };
+    let text = format_recent_location(0, &loc, Some(&pinned), &state);
+    assert!(text.starts_with("1. ★ home · Stockholm"), "got: {text}");
+}
+
 #[test]
 fn format_recent_location_without_timezone_shows_placeholder() {
     let state = make_state();
@@ -149,7 +161,7 @@ fn format_recent_location_without_timezone_shows_placeholder() {
         admin1: None,
         timezone: None,
     };
-    let text = format_recent_location(0, &recent, &state);
+    let text = format_recent_location(0, &recent, None, &state);
     assert!(text.contains("--"));
 }
 
@@ -0,0 +1,293 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::{
+    app::state::{AppState, LocationKey},
+    domain::{
+        compare::{Delta, Wetness, align_daily, align_hourly, daily_delta, hourly_delta},
+        weather::{
+            DailyForecast, ForecastBundle, HourlyForecast, convert_temp, round_temp,
+            weather_code_to_category, weather_icon, weather_label_for_time,
+        },
+    },
+    ui::theme::{Theme, icon_color, resolved_theme, temp_color},
+};
+
+const HOURLY_ROWS: usize = 12;
+/// Width of the middle column holding `+12° wetter`.
+const DELTA_WIDTH: usize = 12;
+
+pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
+    let theme = resolved_theme(state);
+    let panel_style = Style::default().fg(theme.text).bg(theme.surface);
+    let left = state
+        .selected_location
+        .as_ref()
+        .map_or("—", |location| location.name.as_str());
+    let right = state
+        .compare
+        .location
+        .as_ref()
+        .map_or("…", |location| location.name.as_str());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Compare · {left} vs {right} · R refresh · Esc close"
+        ))
+        .style(panel_style)
+        .border_style(Style::default().fg(theme.border).bg(theme.surface));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let (Some(a), Some(b)) = (state.weather.as_ref(), state.compare_bundle()) else {
+        frame.render_widget(
+            Paragraph::new(waiting_line(state)).style(Style::default().fg(theme.muted_text)),
+            inner,
+        );
+        return;
+    };
+
+    let column = column_width(inner.width);
+    let mut lines = vec![
+        header_line(state, a, b, column, theme),
+        Line::from(""),
+        section_line("Next hours", theme),
+    ];
+    lines.extend(align_hourly(a, b, HOURLY_ROWS).into_iter().enumerate().map(
+        |(offset, (hour_a, hour_b))| hourly_line(state, offset, hour_a, hour_b, column, theme),
+    ));
+    lines.push(Line::from(""));
+    lines.push(section_line("Days", theme));
+    lines.extend(
+        align_daily(a, b)
+            .into_iter()
+            .map(|(day_a, day_b)| daily_line(state, day_a, day_b, column, theme)),
+    );
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Why the right-hand side is empty: lookup progress, a fetch error, or a
+/// fetch still in flight.
+fn waiting_line(state: &AppState) -> String {
+    if let Some(status) = &state.compare.status {
+        return status.clone();
+    }
+    if state.weather.is_none() {
+        return "Loading...".to_string();
+    }
+    state
+        .compare
+        .location
+        .as_ref()
+        .and_then(|location| {
+            let key: LocationKey = location.into();
+            state.dashboard.errors.get(&key)
+        })
+        .map_or_else(|| "Loading...".to_string(), |err| format!("⚠ {err}"))
+}
+
+/// Each side gets half of what remains after the row label and delta columns.
+fn column_width(width: u16) -> usize {
+    (usize::from(width).saturating_sub(DELTA_WIDTH + 8) / 2).max(12)
+}
+
+fn header_line(
+    state: &AppState,
+    a: &ForecastBundle,
+    b: &ForecastBundle,
+    column: usize,
+    theme: Theme,
+) -> Line<'static> {
+    let side = |bundle: &ForecastBundle| {
+        let current = &bundle.current;
+        let icon = weather_icon(
+            current.weather_code,
+            state.settings.icon_mode,
+            current.is_day,
+        );
+        let text = format!(
+            "{icon} {}° {}",
+            bundle.current_temp(state.units),
+            weather_label_for_time(current.weather_code, current.is_day)
+        );
+        Span::styled(
+            pad(&text, column),
+            Style::default()
+                .fg(temp_color(&theme, current.temperature_2m_c))
+                .add_modifier(Modifier::BOLD),
+        )
+    };
+    let now_delta = Delta {
+        temp: Some(b.current_temp(state.units) - a.current_temp(state.units)),
+        wetness: Wetness::Similar,
+    };
+    Line::from(vec![
+        Span::raw(pad("Now", 6)),
+        side(a),
+        delta_span(now_delta, theme),
+        side(b),
+    ])
+}
+
+fn hourly_line(
+    state: &AppState,
+    offset: usize,
+    a: &HourlyForecast,
+    b: &HourlyForecast,
+    column: usize,
+    theme: Theme,
+) -> Line<'static> {
+    let side = |hour: &HourlyForecast| {
+        let temp = hour
+            .temperature_2m_c
+            .map_or_else(|| "--".to_string(), |t| temp_label(t, state));
+        let text = format!(
+            "{} {} {temp} {}",
+            hour.time.format("%H:%M"),
+            hour_icon(state, hour),
+            percent_label(hour.precipitation_probability)
+        );
+        side_span(text, hour.weather_code, column, theme)
+    };
+    Line::from(vec![
+        Span::styled(
+            pad(&format!("+{offset}h"), 6),
+            Style::default().fg(theme.muted_text),
+        ),
+        side(a),
+        delta_span(hourly_delta(a, b, state.units), theme),
+        side(b),
+    ])
+}
+
+fn daily_line(
+    state: &AppState,
+    a: &DailyForecast,
+    b: &DailyForecast,
+    column: usize,
+    theme: Theme,
+) -> Line<'static> {
+    let side = |day: &DailyForecast| {
+        let icon = day.weather_code.map_or("·", |code| {
+            weather_icon(code, state.settings.icon_mode, true)
+        });
+        let high = day
+            .temperature_max_c
+            .map_or_else(|| "--".to_string(), |t| temp_label(t, state));
+        let low = day
+            .temperature_min_c
+            .map_or_else(|| "--".to_string(), |t| temp_label(t, state));
+        let text = format!(
+            "{icon} {high}/{low} {}",
+            percent_label(day.precipitation_probability_max)
+        );
+        side_span(text, day.weather_code, column, theme)
+    };
+    Line::from(vec![
+        Span::styled(
+            pad(&a.date.format("%a %d").to_string(), 6),
+            Style::default().fg(theme.muted_text),
+        ),
+        side(a),
+        delta_span(daily_delta(a, b, state.units), theme),
+        side(b),
+    ])
+}
+
+fn hour_icon(state: &AppState, hour: &HourlyForecast) -> &'static str {
+    hour.weather_code.map_or("·", |code| {
+        weather_icon(code, state.settings.icon_mode, hour.is_day.unwrap_or(true))
+    })
+}
+
+fn side_span(text: String, code: Option<u8>, column: usize, theme: Theme) -> Span<'static> {
+    let color = code.map_or(theme.text, |code| {
+        icon_color(&theme, weather_code_to_category(code))
+    });
+    Span::styled(pad(&text, column), Style::default().fg(color))
+}
+
+/// Warmer reads in the warning color, colder in the info color.
+fn delta_span(delta: Delta, theme: Theme) -> Span<'static> {
+    let color = match delta.temp {
+        Some(temp) if temp > 0 => theme.warning,
+        Some(temp) if temp < 0 => theme.info,
+        _ => theme.muted_text,
+    };
+    Span::styled(
+        pad(&delta.label(), DELTA_WIDTH),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    )
+}
+
+fn section_line(title: &'static str, theme: Theme) -> Line<'static> {
+    Line::from(Span::styled(
+        title,
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn temp_label(celsius: f32, state: &AppState) -> String {
+    format!("{}°", round_temp(convert_temp(celsius, state.units)))
+}
+
+fn percent_label(probability: Option<f32>) -> String {
+    probability.map_or_else(String::new, |p| format!("{}%", p.round() as i32))
+}
+
+/// Truncates or pads `text` to exactly `width` characters.
+fn pad(text: &str, width: usize) -> String {
+    let truncated: String = text.chars().take(width.saturating_sub(1)).collect();
+    format!("{truncated:<width$}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{sample_bundle, state_test_cli};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn rendered_text(state: &AppState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).expect("test terminal");
+        terminal
+            .draw(|frame| render(frame, frame.area(), state))
+            .expect("draw");
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    #[test]
+    fn shows_both_sides_and_deltas_once_second_forecast_arrives() {
+        let mut state = AppState::new(&state_test_cli());
+        let a = sample_bundle();
+        let mut b = sample_bundle();
+        b.location.name = "Oslo".to_string();
+        b.location.latitude = 59.91;
+        b.current.temperature_2m_c += 3.0;
+        state.selected_location = Some(a.location.clone());
+        state.weather = Some(a);
+        state.compare.open = true;
+        state.compare.location = Some(b.location.clone());
+        assert!(rendered_text(&state).contains("Loading..."));
+
+        state.forecast_cache.put((&b.location).into(), b);
+        let text = rendered_text(&state);
+        assert!(text.contains("Stockholm vs Oslo"), "got: {text}");
+        assert!(text.contains("+3°"), "got: {text}");
+        assert!(text.contains("Next hours"));
+        assert!(text.contains("Days"));
+    }
+}
@@ -4,9 +4,11 @@
     clippy::cast_sign_loss
 )]
 
+use std::ops::Range;
+
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout, Rect},
+    layout::{Constraint, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row},
@@ -16,8 +18,8 @@ use crate::{
     app::state::{AppState, PanelFocus},
     cli::{Cli, IconMode},
     domain::weather::{
-        DailyForecast, ForecastBundle, Units, convert_temp, round_temp, weather_code_to_category,
-        weather_icon,
+        DailyForecast, ForecastBundle, MeasureUnits, Units, convert_temp, round_temp,
+        weather_code_to_category, weather_icon,
     },
     ui::{
         motion_context,
@@ -26,6 +28,7 @@ use crate::{
     },
 };
 
+pub mod detail;
 mod layout;
 mod loading;
 mod summary;
@@ -49,13 +52,11 @@ fn render_daily_loading(frame: &mut Frame, area: Rect, state: &AppState) {
     let panel_style = Style::default().fg(theme.text).bg(theme.surface_alt);
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(
-            if state.panel_focus == crate::app::state::PanelFocus::Daily {
-                "▶ 7-Day"
-            } else {
-                "7-Day"
-            },
-        )
+        .title(daily_title(
+            usize::from(state.settings.forecast_days),
+            None,
+            state.panel_focus == PanelFocus::Daily,
+        ))
         .style(panel_style)
         .border_style(Style::default().fg(theme.border).bg(theme.surface_alt));
     let inner = block.inner(area);
@@ -76,34 +77,38 @@ fn render_daily_with_bundle(
     state: &AppState,
     bundle: &ForecastBundle,
 ) {
-    let (layout, theme, panel_style, inner) = prepare_daily_bundle_panel(frame, area, state);
+    let layout = DailyLayout::for_area(area);
+    let window = day_window(
+        layout.max_rows(daily_content_area(area, state).height),
+        bundle.daily.len(),
+        state.daily_cursor,
+    );
+    let title = daily_title(
+        bundle.daily.len(),
+        Some(&window),
+        state.panel_focus == PanelFocus::Daily,
+    );
+    let (theme, panel_style, inner) = prepare_daily_bundle_panel(frame, area, state, title);
     let content_area = render_daily_context_strip(frame, inner, state, bundle, theme);
-    let max_rows = layout.max_rows(content_area.height);
-    if max_rows == 0 {
+    if window.is_empty() {
         return;
     }
 
     let (global_min, global_max) = global_temp_bounds(bundle);
     let ctx = DailyRenderContext {
         units: state.units,
+        measures: state.settings.measure_units(),
         icon_mode: state.settings.icon_mode,
         layout,
         theme,
         global_min,
         global_max,
     };
-    let reveal_rows = visible_reveal_rows(max_rows, state.transition_progress());
-    let rows = build_daily_rows(bundle, reveal_rows, ctx);
-    let table = build_daily_table(rows, panel_style, layout, theme.muted_text);
-    render_daily_table_and_summary(
-        frame,
-        content_area,
-        table,
-        bundle,
-        state.units,
-        theme,
-        layout,
-    );
+    let reveal_rows = visible_reveal_rows(window.len(), state.transition_progress());
+    let selected = (state.panel_focus == PanelFocus::Daily).then_some(state.daily_cursor);
+    let rows = build_daily_rows(bundle, window, reveal_rows, selected, ctx);
+    let table = build_daily_table(rows, panel_style, layout, ctx.measures, theme.muted_text);
+    render_daily_table_and_summary(frame, content_area, table, bundle, ctx);
 }
 
 fn visible_reveal_rows(max_rows: usize, transition_progress: Option<f32>) -> usize {
@@ -118,22 +123,76 @@ fn prepare_daily_bundle_panel(
     frame: &mut Frame,
     area: Rect,
     state: &AppState,
-) -> (DailyLayout, crate::ui::theme::Theme, Style, Rect) {
-    let layout = DailyLayout::for_area(area);
+    title: String,
+) -> (crate::ui::theme::Theme, Style, Rect) {
     let theme = resolved_theme(state);
     let panel_style = Style::default().fg(theme.text).bg(theme.surface_alt);
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(if state.panel_focus == PanelFocus::Daily {
-            "▶ 7-Day Forecast"
-        } else {
-            "7-Day Forecast"
-        })
+        .title(title)
         .style(panel_style)
         .border_style(Style::default().fg(theme.border).bg(theme.surface_alt));
     let inner = block.inner(area);
     frame.render_widget(block, area);
-    (layout, theme, panel_style, inner)
+    (theme, panel_style, inner)
+}
+
+/// `14-Day Forecast`, with the visible range once the days outgrow the panel.
+fn daily_title(days: usize, window: Option<&Range<usize>>, focused: bool) -> String {
+    let marker = if focused { "▶ " } else { "" };
+    match window {
+        Some(window) if !window.is_empty() && window.len() < days => format!(
+            "{marker}{days}-Day Forecast · {}–{} of {days}",
+            window.start + 1,
+            window.end
+        ),
+        _ => format!("{marker}{days}-Day Forecast"),
+    }
+}
+
+/// The days shown when `visible` rows fit: from the top, scrolled just far
+/// enough to keep the cursor on screen.
+fn day_window(visible: usize, days: usize, cursor: usize) -> Range<usize> {
+    let visible = visible.min(days);
+    if visible == 0 {
+        return 0..0;
+    }
+    let start = cursor.saturating_sub(visible - 1).min(days - visible);
+    start..start + visible
+}
+
+/// The panel interior below the context strip, where the table and summary go.
+fn daily_content_area(area: Rect, state: &AppState) -> Rect {
+    let inner = area.inner(Margin::new(1, 1));
+    if shows_context_strip(inner, state) {
+        Rect {
+            y: inner.y + 1,
+            height: inner.height.saturating_sub(1),
+            ..inner
+        }
+    } else {
+        inner
+    }
+}
+
+/// The day under terminal `row` for a daily panel drawn in `area`.
+#[must_use]
+pub fn day_at(area: Rect, state: &AppState, row: u16) -> Option<usize> {
+    let bundle = state.weather.as_ref()?;
+    let layout = DailyLayout::for_area(area);
+    let content_area = daily_content_area(area, state);
+    let first_row = content_area.y + u16::from(layout.show_header);
+    let window = day_window(
+        layout.max_rows(content_area.height),
+        bundle.daily.len(),
+        state.daily_cursor,
+    );
+    let index = window.start + usize::from(row.checked_sub(first_row)?);
+    window.contains(&index).then_some(index)
+}
+
+fn shows_context_strip(inner: Rect, state: &AppState) -> bool {
+    state.settings.inline_hints && state.panel_focus == PanelFocus::Daily && inner.height >= 8
 }
 
 fn render_daily_context_strip(
@@ -143,7 +202,7 @@ fn render_daily_context_strip(
     bundle: &ForecastBundle,
     theme: crate::ui::theme::Theme,
 ) -> Rect {
-    if !state.settings.inline_hints || state.panel_focus != PanelFocus::Daily || inner.height < 8 {
+    if !shows_context_strip(inner, state) {
         return inner;
     }
 
@@ -171,21 +230,23 @@ fn global_temp_bounds(bundle: &ForecastBundle) -> (f32, f32) {
 
 fn build_daily_rows(
     bundle: &ForecastBundle,
+    window: Range<usize>,
     max_rows: usize,
+    selected: Option<usize>,
     ctx: DailyRenderContext,
 ) -> Vec<Row<'static>> {
-    bundle
-        .daily
+    bundle.daily[window.clone()]
         .iter()
+        .zip(window)
         .take(max_rows)
-        .enumerate()
-        .map(|(idx, day)| build_daily_row(day, idx == 0, ctx))
+        .map(|(day, idx)| build_daily_row(day, idx == 0, selected == Some(idx), ctx))
         .collect()
 }
 
 #[derive(Debug, Clone, Copy)]
 struct DailyRenderContext {
     units: Units,
+    measures: MeasureUnits,
     icon_mode: IconMode,
     layout: DailyLayout,
     theme: crate::ui::theme::Theme,
@@ -193,9 +254,15 @@ struct DailyRenderContext {
     global_max: f32,
 }
 
-fn build_daily_row(day: &DailyForecast, is_today: bool, ctx: DailyRenderContext) -> Row<'static> {
+fn build_daily_row(
+    day: &DailyForecast,
+    is_today: bool,
+    is_selected: bool,
+    ctx: DailyRenderContext,
+) -> Row<'static> {
     let DailyRenderContext {
         units,
+        measures,
         icon_mode,
         layout,
         theme,
@@ -211,6 +278,7 @@ fn build_daily_row(day: &DailyForecast, is_today: bool, ctx: DailyRenderContext)
     let mut cells = daily_base_cells(day, theme);
     let ctx = DailyRowContext {
         units,
+        measures,
         icon_mode,
         layout,
         theme,
@@ -224,17 +292,20 @@ fn build_daily_row(day: &DailyForecast, is_today: bool, ctx: DailyRenderContext)
 
     append_daily_optional_cells(&mut cells, day, &ctx);
 
-    let row = Row::new(cells);
+    let mut style = Style::default();
     if is_today {
-        row.style(Style::default().add_modifier(Modifier::BOLD))
-    } else {
-        row
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if is_selected {
+        style = style.add_modifier(Modifier::REVERSED);
     }
+    Row::new(cells).style(style)
 }
 
 #[derive(Debug)]
 struct DailyRowContext {
     units: Units,
+    measures: MeasureUnits,
     icon_mode: IconMode,
     layout: DailyLayout,
     theme: crate::ui::theme::Theme,
@@ -266,8 +337,20 @@ fn append_daily_optional_cells(
         ctx.theme,
     );
     append_daily_range_cell(cells, ctx);
-    append_daily_precip_cell(cells, day, ctx.layout.show_precip_col, ctx.theme);
-    append_daily_gust_cell(cells, day, ctx.layout.show_gust_col, ctx.theme);
+    append_daily_precip_cell(
+        cells,
+        day,
+        ctx.layout.show_precip_col,
+        ctx.measures,
+        ctx.theme,
+    );
+    append_daily_gust_cell(
+        cells,
+        day,
+        ctx.layout.show_gust_col,
+        ctx.measures,
+        ctx.theme,
+    );
 }
 
 fn append_daily_icon_cell(
@@ -327,14 +410,16 @@ fn append_daily_precip_cell(
     cells: &mut Vec<Cell<'static>>,
     day: &DailyForecast,
     show_precip_col: bool,
+    measures: MeasureUnits,
     theme: crate::ui::theme::Theme,
 ) {
     if !show_precip_col {
         return;
     }
-    let precip = day
-        .precipitation_sum_mm
-        .map_or_else(|| "--.-".to_string(), |v| format!("{v:>4.1}"));
+    let precip = day.precipitation_sum_mm.map_or_else(
+        || "--.-".to_string(),
+        |v| format!("{:>4}", measures.precip.format_value(v)),
+    );
     cells.push(Cell::from(precip).style(Style::default().fg(theme.info)));
 }
 
@@ -342,6 +427,7 @@ fn append_daily_gust_cell(
     cells: &mut Vec<Cell<'static>>,
     day: &DailyForecast,
     show_gust_col: bool,
+    measures: MeasureUnits,
     theme: crate::ui::theme::Theme,
 ) {
     if !show_gust_col {
@@ -349,7 +435,7 @@ fn append_daily_gust_cell(
     }
     let gust = day.wind_gusts_10m_max.map_or_else(
         || "-- ".to_string(),
-        |v| format!("{:>3}", crate::domain::weather::round_wind_speed(v)),
+        |v| format!("{:>3}", measures.wind.round(v)),
     );
     cells.push(Cell::from(gust).style(Style::default().fg(theme.warning)));
 }
@@ -0,0 +1,348 @@
+use ratatui::widgets::{Clear, Table};
+
+use super::*;
+use crate::{
+    domain::weather::{HourlyForecast, summarize_dayparts, weather_label},
+    ui::widgets::{
+        hourly::render_daypart_section,
+        shared::{popup_block, popup_panel_style, sparkline_blocks},
+    },
+};
+use summary::utils::format_duration_hm;
+
+const CURVE_LABEL_WIDTH: usize = 8;
+
+/// The drill-down for the day under the daily cursor: its hourly curves,
+/// dayparts, an hour-by-hour table, and the sun and UV figures.
+pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
+    frame.render_widget(Clear, area);
+    let theme = resolved_theme(state);
+    let panel_style = popup_panel_style(theme);
+    let block = popup_block("Day detail", theme, panel_style);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(bundle) = state.weather.as_ref() else {
+        return;
+    };
+    let Some(day) = bundle.daily.get(state.daily_cursor) else {
+        return;
+    };
+    let hours: Vec<&HourlyForecast> = bundle
+        .hourly
+        .iter()
+        .filter(|hour| hour.time.date() == day.date)
+        .collect();
+
+    let [header, curves, dayparts, table] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(5),
+        Constraint::Length(7),
+        Constraint::Min(0),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new(header_lines(state, bundle, day, theme)).style(panel_style),
+        header,
+    );
+    if hours.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No hourly data for this day.")
+                .style(Style::default().fg(theme.popup_muted_text)),
+            curves,
+        );
+        return;
+    }
+    frame.render_widget(
+        Paragraph::new(curve_lines(state, &hours, curves.width, theme)),
+        curves,
+    );
+    let summaries = summarize_dayparts(
+        &bundle.hourly,
+        bundle.current.weather_code,
+        state.daily_cursor + 1,
+    );
+    render_daypart_section(frame, dayparts, day.date, &summaries, state, theme);
+    render_hour_table(frame, table, state, &hours, theme);
+}
+
+fn header_lines(
+    state: &AppState,
+    bundle: &ForecastBundle,
+    day: &DailyForecast,
+    theme: crate::ui::theme::Theme,
+) -> Vec<Line<'static>> {
+    let muted = Style::default().fg(theme.popup_muted_text);
+    let code = day.weather_code.unwrap_or(bundle.current.weather_code);
+    let temp = |value: Option<f32>| {
+        value.map_or_else(
+            || "--".to_string(),
+            |c| format!("{}°", round_temp(convert_temp(c, state.units))),
+        )
+    };
+    let measures = state.settings.measure_units();
+    let precip = format!(
+        "{}{}",
+        measures
+            .precip
+            .format(day.precipitation_sum_mm.unwrap_or(0.0).max(0.0)),
+        day.precipitation_probability_max
+            .map_or_else(String::new, |p| format!(" ({p:.0}%)"))
+    );
+    let clock = |time: Option<chrono::NaiveDateTime>| {
+        time.map_or_else(|| "--:--".to_string(), |t| t.format("%H:%M").to_string())
+    };
+    let duration =
+        |seconds: Option<f32>| seconds.map_or_else(|| "--".to_string(), format_duration_hm);
+    vec![
+        Line::from(vec![
+            Span::styled(
+                format!(
+                    "{} {}  ",
+                    day.date.format("%A %d %B"),
+                    weather_icon(code, state.settings.icon_mode, true)
+                ),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(weather_label(code).to_string()),
+            Span::styled(
+                format!(
+                    "  ·  Day {}/{}  ·  ←/→ day  ·  Esc close",
+                    state.daily_cursor + 1,
+                    bundle.daily.len()
+                ),
+                muted,
+            ),
+        ]),
+        Line::from(format!(
+            "High {}  ·  Low {}  ·  Precip {precip}  ·  UV {}",
+            temp(day.temperature_max_c),
+            temp(day.temperature_min_c),
+            day.uv_index_max
+                .map_or_else(|| "--".to_string(), |uv| format!("{uv:.0}"))
+        )),
+        Line::from(Span::styled(
+            format!(
+                "Sunrise {}  ·  Sunset {}  ·  Daylight {}  ·  Sunshine {}",
+                clock(day.sunrise),
+                clock(day.sunset),
+                duration(day.daylight_duration_s),
+                duration(day.sunshine_duration_s)
+            ),
+            muted,
+        )),
+    ]
+}
+
+/// Sparklines over the day's hours, each followed by its range.
+fn curve_lines(
+    state: &AppState,
+    hours: &[&HourlyForecast],
+    width: u16,
+    theme: crate::ui::theme::Theme,
+) -> Vec<Line<'static>> {
+    let spark_width = usize::from(width)
+        .saturating_sub(CURVE_LABEL_WIDTH + 18)
+        .clamp(8, hours.len().max(8) * 2);
+    let series = |project: fn(&HourlyForecast) -> Option<f32>| -> Vec<f32> {
+        hours.iter().filter_map(|hour| project(hour)).collect()
+    };
+    let temps: Vec<f32> = series(|hour| hour.temperature_2m_c)
+        .into_iter()
+        .map(|c| convert_temp(c, state.units))
+        .collect();
+    let measures = state.settings.measure_units();
+    let precip = series(|hour| hour.precipitation_mm.map(|mm| mm.max(0.0)));
+    let wind = series(|hour| hour.wind_speed_10m);
+    let gusts = series(|hour| hour.wind_gusts_10m);
+
+    let curve = |label: &str, values: &[f32], summary: String, color: Color| {
+        Line::from(vec![
+            Span::styled(
+                format!("{label:<CURVE_LABEL_WIDTH$}"),
+                Style::default().fg(theme.popup_muted_text),
+            ),
+            Span::styled(
+                sparkline_blocks(values, spark_width),
+                Style::default().fg(color),
+            ),
+            Span::raw(format!("  {summary}")),
+        ])
+    };
+    let min_max = |values: &[f32]| {
+        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        (min, max)
+    };
+    let (temp_min, temp_max) = min_max(&temps);
+    let (_, wind_max) = min_max(&wind);
+    let (_, gust_max) = min_max(&gusts);
+    let first = hours
+        .first()
+        .map(|hour| hour.time.format("%H:%M").to_string());
+    let last = hours
+        .last()
+        .map(|hour| hour.time.format("%H:%M").to_string());
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "Hourly  {}–{} ({} h)",
+            first.unwrap_or_default(),
+            last.unwrap_or_default(),
+            hours.len()
+        ),
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    if !temps.is_empty() {
+        lines.push(curve(
+            "Temp",
+            &temps,
+            format!("{}°..{}°", round_temp(temp_min), round_temp(temp_max)),
+            temp_color(&theme, temp_max),
+        ));
+    }
+    if !precip.is_empty() {
+        lines.push(curve(
+            "Precip",
+            &precip,
+            format!(
+                "{} total",
+                measures.precip.format(precip.iter().sum::<f32>())
+            ),
+            theme.info,
+        ));
+    }
+    if !wind.is_empty() {
+        let gust = if gusts.is_empty() {
+            String::new()
+        } else {
+            format!(", gusts {}", measures.wind.round(gust_max))
+        };
+        lines.push(curve(
+            "Wind",
+            &wind,
+            format!(
+                "max {}{gust} {}",
+                measures.wind.round(wind_max),
+                measures.wind.symbol()
+            ),
+            theme.warning,
+        ));
+    }
+    lines
+}
+
+/// Hour-by-hour rows, thinned to every second or third hour when the panel
+/// is too short for all of them.
+fn render_hour_table(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    hours: &[&HourlyForecast],
+    theme: crate::ui::theme::Theme,
+) {
+    let capacity = usize::from(area.height.saturating_sub(1));
+    if capacity == 0 {
+        return;
+    }
+    let step = hours.len().div_ceil(capacity).max(1);
+    let measures = state.settings.measure_units();
+    let rows = hours.iter().step_by(step).map(|hour| {
+        let code = hour.weather_code.unwrap_or(3);
+        Row::new(vec![
+            Cell::from(hour.time.format("%H:%M").to_string()),
+            Cell::from(weather_icon(
+                code,
+                state.settings.icon_mode,
+                hour.is_day.unwrap_or(true),
+            ))
+            .style(Style::default().fg(icon_color(&theme, weather_code_to_category(code)))),
+            Cell::from(hour.temperature_2m_c.map_or_else(
+                || "--".to_string(),
+                |c| format!("{}°", round_temp(convert_temp(c, state.units))),
+            ))
+            .style(
+                Style::default().fg(hour
+                    .temperature_2m_c
+                    .map_or(theme.text, |c| temp_color(&theme, c))),
+            ),
+            Cell::from(
+                hour.precipitation_probability
+                    .map_or_else(|| "--".to_string(), |p| format!("{p:.0}%")),
+            ),
+            Cell::from(hour.precipitation_mm.map_or_else(
+                || "--".to_string(),
+                |mm| measures.precip.format(mm.max(0.0)),
+            ))
+            .style(Style::default().fg(theme.info)),
+            Cell::from(hour.wind_speed_10m.map_or_else(
+                || "--".to_string(),
+                |kmh| format!("{}{}", measures.wind.round(kmh), measures.wind.symbol()),
+            )),
+        ])
+    });
+    let header = Row::new(vec!["Time", "Wx", "Temp", "Prob", "Precip", "Wind"])
+        .style(Style::default().fg(theme.popup_muted_text));
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(6),
+            Constraint::Length(3),
+            Constraint::Length(5),
+            Constraint::Length(5),
+            Constraint::Length(7),
+            Constraint::Length(7),
+        ],
+    )
+    .header(header)
+    .column_spacing(1);
+    frame.render_widget(table, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{sample_bundle, state_test_cli};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn shows_sun_times_curves_and_hours_for_selected_day() {
+        let mut state = AppState::new(&state_test_cli());
+        let mut bundle = sample_bundle();
+        let first = bundle.hourly[0].clone();
+        bundle.hourly = (0..6)
+            .map(|offset| {
+                let mut hour = first.clone();
+                hour.time += chrono::Duration::hours(offset);
+                hour
+            })
+            .collect();
+        bundle.daily[0].date = first.time.date();
+        state.weather = Some(bundle);
+
+        let mut terminal = Terminal::new(TestBackend::new(90, 32)).expect("test terminal");
+        terminal
+            .draw(|frame| render(frame, frame.area(), &state))
+            .expect("draw");
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains("Sunrise"), "got: {text}");
+        assert!(text.contains("Day 1/1"), "got: {text}");
+        assert!(text.contains("(6 h)"), "got: {text}");
+        assert!(text.contains("Temp"), "got: {text}");
+        assert!(
+            text.contains("Morning") || text.contains("Noon"),
+            "got: {text}"
+        );
+    }
+}
@@ -77,7 +77,7 @@ impl DailyLayout {
 
     pub(super) fn max_rows(self, inner_height: u16) -> usize {
         let reserved = u16::from(self.show_header);
-        usize::from(inner_height.saturating_sub(reserved)).min(7)
+        usize::from(inner_height.saturating_sub(reserved))
     }
 
     fn narrow() -> Self {
@@ -160,13 +160,13 @@ mod tests {
     }
 
     #[test]
-    fn max_rows_respects_header_and_seven_day_cap() {
+    fn max_rows_respects_header() {
         let wide = DailyLayout::for_area(area_with_inner_width(112));
-        assert_eq!(wide.max_rows(20), 7);
+        assert_eq!(wide.max_rows(20), 19);
         assert_eq!(wide.max_rows(3), 2);
 
         let narrow = DailyLayout::for_area(area_with_inner_width(35));
-        assert_eq!(narrow.max_rows(20), 7);
+        assert_eq!(narrow.max_rows(20), 20);
         assert_eq!(narrow.max_rows(3), 3);
     }
 
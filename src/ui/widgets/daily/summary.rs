@@ -3,13 +3,14 @@ use super::*;
 mod accumulator;
 pub(super) mod utils;
 
+use crate::domain::weather::PrecipUnit;
 use accumulator::WeekAccumulator;
 use utils::{day_cue, first_day_shifted_time, first_day_time, profile_bar, short_weekday};
 
 #[cfg(test)]
 use accumulator::{
-    average_duration, average_precip_hours, format_day_value_mm, format_day_value_mps,
-    format_uv_peak, week_thermal_span,
+    average_duration, average_precip_hours, format_day_gust, format_day_precip, format_uv_peak,
+    week_thermal_span,
 };
 
 pub(super) fn render_week_summary(
@@ -17,14 +18,18 @@ pub(super) fn render_week_summary(
     area: Rect,
     bundle: &ForecastBundle,
     units: Units,
+    measures: MeasureUnits,
     theme: crate::ui::theme::Theme,
 ) {
     if area.width < 20 || area.height == 0 || bundle.daily.is_empty() {
         return;
     }
 
-    let summary = summarize_week(bundle, units);
+    let summary = summarize_week(bundle, units, measures);
     let mut lines = week_summary_header_lines(&summary, theme);
+    if let Some(line) = week_blocks_line(bundle, units, measures, theme) {
+        lines.push(line);
+    }
     if area.width >= 64 {
         append_week_meta_line(&mut lines, bundle, theme);
     } else if area.width >= 38 {
@@ -32,13 +37,16 @@ pub(super) fn render_week_summary(
     }
     let mut remaining_rows = (area.height as usize).saturating_sub(lines.len());
     append_week_profiles(&mut lines, &mut remaining_rows, area, &summary, theme);
-    append_day_cues(&mut lines, &mut remaining_rows, bundle, theme);
+    append_day_cues(&mut lines, &mut remaining_rows, bundle, measures, theme);
     append_compact_profiles(&mut lines, remaining_rows, area, &summary, theme);
     frame.render_widget(Paragraph::new(lines), area);
 }
 
 #[derive(Debug, Default)]
 pub(super) struct WeekSummaryData {
+    /// Days aggregated; the labels say "week" only when this is seven.
+    pub(super) days: usize,
+    pub(super) precip_unit: PrecipUnit,
     pub(super) precip_total: f32,
     pub(super) rain_total: f32,
     pub(super) snow_total: f32,
@@ -55,12 +63,16 @@ pub(super) struct WeekSummaryData {
     pub(super) gusts: Vec<f32>,
 }
 
-pub(super) fn summarize_week(bundle: &ForecastBundle, units: Units) -> WeekSummaryData {
+pub(super) fn summarize_week(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+) -> WeekSummaryData {
     let mut accumulator = WeekAccumulator::default();
     for day in &bundle.daily {
         accumulator.ingest(day);
     }
-    accumulator.finish(units, &bundle.daily)
+    accumulator.finish(units, measures, &bundle.daily)
 }
 
 fn week_summary_header_lines(
@@ -109,27 +121,97 @@ fn week_actionability_lines(
     ]
 }
 
-fn actionability_summary(summary: &WeekSummaryData) -> &'static str {
-    if summary.precip_total >= 20.0 {
-        "Precip-heavy week: prioritize dry windows"
-    } else if summary.breeziest_txt != "--" && summary.breeziest_txt.contains("m/s") {
-        "Mixed week: track wind and UV day by day"
+/// Precipitation over a week that makes it precip-heavy; scaled for other horizons.
+const HEAVY_WEEK_PRECIP_MM: f32 = 20.0;
+
+fn actionability_summary(summary: &WeekSummaryData) -> String {
+    let period = period_noun(summary.days);
+    let heavy_mm = HEAVY_WEEK_PRECIP_MM * summary.days.max(1) as f32 / 7.0;
+    if summary.precip_total >= heavy_mm {
+        format!("Precip-heavy {period}: prioritize dry windows")
+    } else if summary.breeziest_txt != "--" {
+        format!("Mixed {period}: track wind and UV day by day")
+    } else {
+        format!("Stable {period}: low planning friction")
+    }
+}
+
+/// `week` for a seven-day horizon, `14-day outlook` and the like otherwise.
+fn period_noun(days: usize) -> String {
+    if days == 7 {
+        "week".to_string()
     } else {
-        "Stable week: low planning friction"
+        format!("{days}-day outlook")
     }
 }
 
+/// `Week` for a seven-day horizon, `14-day` otherwise.
+fn period_label(days: usize) -> String {
+    if days == 7 {
+        "Week".to_string()
+    } else {
+        format!("{days}-day")
+    }
+}
+
+/// Precipitation and temperature span per run of seven days, once the horizon
+/// is longer than a week.
+fn week_blocks_line(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+    theme: crate::ui::theme::Theme,
+) -> Option<Line<'static>> {
+    if bundle.daily.len() <= 7 {
+        return None;
+    }
+    let mut spans = vec![Span::styled(
+        "Weeks ",
+        Style::default().fg(theme.muted_text),
+    )];
+    for (idx, week) in bundle.daily.chunks(7).enumerate() {
+        let (Some(first), Some(last)) = (week.first(), week.last()) else {
+            continue;
+        };
+        let mut accumulator = WeekAccumulator::default();
+        for day in week {
+            accumulator.ingest(day);
+        }
+        let summary = accumulator.finish(units, measures, week);
+        if idx > 0 {
+            spans.push(Span::raw("  "));
+        }
+        spans.push(Span::styled(
+            format!(
+                "{}–{} ",
+                short_weekday(first.date),
+                short_weekday(last.date)
+            ),
+            Style::default().fg(theme.text),
+        ));
+        spans.push(Span::styled(
+            format!("{} ", measures.precip.format(summary.precip_total)),
+            Style::default().fg(theme.info),
+        ));
+        spans.push(Span::styled(
+            summary.week_thermal,
+            Style::default().fg(theme.accent),
+        ));
+    }
+    Some(Line::from(spans))
+}
+
 fn week_totals_line(summary: &WeekSummaryData, theme: crate::ui::theme::Theme) -> Line<'static> {
     Line::from(vec![
         Span::styled("Totals ", Style::default().fg(theme.muted_text)),
         Span::styled(
-            format!("P {:.1}mm", summary.precip_total),
+            format!("P {}", summary.precip_unit.format(summary.precip_total)),
             Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
         ),
         Span::raw("  "),
         Span::styled("Rain ", Style::default().fg(theme.muted_text)),
         Span::styled(
-            format!("{:.1}mm", summary.rain_total),
+            summary.precip_unit.format(summary.rain_total),
             Style::default().fg(theme.info),
         ),
         Span::raw("  "),
@@ -178,7 +260,10 @@ fn week_precip_uv_line(summary: &WeekSummaryData, theme: crate::ui::theme::Theme
         Span::styled("Peak UV ", Style::default().fg(theme.muted_text)),
         Span::styled(summary.uv_peak.clone(), Style::default().fg(theme.warning)),
         Span::raw("  "),
-        Span::styled("Week span ", Style::default().fg(theme.muted_text)),
+        Span::styled(
+            format!("{} span ", period_label(summary.days)),
+            Style::default().fg(theme.muted_text),
+        ),
         Span::styled(
             summary.week_thermal.clone(),
             Style::default().fg(theme.accent),
@@ -241,7 +326,7 @@ fn append_week_profiles(
     }
 
     lines.push(Line::from(Span::styled(
-        "Week profiles",
+        format!("{} profiles", period_label(summary.days)),
         Style::default()
             .fg(theme.muted_text)
             .add_modifier(Modifier::BOLD),
@@ -259,7 +344,7 @@ fn append_week_profiles(
         ProfileLineSpec {
             label: "Precip lane ",
             values: &summary.precip,
-            suffix: format!("{:.1}mm", summary.precip_total),
+            suffix: summary.precip_unit.format(summary.precip_total),
             color: theme.info,
         },
         ProfileLineSpec {
@@ -308,6 +393,7 @@ fn append_day_cues(
     lines: &mut Vec<Line<'static>>,
     remaining_rows: &mut usize,
     bundle: &ForecastBundle,
+    measures: MeasureUnits,
     theme: crate::ui::theme::Theme,
 ) {
     if *remaining_rows < 2 {
@@ -329,7 +415,7 @@ fn append_day_cues(
                 format!("{:>3} ", short_weekday(day.date)),
                 Style::default().fg(theme.muted_text),
             ),
-            Span::styled(day_cue(day), Style::default().fg(theme.text)),
+            Span::styled(day_cue(day, measures), Style::default().fg(theme.text)),
         ]));
     }
     *remaining_rows = remaining_rows.saturating_sub(cue_rows);
@@ -1,4 +1,6 @@
-use crate::domain::weather::{DailyForecast, Units, convert_temp, round_temp};
+use crate::domain::weather::{
+    DailyForecast, MeasureUnits, PrecipUnit, Units, WindUnit, convert_temp, round_temp,
+};
 
 use super::WeekSummaryData;
 use super::utils::{format_duration_hm, short_weekday};
@@ -116,9 +118,14 @@ impl WeekAccumulator {
         }
     }
 
-    pub(super) fn finish(self, units: Units, daily: &[DailyForecast]) -> WeekSummaryData {
-        let wettest_txt = format_day_value_mm(self.wettest);
-        let breeziest_txt = format_day_value_mps(self.breeziest);
+    pub(super) fn finish(
+        self,
+        units: Units,
+        measures: MeasureUnits,
+        daily: &[DailyForecast],
+    ) -> WeekSummaryData {
+        let wettest_txt = format_day_precip(self.wettest, measures.precip);
+        let breeziest_txt = format_day_gust(self.breeziest, measures.wind);
         let comfort_best_txt = format_best_day(self.comfort_best);
         let avg_daylight = average_duration(self.daylight_total, self.daylight_count);
         let avg_sun = average_duration(self.sunshine_total, self.sunshine_count);
@@ -130,9 +137,11 @@ impl WeekAccumulator {
         let week_thermal = week_thermal_span(self.week_min_temp_c, self.week_max_temp_c, units);
         let highs = collect_highs(daily, units);
         let precip = collect_precip(daily);
-        let gusts = collect_gusts(daily);
+        let gusts = collect_gusts(daily, measures.wind);
 
         WeekSummaryData {
+            days: daily.len(),
+            precip_unit: measures.precip,
             precip_total: self.precip_total,
             rain_total: self.rain_total,
             snow_total: self.snow_total,
@@ -151,23 +160,20 @@ impl WeekAccumulator {
     }
 }
 
-pub(super) fn format_day_value_mm(value: Option<(chrono::NaiveDate, f32)>) -> String {
+pub(super) fn format_day_precip(
+    value: Option<(chrono::NaiveDate, f32)>,
+    unit: PrecipUnit,
+) -> String {
     value.map_or_else(
         || "--".to_string(),
-        |(day, mm)| format!("{} {mm:.1}mm", short_weekday(day)),
+        |(day, mm)| format!("{} {}", short_weekday(day), unit.format(mm)),
     )
 }
 
-pub(super) fn format_day_value_mps(value: Option<(chrono::NaiveDate, f32)>) -> String {
+pub(super) fn format_day_gust(value: Option<(chrono::NaiveDate, f32)>, unit: WindUnit) -> String {
     value.map_or_else(
         || "--".to_string(),
-        |(day, speed)| {
-            format!(
-                "{} {}m/s",
-                short_weekday(day),
-                crate::domain::weather::round_wind_speed(speed)
-            )
-        },
+        |(day, speed)| format!("{} {}", short_weekday(day), unit.format(speed)),
     )
 }
 
@@ -227,9 +233,9 @@ fn collect_precip(daily: &[DailyForecast]) -> Vec<f32> {
         .collect::<Vec<_>>()
 }
 
-fn collect_gusts(daily: &[DailyForecast]) -> Vec<f32> {
+fn collect_gusts(daily: &[DailyForecast], unit: WindUnit) -> Vec<f32> {
     daily
         .iter()
-        .map(|d| crate::domain::weather::convert_wind_speed(d.wind_gusts_10m_max.unwrap_or(0.0)))
+        .map(|d| unit.convert(d.wind_gusts_10m_max.unwrap_or(0.0)))
         .collect::<Vec<_>>()
 }
@@ -48,6 +48,7 @@ fn sample_bundle_with_daily(daily: Vec<DailyForecast>) -> ForecastBundle {
         daily,
         air_quality: None,
         fetched_at: Utc::now(),
+        utc_offset_seconds: 0,
     }
 }
 
@@ -1,4 +1,5 @@
 use super::*;
+use crate::domain::weather::WindUnit;
 use chrono::NaiveDate;
 
 #[test]
@@ -22,27 +23,31 @@ fn average_precip_hours_formats_correctly() {
 }
 
 #[test]
-fn format_day_value_mm_none_returns_placeholder() {
-    assert_eq!(format_day_value_mm(None), "--");
+fn format_day_precip_none_returns_placeholder() {
+    assert_eq!(format_day_precip(None, PrecipUnit::Mm), "--");
 }
 
 #[test]
-fn format_day_value_mm_some_formats_correctly() {
-    let result = format_day_value_mm(Some((NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(), 3.2)));
+fn format_day_precip_some_formats_correctly() {
+    let day = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+    let result = format_day_precip(Some((day, 3.2)), PrecipUnit::Mm);
     assert!(result.contains("Mon"), "got: {result}");
     assert!(result.contains("3.2mm"), "got: {result}");
+    assert!(format_day_precip(Some((day, 25.4)), PrecipUnit::In).contains("1.00in"));
 }
 
 #[test]
-fn format_day_value_mps_none_returns_placeholder() {
-    assert_eq!(format_day_value_mps(None), "--");
+fn format_day_gust_none_returns_placeholder() {
+    assert_eq!(format_day_gust(None, WindUnit::Ms), "--");
 }
 
 #[test]
-fn format_day_value_mps_some_formats_in_mps() {
-    let result = format_day_value_mps(Some((NaiveDate::from_ymd_opt(2023, 1, 3).unwrap(), 36.0)));
+fn format_day_gust_some_formats_in_selected_unit() {
+    let day = NaiveDate::from_ymd_opt(2023, 1, 3).unwrap();
+    let result = format_day_gust(Some((day, 36.0)), WindUnit::Ms);
     assert!(result.contains("Tue"), "got: {result}");
-    assert!(result.contains("m/s"), "got: {result}");
+    assert!(result.contains("10 m/s"), "got: {result}");
+    assert!(format_day_gust(Some((day, 36.0)), WindUnit::Kmh).contains("36 km/h"));
 }
 
 #[test]
@@ -3,7 +3,7 @@ use super::*;
 #[test]
 fn summarize_week_empty_daily_returns_defaults() {
     let bundle = sample_bundle_with_daily(vec![]);
-    let summary = summarize_week(&bundle, Units::Celsius);
+    let summary = summarize_week(&bundle, Units::Celsius, MeasureUnits::default());
     assert_eq!(summary.avg_daylight, "--:--");
     assert_eq!(summary.avg_sun, "--:--");
     assert_eq!(summary.wettest_txt, "--");
@@ -16,7 +16,7 @@ fn summarize_week_empty_daily_returns_defaults() {
 #[test]
 fn summarize_week_aggregates_single_day() {
     let bundle = sample_bundle_with_daily(vec![sample_day(3.5, 20.0, 4.5)]);
-    let summary = summarize_week(&bundle, Units::Celsius);
+    let summary = summarize_week(&bundle, Units::Celsius, MeasureUnits::default());
     assert!((summary.precip_total - 3.5).abs() < f32::EPSILON);
     assert_eq!(summary.highs.len(), 1);
 }
@@ -26,14 +26,14 @@ fn summarize_week_null_precipitation_skipped() {
     let mut day = sample_day(0.0, 20.0, 3.0);
     day.precipitation_sum_mm = None;
     let bundle = sample_bundle_with_daily(vec![day]);
-    let summary = summarize_week(&bundle, Units::Celsius);
+    let summary = summarize_week(&bundle, Units::Celsius, MeasureUnits::default());
     assert_eq!(summary.wettest_txt, "--");
 }
 
 #[test]
 fn append_week_profiles_covers_guard_and_render_paths() {
     let bundle = sample_bundle_with_daily(vec![sample_day(1.0, 10.0, 2.0)]);
-    let summary = summarize_week(&bundle, Units::Celsius);
+    let summary = summarize_week(&bundle, Units::Celsius, MeasureUnits::default());
     let theme = test_theme();
 
     let mut lines = Vec::new();
@@ -7,7 +7,16 @@ fn draw_week_summary(area: Rect, daily: Vec<DailyForecast>) {
     let backend = TestBackend::new(area.width, area.height);
     let mut terminal = Terminal::new(backend).expect("terminal");
     terminal
-        .draw(|frame| render_week_summary(frame, area, &bundle, Units::Celsius, theme))
+        .draw(|frame| {
+            render_week_summary(
+                frame,
+                area,
+                &bundle,
+                Units::Celsius,
+                MeasureUnits::default(),
+                theme,
+            );
+        })
         .expect("draw");
 }
 
@@ -39,6 +48,7 @@ fn render_week_summary_hits_meta_and_sunrise_layout_paths() {
                 Rect::new(0, 0, 80, 10),
                 &bundle,
                 Units::Celsius,
+                MeasureUnits::default(),
                 theme,
             );
         })
@@ -52,8 +62,52 @@ fn render_week_summary_hits_meta_and_sunrise_layout_paths() {
                 Rect::new(0, 0, 48, 10),
                 &bundle,
                 Units::Celsius,
+                MeasureUnits::default(),
                 theme,
             );
         })
         .expect("draw");
 }
+
+#[test]
+fn long_horizon_summary_labels_period_and_splits_weeks() {
+    let daily: Vec<DailyForecast> = (0..14)
+        .map(|offset| DailyForecast {
+            date: NaiveDate::from_ymd_opt(2026, 2, 12).expect("date")
+                + chrono::Duration::days(offset),
+            ..sample_day(2.0, 10.0, 2.0)
+        })
+        .collect();
+    let bundle = sample_bundle_with_daily(daily);
+    let summary = summarize_week(&bundle, Units::Celsius, MeasureUnits::default());
+    // 28mm over 14 days is a wet pace for a week but not for a fortnight.
+    assert_eq!(
+        actionability_summary(&summary),
+        "Mixed 14-day outlook: track wind and UV day by day"
+    );
+
+    let weeks = week_blocks_line(
+        &bundle,
+        Units::Celsius,
+        MeasureUnits::default(),
+        test_theme(),
+    )
+    .expect("two weeks");
+    let text: String = weeks
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect();
+    assert_eq!(text, "Weeks Thu–Wed 14.0mm 1°..8°  Thu–Wed 14.0mm 1°..8°");
+
+    let one_week = sample_bundle_with_daily(vec![sample_day(1.0, 10.0, 2.0)]);
+    assert!(
+        week_blocks_line(
+            &one_week,
+            Units::Celsius,
+            MeasureUnits::default(),
+            test_theme()
+        )
+        .is_none()
+    );
+}
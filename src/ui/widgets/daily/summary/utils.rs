@@ -1,4 +1,6 @@
-pub(super) fn format_duration_hm(seconds: f32) -> String {
+use crate::domain::weather::{DailyForecast, MeasureUnits, PrecipUnit, WindUnit};
+
+pub(crate) fn format_duration_hm(seconds: f32) -> String {
     let total_minutes = (seconds.max(0.0) / 60.0).round() as i64;
     let h = total_minutes / 60;
     let m = total_minutes % 60;
@@ -12,28 +14,24 @@ pub(super) fn profile_bar(values: &[f32], width: usize) -> String {
     crate::ui::widgets::shared::sparkline_blocks(values, width)
 }
 
-pub(super) fn precipitation_cue(day: &crate::domain::weather::DailyForecast) -> String {
+pub(super) fn precipitation_cue(day: &DailyForecast, unit: PrecipUnit) -> String {
     let precip = day.precipitation_sum_mm.unwrap_or(0.0).max(0.0);
     let rain = day.rain_sum_mm.unwrap_or(0.0).max(0.0);
     let snow = day.snowfall_sum_cm.unwrap_or(0.0).max(0.0);
-    threshold_cue(snow, 1.0, 0.2, "snow", "light snow", "cm")
-        .or_else(|| threshold_cue(rain, 6.0, 1.0, "wet", "light rain", "mm"))
-        .or_else(|| threshold_cue(precip, 6.0, 1.0, "wet", "light precip", "mm"))
+    let snow_cm = |value: f32| format!("{value:.1}cm");
+    let liquid = |value: f32| unit.format(value);
+    threshold_cue(snow, 1.0, 0.2, "snow", "light snow", snow_cm)
+        .or_else(|| threshold_cue(rain, 6.0, 1.0, "wet", "light rain", liquid))
+        .or_else(|| threshold_cue(precip, 6.0, 1.0, "wet", "light precip", liquid))
         .unwrap_or_else(|| "mostly dry".to_string())
 }
 
-pub(super) fn gust_cue(gust: f32) -> Option<String> {
+pub(super) fn gust_cue(gust: f32, unit: WindUnit) -> Option<String> {
     if gust >= 45.0 {
-        return Some(format!(
-            "gusty {}m/s",
-            crate::domain::weather::round_wind_speed(gust)
-        ));
+        return Some(format!("gusty {}", unit.format(gust)));
     }
     if gust >= 30.0 {
-        return Some(format!(
-            "breezy {}m/s",
-            crate::domain::weather::round_wind_speed(gust)
-        ));
+        return Some(format!("breezy {}", unit.format(gust)));
     }
     None
 }
@@ -79,9 +77,9 @@ pub(super) fn first_day_shifted_time(
         )
 }
 
-pub(super) fn day_cue(day: &crate::domain::weather::DailyForecast) -> String {
-    let mut parts = vec![precipitation_cue(day)];
-    if let Some(gust) = gust_cue(day.wind_gusts_10m_max.unwrap_or(0.0)) {
+pub(super) fn day_cue(day: &DailyForecast, measures: MeasureUnits) -> String {
+    let mut parts = vec![precipitation_cue(day, measures.precip)];
+    if let Some(gust) = gust_cue(day.wind_gusts_10m_max.unwrap_or(0.0), measures.wind) {
         parts.push(gust);
     }
     if let Some(sunlight) = sunlight_cue(day) {
@@ -96,12 +94,12 @@ fn threshold_cue(
     light_threshold: f32,
     strong_label: &str,
     light_label: &str,
-    unit: &str,
+    format_value: impl Fn(f32) -> String,
 ) -> Option<String> {
     if value >= strong_threshold {
-        Some(format!("{strong_label} {value:.1}{unit}"))
+        Some(format!("{strong_label} {}", format_value(value)))
     } else if value >= light_threshold {
-        Some(format!("{light_label} {value:.1}{unit}"))
+        Some(format!("{light_label} {}", format_value(value)))
     } else {
         None
     }
@@ -123,7 +121,6 @@ pub(crate) fn short_weekday(date: chrono::NaiveDate) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::weather::DailyForecast;
     use chrono::NaiveDate;
 
     fn sample_day(
@@ -188,28 +185,28 @@ mod tests {
     #[test]
     fn precipitation_cue_snow_takes_priority() {
         let day = sample_day(10.0, 5.0, 0.0, 0.0, 1.0);
-        let cue = precipitation_cue(&day);
+        let cue = precipitation_cue(&day, PrecipUnit::Mm);
         assert!(cue.contains("snow"), "got: {cue}");
     }
 
     #[test]
     fn precipitation_cue_heavy_rain() {
         let day = sample_day(8.0, 0.0, 0.0, 0.0, 1.0);
-        let cue = precipitation_cue(&day);
+        let cue = precipitation_cue(&day, PrecipUnit::Mm);
         assert!(cue.contains("wet"), "got: {cue}");
     }
 
     #[test]
     fn precipitation_cue_light_rain() {
         let day = sample_day(2.0, 0.0, 0.0, 0.0, 1.0);
-        let cue = precipitation_cue(&day);
+        let cue = precipitation_cue(&day, PrecipUnit::Mm);
         assert!(cue.contains("light rain"), "got: {cue}");
     }
 
     #[test]
     fn precipitation_cue_dry() {
         let day = sample_day(0.0, 0.0, 0.0, 0.0, 1.0);
-        let cue = precipitation_cue(&day);
+        let cue = precipitation_cue(&day, PrecipUnit::Mm);
         assert_eq!(cue, "mostly dry");
     }
 
@@ -217,7 +214,7 @@ mod tests {
     fn precipitation_cue_light_snow_when_rain_is_zero() {
         let mut day = sample_day(2.0, 0.4, 0.0, 0.0, 1.0);
         day.rain_sum_mm = Some(0.0);
-        let cue = precipitation_cue(&day);
+        let cue = precipitation_cue(&day, PrecipUnit::Mm);
         assert!(cue.contains("light snow"), "got: {cue}");
     }
 
@@ -225,7 +222,7 @@ mod tests {
     fn precipitation_cue_light_precip_when_type_not_identified() {
         let mut day = sample_day(1.8, 0.0, 0.0, 0.0, 1.0);
         day.rain_sum_mm = Some(0.0);
-        let cue = precipitation_cue(&day);
+        let cue = precipitation_cue(&day, PrecipUnit::Mm);
         assert!(cue.contains("light precip"), "got: {cue}");
     }
 
@@ -233,18 +230,18 @@ mod tests {
 
     #[test]
     fn gust_cue_none_below_threshold() {
-        assert!(gust_cue(20.0).is_none());
+        assert!(gust_cue(20.0, WindUnit::Ms).is_none());
     }
 
     #[test]
     fn gust_cue_breezy_threshold() {
-        let cue = gust_cue(35.0).expect("should be Some");
+        let cue = gust_cue(35.0, WindUnit::Ms).expect("should be Some");
         assert!(cue.contains("breezy"), "got: {cue}");
     }
 
     #[test]
     fn gust_cue_gusty_threshold() {
-        let cue = gust_cue(50.0).expect("should be Some");
+        let cue = gust_cue(50.0, WindUnit::Ms).expect("should be Some");
         assert!(cue.contains("gusty"), "got: {cue}");
     }
 
@@ -328,7 +325,7 @@ mod tests {
     #[test]
     fn day_cue_combines_precip_gust_and_sun() {
         let day = sample_day(8.0, 0.0, 50.0, 72_000.0, 100_000.0);
-        let cue = day_cue(&day);
+        let cue = day_cue(&day, MeasureUnits::default());
         assert!(cue.contains("wet"), "got: {cue}");
         assert!(cue.contains("gusty"), "got: {cue}");
         assert!(cue.contains("bright"), "got: {cue}");
@@ -337,7 +334,7 @@ mod tests {
     #[test]
     fn day_cue_dry_calm_returns_mostly_dry() {
         let day = sample_day(0.0, 0.0, 10.0, 30_000.0, 70_000.0);
-        let cue = day_cue(&day);
+        let cue = day_cue(&day, MeasureUnits::default());
         assert_eq!(cue, "mostly dry");
     }
 }
@@ -7,22 +7,25 @@ use ratatui::{
 };
 
 use crate::{
-    domain::weather::{ForecastBundle, Units},
-    ui::widgets::daily::{layout::DailyLayout, summary::render_week_summary},
+    domain::weather::{ForecastBundle, MeasureUnits, PrecipUnit},
+    ui::widgets::daily::{DailyRenderContext, layout::DailyLayout, summary::render_week_summary},
 };
 
 pub(super) fn build_daily_table(
     rows: Vec<Row<'static>>,
     panel_style: Style,
     layout: DailyLayout,
+    measures: MeasureUnits,
     muted_text: Color,
 ) -> Table<'static> {
     let mut table = Table::new(rows, daily_table_widths(layout))
         .column_spacing(layout.column_spacing)
         .style(panel_style);
     if layout.show_header {
-        table = table
-            .header(Row::new(daily_header_cells(layout)).style(Style::default().fg(muted_text)));
+        table = table.header(
+            Row::new(daily_header_cells(layout, measures.precip))
+                .style(Style::default().fg(muted_text)),
+        );
     }
     table
 }
@@ -32,16 +35,22 @@ pub(super) fn render_daily_table_and_summary(
     inner: Rect,
     table: Table<'static>,
     bundle: &ForecastBundle,
-    units: Units,
-    theme: crate::ui::theme::Theme,
-    layout: DailyLayout,
+    ctx: DailyRenderContext,
 ) {
+    let layout = ctx.layout;
     let row_count = bundle.daily.len().min(layout.max_rows(inner.height)) as u16;
     let table_height = row_count.saturating_add(u16::from(layout.show_header));
     let (table_area, summary_slot) = split_table_and_summary(inner, table_height);
     frame.render_widget(table, table_area);
     if let Some(summary_area) = summary_slot {
-        render_week_summary(frame, summary_area, bundle, units, theme);
+        render_week_summary(
+            frame,
+            summary_area,
+            bundle,
+            ctx.units,
+            ctx.measures,
+            ctx.theme,
+        );
     }
 }
 
@@ -64,7 +73,7 @@ fn daily_table_widths(layout: DailyLayout) -> Vec<Constraint> {
     widths
 }
 
-fn daily_header_cells(layout: DailyLayout) -> Vec<Line<'static>> {
+fn daily_header_cells(layout: DailyLayout, precip_unit: PrecipUnit) -> Vec<Line<'static>> {
     let mut cells = vec![Line::from("Day")];
     if layout.show_icon {
         cells.push(Line::from("Wx"));
@@ -75,7 +84,7 @@ fn daily_header_cells(layout: DailyLayout) -> Vec<Line<'static>> {
     }
     cells.push(Line::from("High"));
     if layout.show_precip_col {
-        cells.push(Line::from("Pmm"));
+        cells.push(Line::from(format!("P{}", precip_unit.symbol())));
     }
     if layout.show_gust_col {
         cells.push(Line::from("Gst"));
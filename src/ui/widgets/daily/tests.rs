@@ -46,11 +46,26 @@ fn daily_layout_changes_by_width() {
     assert!(!narrow.show_header);
 }
 
+#[test]
+fn day_window_scrolls_to_keep_cursor_visible() {
+    assert_eq!(day_window(5, 14, 0), 0..5);
+    assert_eq!(day_window(5, 14, 4), 0..5);
+    assert_eq!(day_window(5, 14, 7), 3..8);
+    assert_eq!(day_window(5, 14, 13), 9..14);
+    assert_eq!(day_window(20, 7, 6), 0..7);
+    assert_eq!(day_window(0, 7, 3), 0..0);
+    assert_eq!(
+        daily_title(14, Some(&(3..8)), true),
+        "▶ 14-Day Forecast · 4–8 of 14"
+    );
+    assert_eq!(daily_title(7, Some(&(0..7)), false), "7-Day Forecast");
+}
+
 #[test]
 fn summarize_week_aggregates_three_day_dataset() {
     let daily = sample_three_day_daily();
     let bundle = sample_bundle(daily.clone());
-    let summary = summarize_week(&bundle, Units::Celsius);
+    let summary = summarize_week(&bundle, Units::Celsius, MeasureUnits::default());
     assert_three_day_summary(&summary, &daily);
 }
 
@@ -115,7 +130,7 @@ fn assert_three_day_summary(summary: &WeekSummaryData, daily: &[DailyForecast])
     assert_eq!(
         summary.breeziest_txt,
         format!(
-            "{} 14m/s",
+            "{} 14 m/s",
             super::summary::utils::short_weekday(daily[1].date)
         )
     );
@@ -195,5 +210,6 @@ fn sample_bundle(daily: Vec<DailyForecast>) -> ForecastBundle {
         daily,
         air_quality: None,
         fetched_at: Utc::now(),
+        utc_offset_seconds: 0,
     }
 }
@@ -0,0 +1,271 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::{
+    app::{
+        settings::PINNED_LOCATIONS_MAX,
+        state::{AppState, LocationKey},
+    },
+    domain::{
+        alerts::{AlertSeverity, scan_alerts},
+        weather::{
+            ForecastBundle, Location, next_notable_change, weather_code_to_category, weather_icon,
+            weather_label_for_time,
+        },
+    },
+    ui::theme::{Theme, icon_color, resolved_theme, temp_color},
+};
+
+const CARD_MIN_WIDTH: u16 = 30;
+const CARD_HEIGHT: u16 = 6;
+const MAX_COLUMNS: usize = 4;
+
+/// Cards per grid row for a terminal `width` columns wide.
+#[must_use]
+pub fn grid_columns(width: u16) -> usize {
+    usize::from(width / CARD_MIN_WIDTH).clamp(1, MAX_COLUMNS)
+}
+
+pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
+    let theme = resolved_theme(state);
+    let panel_style = Style::default().fg(theme.text).bg(theme.surface);
+    let pinned = state.pinned_locations();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "Dashboard · {}/{PINNED_LOCATIONS_MAX} pinned · Enter open · R refresh · X unpin · D close",
+            pinned.len()
+        ))
+        .style(panel_style)
+        .border_style(Style::default().fg(theme.border).bg(theme.surface));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if pinned.is_empty() {
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::from("No pinned locations yet."),
+                Line::from("Press P in the main view to pin the city on screen,"),
+                Line::from("or run `terminal-weather locations pin <city>`."),
+            ])
+            .style(Style::default().fg(theme.muted_text)),
+            inner,
+        );
+        return;
+    }
+
+    let columns = grid_columns(area.width);
+    let visible_rows = usize::from((inner.height / CARD_HEIGHT).max(1));
+    let selected_row = state.dashboard.selected / columns;
+    let first_row = selected_row.saturating_sub(visible_rows - 1);
+    let row_areas =
+        Layout::vertical(vec![Constraint::Length(CARD_HEIGHT); visible_rows]).split(inner);
+    for (row_offset, row_area) in row_areas.iter().enumerate() {
+        let row = first_row + row_offset;
+        let cell_areas = Layout::horizontal(vec![Constraint::Ratio(1, columns as u32); columns])
+            .split(*row_area);
+        for (column, cell_area) in cell_areas.iter().enumerate() {
+            let index = row * columns + column;
+            if let Some(location) = pinned.get(index) {
+                render_card(frame, *cell_area, state, index, location, theme);
+            }
+        }
+    }
+}
+
+fn render_card(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    index: usize,
+    location: &Location,
+    theme: Theme,
+) {
+    let selected = index == state.dashboard.selected;
+    let border_style = if selected {
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.border)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(card_title(state, index, location))
+        .border_style(border_style);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let key: LocationKey = location.into();
+    let lines = match state.forecast_cache.peek(&key) {
+        Some(bundle) => card_lines(state, bundle, theme),
+        None => {
+            let status = state
+                .dashboard
+                .errors
+                .get(&key)
+                .map_or_else(|| "Loading...".to_string(), |err| format!("⚠ {err}"));
+            vec![Line::from(Span::styled(
+                status,
+                Style::default().fg(theme.muted_text),
+            ))]
+        }
+    };
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// `N alias · name` for aliased favorites, `N name` otherwise.
+fn card_title(state: &AppState, index: usize, location: &Location) -> String {
+    match state
+        .settings
+        .pinned_locations
+        .get(index)
+        .and_then(|pinned| pinned.alias.as_deref())
+    {
+        Some(alias) => format!("{} {alias} · {}", index + 1, location.name),
+        None => format!("{} {}", index + 1, location.name),
+    }
+}
+
+fn card_lines(state: &AppState, bundle: &ForecastBundle, theme: Theme) -> Vec<Line<'static>> {
+    let current = &bundle.current;
+    let category = weather_code_to_category(current.weather_code);
+    let icon = weather_icon(
+        current.weather_code,
+        state.settings.icon_mode,
+        current.is_day,
+    );
+    let muted = Style::default().fg(theme.muted_text);
+
+    let alert_line = scan_alerts(
+        bundle,
+        state.units,
+        state.settings.measure_units(),
+        &state.settings.alert_rules,
+    )
+    .first()
+    .map_or_else(
+        || Line::from(Span::styled("No alerts", muted)),
+        |alert| {
+            let color = match alert.severity {
+                AlertSeverity::Danger => theme.danger,
+                AlertSeverity::Warning => theme.warning,
+                AlertSeverity::Info => theme.info,
+            };
+            Line::from(Span::styled(
+                format!(
+                    "{} {} · {}",
+                    alert.icon,
+                    alert.message,
+                    alert.timing_label()
+                ),
+                Style::default().fg(color),
+            ))
+        },
+    );
+    let change_line = next_notable_change(&bundle.hourly, state.units, state.settings.wind_unit)
+        .map_or_else(
+            || Line::from(Span::styled("Steady next 24h", muted)),
+            |change| {
+                Line::from(Span::styled(
+                    format!("{} in {}h", change.message, change.hours_from_now),
+                    Style::default().fg(theme.text),
+                ))
+            },
+        );
+
+    vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{icon} "),
+                Style::default().fg(icon_color(&theme, category)),
+            ),
+            Span::styled(
+                format!("{}°", bundle.current_temp(state.units)),
+                Style::default()
+                    .fg(temp_color(&theme, current.temperature_2m_c))
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "  {}",
+                    weather_label_for_time(current.weather_code, current.is_day)
+                ),
+                Style::default().fg(theme.text),
+            ),
+        ]),
+        alert_line,
+        change_line,
+        Line::from(Span::styled(age_label(bundle), muted)),
+    ]
+}
+
+fn age_label(bundle: &ForecastBundle) -> String {
+    let minutes = (chrono::Utc::now() - bundle.fetched_at)
+        .num_minutes()
+        .max(0);
+    if minutes < 1 {
+        "Updated just now".to_string()
+    } else if minutes < 60 {
+        format!("Updated {minutes}m ago")
+    } else {
+        format!("Updated {}h ago", minutes / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{sample_bundle, state_test_cli, stockholm_location};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn rendered_text(state: &AppState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).expect("test terminal");
+        terminal
+            .draw(|frame| render(frame, frame.area(), state))
+            .expect("draw");
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    #[test]
+    fn grid_columns_fit_width_within_bounds() {
+        assert_eq!(grid_columns(20), 1);
+        assert_eq!(grid_columns(100), 3);
+        assert_eq!(grid_columns(300), MAX_COLUMNS);
+    }
+
+    #[test]
+    fn cards_show_cached_forecast_and_loading_placeholder() {
+        let mut state = AppState::new(&state_test_cli());
+        state.settings.pin_location(&stockholm_location(), None);
+        let mut oslo = stockholm_location();
+        oslo.name = "Oslo".to_string();
+        oslo.latitude = 59.91;
+        state.settings.pin_location(&oslo, None);
+        let bundle = sample_bundle();
+        state.forecast_cache.put((&bundle.location).into(), bundle);
+
+        let text = rendered_text(&state);
+        assert!(text.contains("1 Stockholm"));
+        assert!(text.contains("7°"));
+        assert!(text.contains("2 Oslo"));
+        assert!(text.contains("Loading..."));
+    }
+
+    #[test]
+    fn empty_dashboard_explains_how_to_pin() {
+        let state = AppState::new(&state_test_cli());
+        assert!(rendered_text(&state).contains("No pinned locations yet."));
+    }
+}
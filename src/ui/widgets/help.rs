@@ -59,7 +59,7 @@ fn append_start_here_help(lines: &mut Vec<Line<'static>>, theme: Theme) {
     lines.push(Line::from(vec![
         Span::raw("2) Press "),
         Span::styled("Tab", key),
-        Span::raw(" to focus Hourly or 7-Day for deeper context"),
+        Span::raw(" to focus Hourly or Daily for deeper context"),
     ]));
     lines.push(Line::from(vec![
         Span::raw("3) Use "),
@@ -85,12 +85,30 @@ fn append_switch_city_help(lines: &mut Vec<Line<'static>>, theme: Theme) {
     lines.push(Line::from(vec![
         Span::raw("Use "),
         Span::styled("1..9", key),
-        Span::raw(" for recent locations"),
+        Span::raw(" for pinned, then recent locations"),
     ]));
     lines.push(Line::from(vec![
         Span::raw("When ambiguous results appear, choose "),
         Span::styled("1..5", key),
     ]));
+    lines.push(Line::from(vec![
+        Span::raw("Press "),
+        Span::styled("P", key),
+        Span::raw(" to pin a city, "),
+        Span::styled("D", key),
+        Span::raw(" for the dashboard of pinned cities"),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("[", key),
+        Span::raw(" / "),
+        Span::styled("]", key),
+        Span::raw(" cycle the location tabs; neighbors are prefetched"),
+    ]));
+    lines.push(Line::from(vec![
+        Span::raw("Type "),
+        Span::styled(":compare <city>", key),
+        Span::raw(" to see two locations side by side"),
+    ]));
     lines.push(Line::from(""));
 }
 
@@ -178,13 +196,35 @@ fn append_key_reference_help(lines: &mut Vec<Line<'static>>, theme: Theme) {
         Span::styled(" hourly view", muted),
     ]));
 
+    lines.push(Line::from(vec![
+        Span::styled("D", key),
+        Span::styled(" dashboard  |  ", muted),
+        Span::styled("P", key),
+        Span::styled(" pin/unpin city  |  ", muted),
+        Span::styled("X", key),
+        Span::styled(" unpin dashboard card  |  ", muted),
+        Span::styled("[ ]", key),
+        Span::styled(" location tabs", muted),
+    ]));
+
     lines.push(Line::from(vec![
         Span::styled("Tab", key),
         Span::styled(" / ", muted),
         Span::styled("Shift+Tab", key),
         Span::styled(" panel focus  |  ", muted),
         Span::styled(":", key),
-        Span::styled(" command bar", muted),
+        Span::styled(" command bar  |  ", muted),
+        Span::styled("↑/↓", key),
+        Span::styled(" day in Daily  |  ", muted),
+        Span::styled("Enter", key),
+        Span::styled(" day detail", muted),
+    ]));
+
+    lines.push(Line::from(vec![
+        Span::styled("Click", key),
+        Span::styled(" focus panel or select  |  ", muted),
+        Span::styled("Wheel", key),
+        Span::styled(" move hourly/day cursor", muted),
     ]));
 
     lines.push(Line::from(""));
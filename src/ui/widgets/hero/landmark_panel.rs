@@ -35,8 +35,15 @@ pub fn render_landmark(
         && area.height >= 8
         && let Some(bundle) = &state.weather
     {
-        scene.context_line =
-            Some(derive_nowcast_insight(bundle, state.units, &state.refresh_meta).action_text);
+        scene.context_line = Some(
+            derive_nowcast_insight(
+                bundle,
+                state.units,
+                state.settings.measure_units(),
+                &state.refresh_meta,
+            )
+            .action_text,
+        );
     }
     let tint = tint_color(scene.tint, theme);
     let has_title = area.height >= 6;
@@ -152,12 +159,26 @@ fn select_scene(
     match state.settings.hero_visual {
         HeroVisualArg::AtmosCanvas => {
             scene_or_loading(state, area, is_day, "Atmos Canvas", |bundle| {
-                scene_for_weather(bundle, state.units, motion, scene_area.0, scene_area.1)
+                scene_for_weather(
+                    bundle,
+                    state.units,
+                    state.settings.measure_units(),
+                    motion,
+                    scene_area.0,
+                    scene_area.1,
+                )
             })
         }
         HeroVisualArg::GaugeCluster => {
             scene_or_loading(state, area, is_day, "Gauge Cluster", |bundle| {
-                scene_for_gauge_cluster(bundle, state.units, scene_area.0, scene_area.1, motion)
+                scene_for_gauge_cluster(
+                    bundle,
+                    state.units,
+                    state.settings.measure_units(),
+                    scene_area.0,
+                    scene_area.1,
+                    motion,
+                )
             })
         }
         HeroVisualArg::SkyObservatory => {
@@ -85,7 +85,7 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState, _cli: &Cli) {
     }
 }
 
-fn hero_context(state: &AppState) -> (bool, u8, crate::ui::theme::Theme) {
+pub(crate) fn hero_context(state: &AppState) -> (bool, u8, crate::ui::theme::Theme) {
     let (category, is_day, code) = state.weather.as_ref().map_or(
         // Loading/no-data should default to a dark palette to avoid bright blank panels.
         (WeatherCategory::Unknown, false, 0),
@@ -102,7 +102,7 @@ fn hero_context(state: &AppState) -> (bool, u8, crate::ui::theme::Theme) {
     (is_day, code, theme)
 }
 
-fn render_hero_background(
+pub(crate) fn render_hero_background(
     frame: &mut Frame,
     area: Rect,
     state: &AppState,
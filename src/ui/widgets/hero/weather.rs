@@ -19,7 +19,7 @@ use crate::{
     app::state::{AppMode, AppState},
     domain::weather::{
         AirQualityCategory, ForecastBundle, HourlyForecast, convert_temp, round_temp,
-        round_wind_speed, weather_code_to_category, weather_label_for_time,
+        weather_code_to_category, weather_label_for_time,
     },
     ui::{
         motion_context,
@@ -266,6 +266,7 @@ fn collect_weather_metrics(state: &AppState, weather: &ForecastBundle) -> Weathe
     let (cloud_low, cloud_mid, cloud_high) =
         cloud_layers_from_hourly(&weather.hourly).unwrap_or((None, None, None));
     let (aqi, aqi_category, aqi_available) = aqi_summary(weather);
+    let measures = state.settings.measure_units();
     WeatherMetricsData {
         feels: round_temp(convert_temp(
             weather.current.apparent_temperature_c,
@@ -274,10 +275,14 @@ fn collect_weather_metrics(state: &AppState, weather: &ForecastBundle) -> Weathe
         humidity: weather.current.relative_humidity_2m.round() as i32,
         dew: round_temp(convert_temp(weather.current.dew_point_2m_c, state.units)),
         wind_dir: compass(weather.current.wind_direction_10m),
-        wind: round_wind_speed(weather.current.wind_speed_10m),
-        gust: round_wind_speed(weather.current.wind_gusts_10m),
-        visibility: format_visibility(weather.current.visibility_m),
-        pressure: weather.current.pressure_msl_hpa.round() as i32,
+        wind: measures.wind.round(weather.current.wind_speed_10m),
+        gust: measures.wind.round(weather.current.wind_gusts_10m),
+        wind_unit: measures.wind.symbol(),
+        visibility: format_visibility(weather.current.visibility_m, measures.distance),
+        pressure: measures
+            .pressure
+            .format_value(weather.current.pressure_msl_hpa),
+        pressure_unit: measures.pressure.symbol(),
         pressure_trend: pressure_trend_marker(&weather.hourly),
         uv_today: weather
             .daily
@@ -13,8 +13,10 @@ pub(super) struct WeatherMetricsData {
     pub(super) wind_dir: &'static str,
     pub(super) wind: i32,
     pub(super) gust: i32,
+    pub(super) wind_unit: &'static str,
     pub(super) visibility: String,
-    pub(super) pressure: i32,
+    pub(super) pressure: String,
+    pub(super) pressure_unit: &'static str,
     pub(super) pressure_trend: &'static str,
     pub(super) uv_today: String,
     pub(super) cloud_total: i32,
@@ -162,7 +164,10 @@ fn descriptor_primary(
         },
         MetricSlot::Wind => MetricDescriptor {
             label: "Wind ",
-            value: format!("{}/{} m/s {}", data.wind, data.gust, data.wind_dir),
+            value: format!(
+                "{}/{} {} {}",
+                data.wind, data.gust, data.wind_unit, data.wind_dir
+            ),
             color: theme.success,
         },
         MetricSlot::Visibility => MetricDescriptor {
@@ -187,7 +192,10 @@ fn descriptor_secondary(
     match slot {
         MetricSlot::PressureStandard => MetricDescriptor {
             label: "Pressure ",
-            value: format!("{}hPa{}", data.pressure, data.pressure_trend),
+            value: format!(
+                "{}{}{}",
+                data.pressure, data.pressure_unit, data.pressure_trend
+            ),
             color: theme.warning,
         },
         MetricSlot::Humidity => MetricDescriptor {
@@ -260,8 +268,10 @@ mod tests {
             wind_dir: "NW",
             wind: 15,
             gust: 28,
+            wind_unit: "m/s",
             visibility: "10 km".to_string(),
-            pressure: 1012,
+            pressure: "1012".to_string(),
+            pressure_unit: "hPa",
             pressure_trend: "→",
             uv_today: "3".to_string(),
             cloud_total: 40,
@@ -286,4 +296,19 @@ mod tests {
         push_metric_lines(&mut lines, &sample_data(), sample_theme(), "  ", true);
         assert_eq!(lines.len(), COMPACT_ROWS.len());
     }
+
+    #[test]
+    fn wind_and_pressure_rows_carry_their_units() {
+        let data = WeatherMetricsData {
+            wind_unit: "kn",
+            pressure: "29.88".to_string(),
+            pressure_unit: "inHg",
+            ..sample_data()
+        };
+        let mut lines: Vec<Line<'static>> = Vec::new();
+        push_metric_lines(&mut lines, &data, sample_theme(), "  ", false);
+        let text: String = lines.iter().map(ToString::to_string).collect();
+        assert!(text.contains("15/28 kn NW"), "got: {text}");
+        assert!(text.contains("29.88inHg→"), "got: {text}");
+    }
 }
@@ -1,4 +1,4 @@
-use crate::domain::weather::HourlyForecast;
+use crate::domain::weather::{DistanceUnit, HourlyForecast};
 
 pub fn compass(deg: f32) -> &'static str {
     const DIRS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
@@ -9,16 +9,8 @@ pub fn compass(deg: f32) -> &'static str {
     DIRS[idx]
 }
 
-pub fn format_visibility(meters: f32) -> String {
-    if !meters.is_finite() || meters <= 0.0 {
-        return "--".to_string();
-    }
-    let km = meters / 1000.0;
-    if km >= 20.0 {
-        format!("{km:.0}km")
-    } else {
-        format!("{km:.1}km")
-    }
+pub fn format_visibility(meters: f32, unit: DistanceUnit) -> String {
+    unit.format(meters)
 }
 
 pub fn pressure_trend_marker(hourly: &[HourlyForecast]) -> &'static str {
@@ -143,13 +135,14 @@ mod tests {
 
     #[test]
     fn format_visibility_handles_edge_cases() {
-        assert_eq!(format_visibility(0.0), "--");
-        assert_eq!(format_visibility(-10.0), "--");
-        assert_eq!(format_visibility(f32::INFINITY), "--");
-        assert_eq!(format_visibility(f32::NAN), "--");
-        assert_eq!(format_visibility(500.0), "0.5km");
-        assert_eq!(format_visibility(5000.0), "5.0km");
-        assert_eq!(format_visibility(25_000.0), "25km");
+        assert_eq!(format_visibility(0.0, DistanceUnit::Km), "--");
+        assert_eq!(format_visibility(-10.0, DistanceUnit::Km), "--");
+        assert_eq!(format_visibility(f32::INFINITY, DistanceUnit::Km), "--");
+        assert_eq!(format_visibility(f32::NAN, DistanceUnit::Km), "--");
+        assert_eq!(format_visibility(500.0, DistanceUnit::Km), "0.5km");
+        assert_eq!(format_visibility(5000.0, DistanceUnit::Km), "5.0km");
+        assert_eq!(format_visibility(25_000.0, DistanceUnit::Km), "25km");
+        assert_eq!(format_visibility(5000.0, DistanceUnit::Mi), "3.1mi");
     }
 
     #[test]
@@ -49,9 +49,18 @@ fn compass_rounds_directions() {
 
 #[test]
 fn format_visibility_formats_km() {
-    assert_eq!(format_visibility(12_345.0), "12.3km");
-    assert_eq!(format_visibility(20_100.0), "20km");
-    assert_eq!(format_visibility(-1.0), "--");
+    assert_eq!(
+        format_visibility(12_345.0, crate::domain::weather::DistanceUnit::Km),
+        "12.3km"
+    );
+    assert_eq!(
+        format_visibility(20_100.0, crate::domain::weather::DistanceUnit::Km),
+        "20km"
+    );
+    assert_eq!(
+        format_visibility(-1.0, crate::domain::weather::DistanceUnit::Km),
+        "--"
+    );
 }
 
 #[test]
@@ -17,8 +17,8 @@ use ratatui::{
 use crate::{
     app::state::AppState,
     domain::weather::{
-        AirQualityCategory, ForecastBundle, HourlyForecast, convert_temp, round_temp,
-        round_wind_speed, weather_code_to_category, weather_label_for_time,
+        AirQualityCategory, ForecastBundle, HourlyForecast, MeasureUnits, convert_temp, round_temp,
+        weather_code_to_category, weather_label_for_time,
     },
     ui::{
         narrative::build_narrative,
@@ -61,8 +61,9 @@ struct ExpandedMetricsData {
     wind_dir: String,
     wind: i32,
     gust: i32,
+    wind_unit: &'static str,
     visibility: String,
-    pressure: i32,
+    pressure: String,
     pressure_trend: &'static str,
     humidity: i32,
     cloud_total: i32,
@@ -85,6 +86,7 @@ struct ExpandedTrendsData {
     precip_values: Vec<f32>,
     cloud_values: Vec<f32>,
     visibility_values: Vec<f32>,
+    measures: MeasureUnits,
 }
 
 pub fn render_weather_info_expanded(
@@ -104,7 +106,13 @@ pub fn render_weather_info_expanded(
     let top_data = build_expanded_top_data(state, weather, theme, code);
     let metrics_data = build_expanded_metrics_data(state, weather);
 
-    let trends_data = collect_trend_series(weather, state.units, trend_area, scale);
+    let trends_data = collect_trend_series(
+        weather,
+        state.units,
+        state.settings.measure_units(),
+        trend_area,
+        scale,
+    );
 
     frame.render_widget(
         Paragraph::new(apply_top_reveal(
@@ -226,6 +234,7 @@ fn build_expanded_metrics_data(state: &AppState, weather: &ForecastBundle) -> Ex
     let (cloud_low, cloud_mid, cloud_high) =
         cloud_layers_from_hourly(&weather.hourly).unwrap_or((None, None, None));
     let (aqi, aqi_category, aqi_available) = expanded_aqi_summary(weather);
+    let measures = state.settings.measure_units();
     ExpandedMetricsData {
         feels: round_temp(convert_temp(
             weather.current.apparent_temperature_c,
@@ -233,10 +242,11 @@ fn build_expanded_metrics_data(state: &AppState, weather: &ForecastBundle) -> Ex
         )),
         dew: round_temp(convert_temp(weather.current.dew_point_2m_c, state.units)),
         wind_dir: compass(weather.current.wind_direction_10m).to_string(),
-        wind: round_wind_speed(weather.current.wind_speed_10m),
-        gust: round_wind_speed(weather.current.wind_gusts_10m),
-        visibility: format_visibility(weather.current.visibility_m),
-        pressure: weather.current.pressure_msl_hpa.round() as i32,
+        wind: measures.wind.round(weather.current.wind_speed_10m),
+        gust: measures.wind.round(weather.current.wind_gusts_10m),
+        wind_unit: measures.wind.symbol(),
+        visibility: format_visibility(weather.current.visibility_m, measures.distance),
+        pressure: measures.pressure.format(weather.current.pressure_msl_hpa),
         pressure_trend: pressure_trend_marker(&weather.hourly),
         humidity: weather.current.relative_humidity_2m.round() as i32,
         cloud_total: weather.current.cloud_cover.round() as i32,
@@ -363,7 +373,10 @@ fn expanded_left_metric_lines(data: &ExpandedMetricsData, theme: Theme) -> Vec<L
         Line::from(vec![
             Span::styled("Wind ", Style::default().fg(theme.muted_text)),
             Span::styled(
-                format!("{}/{} m/s {}", data.wind, data.gust, data.wind_dir),
+                format!(
+                    "{}/{} {} {}",
+                    data.wind, data.gust, data.wind_unit, data.wind_dir
+                ),
                 Style::default().fg(theme.success),
             ),
             Span::raw("  "),
@@ -385,7 +398,7 @@ fn expanded_pressure_line(data: &ExpandedMetricsData, theme: Theme) -> Line<'sta
     Line::from(vec![
         Span::styled("Pressure ", Style::default().fg(theme.muted_text)),
         Span::styled(
-            format!("{}hPa{}", data.pressure, data.pressure_trend),
+            format!("{}{}", data.pressure, data.pressure_trend),
             Style::default().fg(theme.warning),
         ),
         Span::raw("  "),
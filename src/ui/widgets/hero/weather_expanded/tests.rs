@@ -7,35 +7,45 @@ use super::{
     expanded_fetch_context, last_updated_label, trends::next_precip_summary,
     trends::pressure_span_summary,
 };
-use crate::{app::state::AppState, domain::weather::HourlyForecast};
+use crate::{
+    app::state::AppState,
+    domain::weather::{HourlyForecast, PrecipUnit, PressureUnit},
+};
 use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
 
 #[test]
 fn next_precip_summary_covers_now_in_nh_and_none() {
     let now = vec![hour(0, Some(0.4)), hour(1, None), hour(2, None)];
-    assert_eq!(next_precip_summary(&now), "now (0.4mm)");
+    assert_eq!(next_precip_summary(&now, PrecipUnit::Mm), "now (0.4mm)");
 
     let later = vec![hour(0, Some(0.0)), hour(1, Some(0.1)), hour(2, Some(0.3))];
-    assert_eq!(next_precip_summary(&later), "in 2h (0.3mm)");
+    assert_eq!(next_precip_summary(&later, PrecipUnit::Mm), "in 2h (0.3mm)");
 
     let dry = vec![hour(0, Some(0.0)), hour(1, None), hour(2, Some(0.1))];
-    assert_eq!(next_precip_summary(&dry), "none in 12h");
+    assert_eq!(next_precip_summary(&dry, PrecipUnit::Mm), "none in 12h");
 }
 
 #[test]
 fn next_precip_summary_includes_12h_boundary() {
     let mut hourly = (0..13).map(|idx| hour(idx, Some(0.0))).collect::<Vec<_>>();
     hourly[12].precipitation_mm = Some(0.4);
-    assert_eq!(next_precip_summary(&hourly), "in 12h (0.4mm)");
+    assert_eq!(
+        next_precip_summary(&hourly, PrecipUnit::Mm),
+        "in 12h (0.4mm)"
+    );
 }
 
 #[test]
 fn pressure_span_summary_handles_empty_and_non_empty() {
-    assert_eq!(pressure_span_summary(&[]), "--");
+    assert_eq!(pressure_span_summary(&[], PressureUnit::Hpa), "--");
     assert_eq!(
-        pressure_span_summary(&[1008.2, 1012.9, 1010.0]),
+        pressure_span_summary(&[1008.2, 1012.9, 1010.0], PressureUnit::Hpa),
         "1008..1013hPa"
     );
+    assert_eq!(
+        pressure_span_summary(&[1008.2, 1012.9], PressureUnit::InHg),
+        "29.77..29.91inHg"
+    );
 }
 
 #[test]
@@ -1,8 +1,10 @@
 use super::*;
+use crate::domain::weather::{DistanceUnit, PrecipUnit, PressureUnit, WindUnit};
 
 pub(super) fn collect_trend_series(
     weather: &ForecastBundle,
     units: crate::domain::weather::Units,
+    measures: MeasureUnits,
     trend_area: Rect,
     scale: HeroScale,
 ) -> ExpandedTrendsData {
@@ -25,6 +27,7 @@ pub(super) fn collect_trend_series(
         precip_values,
         cloud_values,
         visibility_values,
+        measures,
     }
 }
 
@@ -45,7 +48,7 @@ fn temp_series_value(hour: &HourlyForecast, units: crate::domain::weather::Units
 }
 
 fn visibility_series_value(hour: &HourlyForecast) -> Option<f32> {
-    hour.visibility_m.map(|value| value / 1000.0)
+    hour.visibility_m
 }
 
 pub(super) fn build_expanded_trend_lines(
@@ -95,11 +98,15 @@ fn append_optional_trend_lines(
     trend_height: u16,
     theme: Theme,
 ) {
-    let options: [(u16, &str, &[f32], Color); 4] = [
+    let visibility_label = match data.measures.distance {
+        DistanceUnit::Km => "Vis km ",
+        DistanceUnit::Mi => "Vis mi ",
+    };
+    let options: [(u16, &'static str, &[f32], Color); 4] = [
         (3, "Gust   ", &data.gust_values, theme.warning),
         (4, "Precip ", &data.precip_values, theme.info),
         (5, "Cloud  ", &data.cloud_values, theme.landmark_neutral),
-        (6, "Vis km ", &data.visibility_values, theme.success),
+        (6, visibility_label, &data.visibility_values, theme.success),
     ];
     for (min_height, label, values, color) in options {
         if trend_height >= min_height {
@@ -119,13 +126,13 @@ fn append_trend_summary_lines(
         append_temp_span_line(lines, &data.temp_values, theme);
     }
     if trend_height >= 8 {
-        append_next_precip_line(lines, weather, theme);
+        append_next_precip_line(lines, weather, data.measures.precip, theme);
     }
     if trend_height >= 9 {
-        append_peak_gust_line(lines, weather, theme);
+        append_peak_gust_line(lines, weather, data.measures.wind, theme);
     }
     if trend_height >= 10 {
-        append_pressure_span_line(lines, &data.pressure_values, theme);
+        append_pressure_span_line(lines, &data.pressure_values, data.measures.pressure, theme);
     }
 }
 
@@ -146,31 +153,46 @@ fn append_temp_span_line(lines: &mut Vec<Line<'static>>, values: &[f32], theme:
     }
 }
 
-fn append_next_precip_line(lines: &mut Vec<Line<'static>>, weather: &ForecastBundle, theme: Theme) {
+fn append_next_precip_line(
+    lines: &mut Vec<Line<'static>>,
+    weather: &ForecastBundle,
+    unit: PrecipUnit,
+    theme: Theme,
+) {
     lines.push(Line::from(vec![
         Span::styled("Next precip ", Style::default().fg(theme.muted_text)),
         Span::styled(
-            next_precip_summary(&weather.hourly),
+            next_precip_summary(&weather.hourly, unit),
             Style::default().fg(theme.info),
         ),
     ]));
 }
 
-fn append_peak_gust_line(lines: &mut Vec<Line<'static>>, weather: &ForecastBundle, theme: Theme) {
+fn append_peak_gust_line(
+    lines: &mut Vec<Line<'static>>,
+    weather: &ForecastBundle,
+    unit: WindUnit,
+    theme: Theme,
+) {
     lines.push(Line::from(vec![
         Span::styled("Peak gust ", Style::default().fg(theme.muted_text)),
         Span::styled(
-            peak_gust_summary(&weather.hourly),
+            peak_gust_summary(&weather.hourly, unit),
             Style::default().fg(theme.warning),
         ),
     ]));
 }
 
-fn append_pressure_span_line(lines: &mut Vec<Line<'static>>, values: &[f32], theme: Theme) {
+fn append_pressure_span_line(
+    lines: &mut Vec<Line<'static>>,
+    values: &[f32],
+    unit: PressureUnit,
+    theme: Theme,
+) {
     lines.push(Line::from(vec![
         Span::styled("Pressure ", Style::default().fg(theme.muted_text)),
         Span::styled(
-            pressure_span_summary(values),
+            pressure_span_summary(values, unit),
             Style::default().fg(theme.success),
         ),
     ]));
@@ -209,7 +231,7 @@ pub(super) fn value_span(values: &[f32]) -> Option<(f32, f32)> {
     Some((min, max))
 }
 
-pub(super) fn next_precip_summary(hourly: &[HourlyForecast]) -> String {
+pub(super) fn next_precip_summary(hourly: &[HourlyForecast], unit: PrecipUnit) -> String {
     crate::domain::weather::summarize_precip_window(
         hourly,
         crate::domain::weather::PRECIP_NEAR_TERM_HOURS,
@@ -224,31 +246,32 @@ pub(super) fn next_precip_summary(hourly: &[HourlyForecast]) -> String {
         },
         |summary| {
             if summary.has_precip_now() {
-                format!("now ({:.1}mm)", summary.first_amount_mm)
+                format!("now ({})", unit.format(summary.first_amount_mm))
             } else {
                 format!(
-                    "in {}h ({:.1}mm)",
-                    summary.first_idx, summary.first_amount_mm
+                    "in {}h ({})",
+                    summary.first_idx,
+                    unit.format(summary.first_amount_mm)
                 )
             }
         },
     )
 }
 
-pub(super) fn peak_gust_summary(hourly: &[HourlyForecast]) -> String {
+pub(super) fn peak_gust_summary(hourly: &[HourlyForecast], unit: WindUnit) -> String {
     hourly
         .iter()
         .take(24)
         .filter_map(|h| h.wind_gusts_10m.map(|g| (g, h.time)))
         .max_by(|(a, _), (b, _)| a.total_cmp(b))
-        .map(|(gust, time)| format!("{}m/s @ {}", round_wind_speed(gust), time.format("%H:%M")))
+        .map(|(gust, time)| format!("{} @ {}", unit.format(gust), time.format("%H:%M")))
         .unwrap_or_else(|| "--".to_string())
 }
 
-pub(super) fn pressure_span_summary(values: &[f32]) -> String {
+pub(super) fn pressure_span_summary(values: &[f32], unit: PressureUnit) -> String {
     value_span(values).map_or_else(
         || "--".to_string(),
-        |(min, max)| format!("{min:.0}..{max:.0}hPa"),
+        |(min, max)| format!("{}..{}", unit.format_value(min), unit.format(max)),
     )
 }
 
@@ -316,12 +339,12 @@ mod tests {
 
     #[test]
     fn pressure_span_summary_returns_placeholder_for_empty() {
-        assert_eq!(pressure_span_summary(&[]), "--");
+        assert_eq!(pressure_span_summary(&[], PressureUnit::Hpa), "--");
     }
 
     #[test]
     fn pressure_span_summary_formats_range() {
-        let out = pressure_span_summary(&[1000.0, 1010.0]);
+        let out = pressure_span_summary(&[1000.0, 1010.0], PressureUnit::Hpa);
         assert!(out.contains("1000"));
         assert!(out.contains("1010"));
     }
@@ -329,20 +352,23 @@ mod tests {
     #[test]
     fn peak_gust_summary_returns_placeholder_when_no_gusts() {
         let hourly = vec![blank_hour()];
-        assert_eq!(peak_gust_summary(&hourly), "--");
+        assert_eq!(peak_gust_summary(&hourly, WindUnit::Ms), "--");
     }
 
     #[test]
     fn peak_gust_summary_formats_max_gust() {
         let mut h = blank_hour();
         h.wind_gusts_10m = Some(72.0);
-        let out = peak_gust_summary(&[h]);
-        assert!(out.contains("m/s"));
+        assert_eq!(
+            peak_gust_summary(&[h.clone()], WindUnit::Ms),
+            "20 m/s @ 08:00"
+        );
+        assert!(peak_gust_summary(&[h], WindUnit::Mph).contains("45 mph"));
     }
 
     #[test]
     fn next_precip_summary_returns_none_message_for_empty() {
-        let out = next_precip_summary(&[]);
+        let out = next_precip_summary(&[], PrecipUnit::Mm);
         assert!(out.contains("none"));
     }
 }
@@ -17,9 +17,9 @@ use crate::{
     app::state::{AppState, PanelFocus},
     cli::Cli,
     domain::weather::{
-        Daypart, DaypartSummary, ForecastBundle, HourlyForecast, HourlyViewMode, Units,
-        convert_temp, round_temp, summarize_dayparts, weather_code_to_category, weather_icon,
-        weather_label_for_time,
+        Daypart, DaypartSummary, DistanceUnit, ForecastBundle, HourlyForecast, HourlyViewMode,
+        MeasureUnits, PrecipUnit, Units, WindUnit, convert_temp, round_temp, summarize_dayparts,
+        weather_code_to_category, weather_icon, weather_label_for_time,
     },
     ui::layout::visible_hour_count,
     ui::theme::{Theme, icon_color, resolved_theme, temp_color},
@@ -31,6 +31,7 @@ mod table;
 mod timeline;
 
 use daypart::render_daypart_cards;
+pub(super) use daypart::render_daypart_section;
 use table::render_table_mode;
 use timeline::{render_chart_metrics, render_temp_precip_timeline};
 
@@ -195,13 +196,7 @@ fn shorten_next_change(next_change: &str) -> String {
 }
 
 fn chart_freshness_summary(state: &AppState) -> String {
-    freshness_summary_for_state(
-        state,
-        state
-            .refresh_meta
-            .age_minutes()
-            .map(|minutes| format!("{minutes}m")),
-    )
+    freshness_summary_for_state(state, state.refresh_meta.age_label())
 }
 
 fn freshness_summary_for_state(state: &AppState, age: Option<String>) -> String {
@@ -210,7 +205,7 @@ fn freshness_summary_for_state(state: &AppState, age: Option<String>) -> String
     match state.refresh_meta.state {
         FreshnessState::Fresh => freshness_age_label("Fresh", age),
         FreshnessState::Stale => freshness_age_label("Stale", age),
-        FreshnessState::Offline => offline_freshness_label(state),
+        FreshnessState::Offline => offline_freshness_label(state, age),
     }
 }
 
@@ -218,9 +213,9 @@ fn freshness_age_label(label: &str, age: Option<String>) -> String {
     age.map_or_else(|| label.to_string(), |value| format!("{label} {value}"))
 }
 
-fn offline_freshness_label(state: &AppState) -> String {
+fn offline_freshness_label(state: &AppState, age: Option<String>) -> String {
     state.refresh_meta.retry_in_seconds().map_or_else(
-        || "Offline".to_string(),
+        || freshness_age_label("Offline", age),
         |retry| format!("Offline retry {retry}s"),
     )
 }
@@ -337,7 +332,14 @@ fn render_hybrid_mode(
         Layout::vertical([Constraint::Length(3), Constraint::Min(2)]).split(area)
     };
 
-    let _ = render_temp_precip_timeline(frame, chunks[0], slice, theme, state.units);
+    let _ = render_temp_precip_timeline(
+        frame,
+        chunks[0],
+        slice,
+        theme,
+        state.units,
+        state.settings.measure_units(),
+    );
 
     let day_count = if area.width >= 100 { 2 } else { 1 };
     render_daypart_cards(frame, chunks[1], bundle, state, theme, day_count)
@@ -357,7 +359,14 @@ fn render_chart_mode(
     }
 
     let chunks = Layout::vertical([Constraint::Min(6), Constraint::Length(1)]).split(area);
-    let timeline_stats = render_temp_precip_timeline(frame, chunks[0], slice, theme, state.units);
+    let timeline_stats = render_temp_precip_timeline(
+        frame,
+        chunks[0],
+        slice,
+        theme,
+        state.units,
+        state.settings.measure_units(),
+    );
     render_chart_metrics(frame, chunks[1], timeline_stats, theme);
     true
 }
@@ -54,7 +54,7 @@ fn daypart_dates(summaries: &[DaypartSummary]) -> Vec<chrono::NaiveDate> {
     dates
 }
 
-fn render_daypart_section(
+pub(in crate::ui::widgets) fn render_daypart_section(
     frame: &mut Frame,
     section: Rect,
     date: chrono::NaiveDate,
@@ -139,20 +139,25 @@ fn build_daypart_rows(
     show_wind: bool,
     show_vis: bool,
 ) -> Vec<Row<'static>> {
+    let measures = state.settings.measure_units();
     let mut rows = vec![
         build_daypart_header_row(theme),
         build_daypart_primary_row(parts, state, theme),
-        build_daypart_precip_row(parts, theme),
+        build_daypart_precip_row(parts, measures.precip, theme),
     ];
     if show_secondary {
         rows.push(build_daypart_advisory_row(parts, theme));
         rows.push(build_daypart_secondary_row(parts, theme));
     }
     if show_wind {
-        rows.push(build_daypart_wind_row(parts, theme));
+        rows.push(build_daypart_wind_row(parts, measures.wind, theme));
     }
     if show_vis {
-        rows.push(build_daypart_visibility_row(parts, theme));
+        rows.push(build_daypart_visibility_row(
+            parts,
+            measures.distance,
+            theme,
+        ));
     }
     rows
 }
@@ -229,7 +234,11 @@ fn build_daypart_primary_row(
     )
 }
 
-fn build_daypart_precip_row(parts: &[DaypartSummary], theme: Theme) -> Row<'static> {
+fn build_daypart_precip_row(
+    parts: &[DaypartSummary],
+    unit: PrecipUnit,
+    theme: Theme,
+) -> Row<'static> {
     Row::new(
         parts
             .iter()
@@ -238,8 +247,8 @@ fn build_daypart_precip_row(parts: &[DaypartSummary], theme: Theme) -> Row<'stat
                     .precip_probability_max
                     .map_or_else(|| "--".to_string(), |v| format!("{v:.0}%"));
                 Cell::from(format!(
-                    "{:.1}mm {prob}",
-                    sanitize_precip_mm(summary.precip_sum_mm)
+                    "{} {prob}",
+                    unit.format(sanitize_precip_mm(summary.precip_sum_mm))
                 ))
                 .style(Style::default().fg(theme.info))
             })
@@ -262,34 +271,36 @@ fn build_daypart_secondary_row(parts: &[DaypartSummary], theme: Theme) -> Row<'s
     )
 }
 
-fn build_daypart_wind_row(parts: &[DaypartSummary], theme: Theme) -> Row<'static> {
+fn build_daypart_wind_row(parts: &[DaypartSummary], unit: WindUnit, theme: Theme) -> Row<'static> {
     Row::new(
         parts
             .iter()
             .map(|summary| {
-                let min_wind = summary.wind_min_kmh.map_or_else(
-                    || "--".to_string(),
-                    |v| crate::domain::weather::round_wind_speed(v).to_string(),
-                );
-                let max_wind = summary.wind_max_kmh.map_or_else(
-                    || "--".to_string(),
-                    |v| crate::domain::weather::round_wind_speed(v).to_string(),
-                );
-                Cell::from(format!("{min_wind}-{max_wind}m/s"))
+                let min_wind = summary
+                    .wind_min_kmh
+                    .map_or_else(|| "--".to_string(), |v| unit.round(v).to_string());
+                let max_wind = summary
+                    .wind_max_kmh
+                    .map_or_else(|| "--".to_string(), |v| unit.round(v).to_string());
+                Cell::from(format!("{min_wind}-{max_wind}{}", unit.symbol()))
                     .style(Style::default().fg(theme.warning))
             })
             .collect::<Vec<_>>(),
     )
 }
 
-fn build_daypart_visibility_row(parts: &[DaypartSummary], theme: Theme) -> Row<'static> {
+fn build_daypart_visibility_row(
+    parts: &[DaypartSummary],
+    unit: DistanceUnit,
+    theme: Theme,
+) -> Row<'static> {
     Row::new(
         parts
             .iter()
             .map(|summary| {
                 let vis = summary.visibility_median_m.map_or_else(
                     || "--".to_string(),
-                    |v| format!("{:.0}km", (v / 1000.0).max(0.0)),
+                    |v| format!("{:.0}{}", unit.convert(v).max(0.0), unit.symbol()),
                 );
                 Cell::from(vis).style(Style::default().fg(theme.success))
             })
@@ -23,8 +23,12 @@ pub(super) fn render_table_mode(
     );
     let table = build_hourly_table(area.width, slice, rows, panel_style, label_width);
 
-    let insight =
-        crate::domain::weather::derive_nowcast_insight(bundle, state.units, &state.refresh_meta);
+    let insight = crate::domain::weather::derive_nowcast_insight(
+        bundle,
+        state.units,
+        state.settings.measure_units(),
+        &state.refresh_meta,
+    );
     let (table_area, detail_area, window_area) = split_table_and_detail_areas(area);
     frame.render_widget(table, table_area);
     let detail_text = cursor_detail_text(
@@ -68,9 +72,12 @@ fn build_table_rows(
     if let Some(date_row) = build_optional_date_row(slice, offset, theme) {
         rows.insert(1, date_row);
     }
-    for (min_height, label, color, formatter) in metric_row_specs(theme) {
+    let measures = state.settings.measure_units();
+    for (min_height, label, color, formatter) in metric_row_specs(theme, measures) {
         if area_height >= min_height {
-            rows.push(build_metric_row(label, slice, color, formatter, theme));
+            rows.push(build_metric_row(
+                label, slice, color, formatter, measures, theme,
+            ));
         }
     }
     rows
@@ -143,9 +150,10 @@ fn cursor_detail_text(
 ) -> Option<String> {
     let (cursor_idx, hour) = selected_cursor_hour(slice, cursor_in_view)?;
     let temp = cursor_temp_text(hour, state.units);
-    let gust = cursor_gust_text(hour);
+    let measures = state.settings.measure_units();
+    let gust = cursor_gust_text(hour, measures.wind);
     let precip = cursor_precip_probability_text(hour);
-    let mm = cursor_precip_mm_text(hour);
+    let amount = cursor_precip_amount_text(hour, measures.precip);
     let decision = strip_action_prefix(action_text);
     let why = format!(
         "focus {} {} is {}",
@@ -156,7 +164,7 @@ fn cursor_detail_text(
             hour.is_day.unwrap_or(bundle.current.is_day),
         ),
     );
-    let details = format!("temp {temp} · P% {precip} · P {mm}mm · gust {gust}");
+    let details = format!("temp {temp} · P% {precip} · P {amount} · gust {gust}");
     Some(format_decision_line(decision, &why, &details))
 }
 
@@ -178,10 +186,10 @@ fn cursor_temp_text(hour: &HourlyForecast, units: Units) -> String {
     )
 }
 
-fn cursor_gust_text(hour: &HourlyForecast) -> String {
+fn cursor_gust_text(hour: &HourlyForecast, unit: WindUnit) -> String {
     wind_reference(hour).map_or_else(
         || "--".to_string(),
-        |value| format!("{}m/s", crate::domain::weather::round_wind_speed(value)),
+        |value| format!("{}{}", unit.round(value), unit.symbol()),
     )
 }
 
@@ -190,10 +198,10 @@ fn cursor_precip_probability_text(hour: &HourlyForecast) -> String {
         .map_or_else(|| "--".to_string(), |value| format!("{value:.0}%"))
 }
 
-fn cursor_precip_mm_text(hour: &HourlyForecast) -> String {
+fn cursor_precip_amount_text(hour: &HourlyForecast, unit: PrecipUnit) -> String {
     hour.precipitation_mm.map_or_else(
-        || "--.-".to_string(),
-        |value| format!("{:.1}", value.max(0.0)),
+        || format!("--.-{}", unit.symbol()),
+        |value| unit.format(value.max(0.0)),
     )
 }
 
@@ -234,7 +242,7 @@ fn wind_reference(hour: &HourlyForecast) -> Option<f32> {
     hour.wind_gusts_10m.or(hour.wind_speed_10m)
 }
 
-type HourlyMetricFormatter = fn(&HourlyForecast) -> String;
+type HourlyMetricFormatter = fn(&HourlyForecast, MeasureUnits) -> String;
 
 fn build_time_row(
     slice: &[&HourlyForecast],
@@ -340,22 +348,28 @@ fn build_metric_row(
     slice: &[&HourlyForecast],
     color: Color,
     formatter: HourlyMetricFormatter,
+    measures: MeasureUnits,
     theme: Theme,
 ) -> Row<'static> {
     let mut cells = vec![Cell::from(label).style(Style::default().fg(theme.muted_text))];
     cells.extend(
         slice
             .iter()
-            .map(|h| Cell::from(formatter(h)).style(Style::default().fg(color))),
+            .map(|h| Cell::from(formatter(h, measures)).style(Style::default().fg(color))),
     );
     Row::new(cells)
 }
 
 pub(super) fn metric_row_specs(
     theme: Theme,
+    measures: MeasureUnits,
 ) -> [(u16, &'static str, Color, HourlyMetricFormatter); 8] {
+    let precip_label = match measures.precip {
+        PrecipUnit::Mm => "P mm",
+        PrecipUnit::In => "P in",
+    };
     [
-        (5, "P mm", theme.info, format_precip_mm_metric),
+        (5, precip_label, theme.info, format_precip_amount_metric),
         (6, "Gust", theme.warning, format_gust_metric),
         (7, "Vis", theme.success, format_visibility_metric),
         (8, "Cloud", theme.landmark_neutral, format_cloud_metric),
@@ -366,48 +380,50 @@ pub(super) fn metric_row_specs(
     ]
 }
 
-fn format_precip_mm_metric(hour: &HourlyForecast) -> String {
+fn format_precip_amount_metric(hour: &HourlyForecast, measures: MeasureUnits) -> String {
     hour.precipitation_mm.map_or_else(
         || "--.-".to_string(),
-        |p| format!("{:>4.1}", sanitize_precip_mm(p)),
+        |p| format!("{:>4}", measures.precip.format_value(sanitize_precip_mm(p))),
     )
 }
 
-fn format_gust_metric(hour: &HourlyForecast) -> String {
+fn format_gust_metric(hour: &HourlyForecast, measures: MeasureUnits) -> String {
     hour.wind_gusts_10m.map_or_else(
         || "-- ".to_string(),
-        |g| format!("{:>3}", crate::domain::weather::round_wind_speed(g)),
+        |g| format!("{:>3}", measures.wind.round(g)),
     )
 }
 
-fn format_visibility_metric(hour: &HourlyForecast) -> String {
+fn format_visibility_metric(hour: &HourlyForecast, measures: MeasureUnits) -> String {
     hour.visibility_m.map_or_else(
         || "-- ".to_string(),
-        |v| format!("{:>3}", (v / 1000.0).round() as i32),
+        |v| format!("{:>3}", measures.distance.convert(v).round() as i32),
     )
 }
 
-fn format_cloud_metric(hour: &HourlyForecast) -> String {
+fn format_cloud_metric(hour: &HourlyForecast, _measures: MeasureUnits) -> String {
     format_percent_metric(hour.cloud_cover)
 }
 
-fn format_pressure_metric(hour: &HourlyForecast) -> String {
-    hour.pressure_msl_hpa
-        .map_or_else(|| " -- ".to_string(), |p| format!("{p:>4.0}"))
+fn format_pressure_metric(hour: &HourlyForecast, measures: MeasureUnits) -> String {
+    hour.pressure_msl_hpa.map_or_else(
+        || " -- ".to_string(),
+        |p| format!("{:>4}", measures.pressure.format_value(p)),
+    )
 }
 
-fn format_humidity_metric(hour: &HourlyForecast) -> String {
+fn format_humidity_metric(hour: &HourlyForecast, _measures: MeasureUnits) -> String {
     format_percent_metric(hour.relative_humidity_2m)
 }
 
-fn format_precip_probability_metric(hour: &HourlyForecast) -> String {
+fn format_precip_probability_metric(hour: &HourlyForecast, _measures: MeasureUnits) -> String {
     format_percent_metric(hour.precipitation_probability)
 }
 
-fn format_wind_metric(hour: &HourlyForecast) -> String {
+fn format_wind_metric(hour: &HourlyForecast, measures: MeasureUnits) -> String {
     hour.wind_speed_10m.map_or_else(
         || "-- ".to_string(),
-        |w| format!("{:>3}", crate::domain::weather::round_wind_speed(w)),
+        |w| format!("{:>3}", measures.wind.round(w)),
     )
 }
 
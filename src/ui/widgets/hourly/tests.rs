@@ -3,7 +3,7 @@ use super::table::{build_optional_date_row, metric_row_specs, sanitize_precip_mm
 use super::*;
 use crate::{
     cli::ThemeArg,
-    domain::weather::{HourlyForecast, HourlyViewMode, WeatherCategory},
+    domain::weather::{HourlyForecast, HourlyViewMode, MeasureUnits, WeatherCategory},
     ui::theme::{ColorCapability, theme_for},
 };
 use chrono::{NaiveDate, NaiveDateTime};
@@ -116,7 +116,7 @@ fn optional_metric_rows_keep_height_thresholds() {
         ThemeArg::Aurora,
     );
     let count_for = |height: u16| {
-        metric_row_specs(theme)
+        metric_row_specs(theme, MeasureUnits::default())
             .iter()
             .filter(|(min_height, _, _, _)| *min_height <= height)
             .count()
@@ -7,6 +7,7 @@ pub(super) struct TimelineStats {
     pub(super) wind_avg: Option<f32>,
     pub(super) precip_prob_max: Option<f32>,
     pub(super) cloud_avg: Option<f32>,
+    pub(super) wind_unit: WindUnit,
 }
 
 pub(super) fn render_temp_precip_timeline(
@@ -15,15 +16,16 @@ pub(super) fn render_temp_precip_timeline(
     slice: &[&HourlyForecast],
     theme: Theme,
     units: Units,
+    measures: MeasureUnits,
 ) -> TimelineStats {
     if area.height == 0 || area.width < 12 {
         return TimelineStats::default();
     }
 
-    let series = timeline_series(slice, units);
+    let series = timeline_series(slice, units, measures.precip);
     let lines = timeline_lines(&series, area.width as usize, area.height, theme);
     frame.render_widget(Paragraph::new(lines), area);
-    timeline_stats(slice)
+    timeline_stats(slice, measures.wind)
 }
 
 pub(super) fn render_chart_metrics(
@@ -35,7 +37,7 @@ pub(super) fn render_chart_metrics(
     if area.width == 0 || area.height == 0 {
         return;
     }
-    let wind = format_chart_wind(stats.wind_avg);
+    let wind = format_chart_wind(stats.wind_avg, stats.wind_unit);
     let precip = format_chart_percent(stats.precip_prob_max);
     let cloud = format_chart_percent(stats.cloud_avg);
 
@@ -52,11 +54,8 @@ pub(super) fn render_chart_metrics(
     frame.render_widget(Paragraph::new(line), area);
 }
 
-fn format_chart_wind(value: Option<f32>) -> String {
-    value.map_or_else(
-        || "--".to_string(),
-        |v| format!("{} m/s", crate::domain::weather::round_wind_speed(v)),
-    )
+fn format_chart_wind(value: Option<f32>, unit: WindUnit) -> String {
+    value.map_or_else(|| "--".to_string(), |v| unit.format(v))
 }
 
 fn format_chart_percent(value: Option<f32>) -> String {
@@ -67,12 +66,17 @@ struct TimelineSeries {
     temps: Vec<Option<f32>>,
     temp_unit: &'static str,
     precips: Vec<f32>,
+    precip_unit: PrecipUnit,
     times: Vec<chrono::NaiveDateTime>,
 }
 
 type TimelineLine = Line<'static>;
 
-fn timeline_series(slice: &[&HourlyForecast], units: Units) -> TimelineSeries {
+fn timeline_series(
+    slice: &[&HourlyForecast],
+    units: Units,
+    precip_unit: PrecipUnit,
+) -> TimelineSeries {
     TimelineSeries {
         temps: slice
             .iter()
@@ -83,6 +87,7 @@ fn timeline_series(slice: &[&HourlyForecast], units: Units) -> TimelineSeries {
             .iter()
             .map(|h| h.precipitation_mm.unwrap_or(0.0).max(0.0))
             .collect::<Vec<_>>(),
+        precip_unit,
         times: slice.iter().map(|h| h.time).collect::<Vec<_>>(),
     }
 }
@@ -158,7 +163,7 @@ fn temp_timeline_line(series: &TimelineSeries, cols: usize, theme: Theme) -> Tim
 fn rain_timeline_line(series: &TimelineSeries, cols: usize, theme: Theme) -> TimelineLine {
     const PRECIP_CAP_MM: f32 = 2.0;
     const SPACING: usize = 2;
-    let range = precip_range_label(&series.precips);
+    let range = precip_range_label(&series.precips, series.precip_unit);
     let chart_width = cols.saturating_sub(range.len() + SPACING);
     let chart = barline_capped(&series.precips, chart_width.max(1), PRECIP_CAP_MM);
     let value = if chart_width == 0 {
@@ -179,7 +184,7 @@ fn time_axis_timeline_line(series: &TimelineSeries, cols: usize, theme: Theme) -
     )
 }
 
-fn timeline_stats(slice: &[&HourlyForecast]) -> TimelineStats {
+fn timeline_stats(slice: &[&HourlyForecast], wind_unit: WindUnit) -> TimelineStats {
     TimelineStats {
         wind_avg: average(slice.iter().filter_map(|h| h.wind_speed_10m)),
         precip_prob_max: slice
@@ -187,6 +192,7 @@ fn timeline_stats(slice: &[&HourlyForecast]) -> TimelineStats {
             .filter_map(|h| h.precipitation_probability)
             .max_by(f32::total_cmp),
         cloud_avg: average(slice.iter().filter_map(|h| h.cloud_cover)),
+        wind_unit,
     }
 }
 
@@ -273,13 +279,13 @@ fn temp_range_label(values: &[Option<f32>], unit: &str) -> String {
     format!("{}..{}{unit}", round_temp(min), round_temp(max))
 }
 
-fn precip_range_label(values: &[f32]) -> String {
+fn precip_range_label(values: &[f32], unit: PrecipUnit) -> String {
     if values.is_empty() {
-        return "--..--mm/h".to_string();
+        return format!("--..--{}/h", unit.symbol());
     }
     let min = values.iter().copied().fold(f32::INFINITY, f32::min);
     let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
-    format!("{min:.1}..{max:.1}mm/h")
+    format!("{}..{}/h", unit.format_value(min), unit.format(max))
 }
 
 fn truncate_to_width(text: &str, width: usize) -> String {
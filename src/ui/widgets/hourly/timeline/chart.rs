@@ -128,9 +128,9 @@ fn chart_scale_labels(series: &TimelineSeries) -> ChartScaleLabels {
         .copied()
         .fold(f32::NEG_INFINITY, f32::max);
     let precip_peak = if precip_peak.is_finite() {
-        format!("{:.1}mm/h", precip_peak.max(0.0))
+        format!("{}/h", series.precip_unit.format(precip_peak.max(0.0)))
     } else {
-        "--.-mm/h".to_string()
+        format!("--.-{}/h", series.precip_unit.symbol())
     };
 
     ChartScaleLabels {
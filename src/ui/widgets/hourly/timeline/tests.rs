@@ -19,6 +19,7 @@ fn expanded_timeline_uses_vertical_space_and_right_scales() {
         temps: vec![Some(-2.0), Some(4.0), Some(1.0)],
         temp_unit: "C",
         precips: vec![0.0, 0.8, 1.6],
+        precip_unit: PrecipUnit::Mm,
         times: vec![dt(2026, 2, 22, 0), dt(2026, 2, 22, 6), dt(2026, 2, 22, 12)],
     };
 
@@ -82,6 +83,7 @@ fn render_temp_precip_timeline_short_circuits_on_tiny_areas() {
         wind_avg: Some(99.0),
         precip_prob_max: Some(99.0),
         cloud_avg: Some(99.0),
+        wind_unit: WindUnit::Ms,
     };
 
     terminal
@@ -92,6 +94,7 @@ fn render_temp_precip_timeline_short_circuits_on_tiny_areas() {
                 &slice,
                 theme,
                 Units::Celsius,
+                MeasureUnits::default(),
             );
         })
         .expect("draw");
@@ -105,6 +108,7 @@ fn render_temp_precip_timeline_short_circuits_on_tiny_areas() {
                 &slice,
                 theme,
                 Units::Celsius,
+                MeasureUnits::default(),
             );
         })
         .expect("draw");
@@ -119,6 +123,7 @@ fn render_chart_metrics_short_circuits_on_zero_dimensions() {
         wind_avg: Some(5.0),
         precip_prob_max: Some(60.0),
         cloud_avg: Some(40.0),
+        wind_unit: WindUnit::Ms,
     };
     let mut terminal = Terminal::new(TestBackend::new(20, 4)).expect("terminal");
 
@@ -137,6 +142,7 @@ fn timeline_lines_omit_time_axis_for_two_rows() {
         temps: vec![Some(1.0), Some(3.0)],
         temp_unit: "C",
         precips: vec![0.0, 0.5],
+        precip_unit: PrecipUnit::Mm,
         times: vec![dt(2026, 2, 22, 3), dt(2026, 2, 22, 9)],
     };
     let lines = timeline_lines(&series, 40, 2, theme);
@@ -153,6 +159,7 @@ fn timeline_line_falls_back_to_range_when_chart_has_no_room() {
         temps: vec![Some(1.0), Some(4.0)],
         temp_unit: "C",
         precips: vec![0.1, 1.7],
+        precip_unit: PrecipUnit::Mm,
         times: vec![dt(2026, 2, 22, 0), dt(2026, 2, 22, 6)],
     };
     let temp_line = line_text(&temp_timeline_line(&series, 4, theme));
@@ -187,7 +194,11 @@ fn sparkline_and_barline_handle_empty_inputs() {
 #[test]
 fn range_labels_and_time_axis_handle_empty_inputs() {
     assert_eq!(temp_range_label(&[], "C"), "--..--C");
-    assert_eq!(precip_range_label(&[]), "--..--mm/h");
+    assert_eq!(precip_range_label(&[], PrecipUnit::Mm), "--..--mm/h");
+    assert_eq!(
+        precip_range_label(&[0.0, 2.54], PrecipUnit::In),
+        "0.00..0.10in/h"
+    );
     assert_eq!(time_axis_line(&[], 10), "");
     assert_eq!(time_axis_line(&[dt(2026, 2, 22, 6)], 0), "");
 }
@@ -8,7 +8,9 @@
 
 use chrono::Timelike;
 
-use crate::domain::weather::{ForecastBundle, Units, WeatherCategory, weather_code_to_category};
+use crate::domain::weather::{
+    ForecastBundle, MeasureUnits, Units, WeatherCategory, weather_code_to_category,
+};
 use crate::ui::animation::{MotionMode, SeededMotion, UiMotionContext};
 use crate::ui::widgets::landmark::compact::compact_condition_scene;
 use crate::ui::widgets::landmark::shared::{canvas_to_lines, paint_char};
@@ -35,6 +37,7 @@ use terrain::{compute_terrain, paint_horizon_haze, paint_terrain};
 pub fn scene_for_weather(
     bundle: &ForecastBundle,
     units: Units,
+    measures: MeasureUnits,
     motion: UiMotionContext,
     width: u16,
     height: u16,
@@ -60,7 +63,7 @@ pub fn scene_for_weather(
         ),
         lines: canvas_to_lines(canvas, w),
         tint: tint_for_category(category),
-        context_line: Some(atmos_context_line(bundle, units, category)),
+        context_line: Some(atmos_context_line(bundle, units, measures.precip, category)),
     }
 }
 
@@ -2,18 +2,20 @@ use crate::cli::IconMode;
 use chrono::Timelike;
 
 use crate::domain::weather::{
-    ForecastBundle, PRECIP_NEAR_TERM_HOURS, PRECIP_SIGNIFICANT_THRESHOLD_MM, PrecipWindowSummary,
-    Units, WeatherCategory, convert_temp, round_temp, summarize_precip_window, weather_icon,
+    ForecastBundle, PRECIP_NEAR_TERM_HOURS, PRECIP_SIGNIFICANT_THRESHOLD_MM, PrecipUnit,
+    PrecipWindowSummary, Units, WeatherCategory, convert_temp, round_temp, summarize_precip_window,
+    weather_icon,
 };
 use crate::ui::widgets::landmark::shared::paint_char;
 
 pub(super) fn atmos_context_line(
     bundle: &ForecastBundle,
     units: Units,
+    precip_unit: PrecipUnit,
     category: WeatherCategory,
 ) -> String {
     if let Some(summary) = precip_summary(bundle) {
-        return precip_context(bundle, summary);
+        return precip_context(bundle, summary, precip_unit);
     }
     stable_weather_context(bundle, units, category)
 }
@@ -42,7 +44,11 @@ fn precip_summary(bundle: &ForecastBundle) -> Option<PrecipWindowSummary> {
     )
 }
 
-fn precip_context(bundle: &ForecastBundle, summary: PrecipWindowSummary) -> String {
+fn precip_context(
+    bundle: &ForecastBundle,
+    summary: PrecipWindowSummary,
+    precip_unit: PrecipUnit,
+) -> String {
     let now_hour = bundle
         .hourly
         .first()
@@ -50,13 +56,13 @@ fn precip_context(bundle: &ForecastBundle, summary: PrecipWindowSummary) -> Stri
     let end_hour = (now_hour + summary.last_idx + 1) % 24;
     if summary.has_precip_now() {
         format!(
-            "Precip clearing by {end_hour:02}:00 · {:.0}mm expected",
-            summary.total_mm
+            "Precip clearing by {end_hour:02}:00 · {} expected",
+            precip_unit.format(summary.total_mm)
         )
     } else {
         format!(
-            "Precipitation expected through {end_hour:02}:00 · {:.0}mm",
-            summary.total_mm
+            "Precipitation expected through {end_hour:02}:00 · {}",
+            precip_unit.format(summary.total_mm)
         )
     }
 }
@@ -129,15 +135,20 @@ fn temp_display(celsius: f32, units: Units) -> (i32, &'static str) {
 mod tests {
     use super::atmos_context_line;
     use crate::domain::weather::{
-        CurrentConditions, DailyForecast, ForecastBundle, HourlyForecast, Location, Units,
-        WeatherCategory,
+        CurrentConditions, DailyForecast, ForecastBundle, HourlyForecast, Location, PrecipUnit,
+        Units, WeatherCategory,
     };
     use chrono::{NaiveDate, NaiveDateTime, Utc};
 
     #[test]
     fn atmos_context_ignores_precip_beyond_12h_window() {
         let bundle = sample_bundle_with_precip_at(13, 0.4);
-        let context = atmos_context_line(&bundle, Units::Celsius, WeatherCategory::Cloudy);
+        let context = atmos_context_line(
+            &bundle,
+            Units::Celsius,
+            PrecipUnit::Mm,
+            WeatherCategory::Cloudy,
+        );
         assert!(!context.contains("Precipitation expected"));
         assert!(context.contains("overcast skies"));
     }
@@ -145,7 +156,12 @@ mod tests {
     #[test]
     fn atmos_context_reports_precip_within_12h_window() {
         let bundle = sample_bundle_with_precip_at(12, 0.4);
-        let context = atmos_context_line(&bundle, Units::Celsius, WeatherCategory::Cloudy);
+        let context = atmos_context_line(
+            &bundle,
+            Units::Celsius,
+            PrecipUnit::Mm,
+            WeatherCategory::Cloudy,
+        );
         assert!(context.contains("Precipitation expected"));
     }
 
@@ -157,6 +173,7 @@ mod tests {
             daily: vec![sample_daily()],
             air_quality: None,
             fetched_at: Utc::now(),
+            utc_offset_seconds: 0,
         }
     }
 
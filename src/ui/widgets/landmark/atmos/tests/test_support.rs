@@ -61,6 +61,7 @@ pub(super) fn bundle_for_category(
         daily: vec![crate::test_support::sample_daily()],
         air_quality: None,
         fetched_at: chrono::Utc::now(),
+        utc_offset_seconds: 0,
     }
 }
 
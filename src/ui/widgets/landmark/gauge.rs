@@ -4,7 +4,7 @@
     clippy::cast_sign_loss
 )]
 
-use crate::domain::weather::{ForecastBundle, Units, weather_code_to_category};
+use crate::domain::weather::{ForecastBundle, MeasureUnits, Units, weather_code_to_category};
 use crate::ui::animation::UiMotionContext;
 use crate::ui::widgets::landmark::shared::{fit_lines, fit_lines_centered};
 use crate::ui::widgets::landmark::{LandmarkScene, tint_for_category};
@@ -28,6 +28,7 @@ use meters::{
 pub fn scene_for_gauge_cluster(
     bundle: &ForecastBundle,
     units: Units,
+    measures: MeasureUnits,
     width: u16,
     height: u16,
     motion: UiMotionContext,
@@ -35,7 +36,7 @@ pub fn scene_for_gauge_cluster(
     let w = width as usize;
     let h = height as usize;
     let category = weather_code_to_category(bundle.current.weather_code);
-    let data = collect_gauge_data(bundle, units, w);
+    let data = collect_gauge_data(bundle, units, measures, w);
     let left_lines = build_left_lines(&data);
     let mut lines = if w >= 74 && h >= 9 {
         let right_lines = build_right_lines(&data, category, bundle.current.is_day);
@@ -92,6 +93,7 @@ fn apply_gauge_accent(lines: &mut [String], motion: UiMotionContext, width: usiz
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::weather::{DistanceUnit, PrecipUnit, WeatherCategory, WindUnit};
     use chrono::{NaiveDate, NaiveDateTime};
 
     fn sample_data() -> GaugeData {
@@ -99,6 +101,7 @@ mod tests {
             temp_c: 20,
             temp_display: 20,
             temp_unit: "C",
+            measures: MeasureUnits::default(),
             humidity: 50.0,
             pressure: 1012.0,
             wind: 5.0,
@@ -167,6 +170,18 @@ mod tests {
         assert!(gauge_context_line(&data).contains("damp"));
     }
 
+    #[test]
+    fn visibility_follows_distance_unit() {
+        let mut data = sample_data();
+        data.measures.distance = DistanceUnit::Mi;
+
+        let left = build_left_lines(&data);
+        let right = build_right_lines(&data, WeatherCategory::Clear, true);
+        assert!(left.iter().any(|line| line.ends_with(" 6.2mi")));
+        assert!(right.contains(&"Visibility  6.2mi".to_string()));
+        assert!(!right.iter().any(|line| line.contains("km")));
+    }
+
     #[test]
     fn meter_and_range_helpers_cover_edge_cases() {
         let bar = meter_with_threshold(0.5, 6, Some(0.5));
@@ -176,10 +191,12 @@ mod tests {
 
         assert_eq!(range_label(&[], "°"), "");
         assert_eq!(temp_range_label(&[1.0, 3.0, 2.0]), "1°–3°");
-        assert_eq!(precip_range_label(&[0.0, 0.0]), "");
-        assert_eq!(precip_range_label(&[0.2, 1.8]), "2mm");
-        assert_eq!(gust_range_label(&[0.0, 0.0]), "");
-        assert!(gust_range_label(&[10.0, 12.9]).contains("m/s"));
+        assert_eq!(precip_range_label(&[0.0, 0.0], PrecipUnit::Mm), "");
+        assert_eq!(precip_range_label(&[0.2, 1.8], PrecipUnit::Mm), "1.8mm");
+        assert_eq!(precip_range_label(&[2.54], PrecipUnit::In), "0.10in");
+        assert_eq!(gust_range_label(&[0.0, 0.0], WindUnit::Ms), "");
+        assert_eq!(gust_range_label(&[10.0, 12.9], WindUnit::Ms), "4 m/s");
+        assert_eq!(gust_range_label(&[10.0, 12.9], WindUnit::Kmh), "13 km/h");
     }
 
     #[test]
@@ -195,7 +212,7 @@ mod tests {
     #[test]
     fn collect_gauge_data_and_tracks_use_bundle_content() {
         let bundle = bundle_with_hourly(24);
-        let data = collect_gauge_data(&bundle, Units::Celsius, 100);
+        let data = collect_gauge_data(&bundle, Units::Celsius, MeasureUnits::default(), 100);
         assert_eq!(data.temp_unit, "C");
         assert_eq!(data.temp_track_display.len(), 24);
         assert_eq!(data.precip_track.len(), 24);
@@ -233,6 +250,7 @@ mod tests {
         let scene = scene_for_gauge_cluster(
             &bundle,
             Units::Celsius,
+            MeasureUnits::default(),
             90,
             12,
             crate::test_support::test_motion_context(),
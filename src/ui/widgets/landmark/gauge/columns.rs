@@ -28,9 +28,9 @@ pub(super) fn build_left_lines(data: &GaugeData) -> Vec<String> {
             data.humidity
         ),
         format!(
-            "Press  {} {:>4.0}hPa",
+            "Press  {} {:>7}",
             meter_with_threshold(pressure_norm, data.meter_w, None),
-            data.pressure
+            data.measures.pressure.format(data.pressure)
         ),
         format!(
             "UV Idx {} {:>4.1}{uv_warn}",
@@ -38,15 +38,16 @@ pub(super) fn build_left_lines(data: &GaugeData) -> Vec<String> {
             data.uv
         ),
         format!(
-            "Visib  {} {:>4.1}km",
+            "Visib  {} {:>6}",
             meter_with_threshold(vis_norm, data.meter_w, None),
-            data.vis_km
+            data.measures.distance.format(data.vis_km * 1000.0)
         ),
         format!(
-            "Wind   {:>2} {:>4} m/s  gust {:>3}",
+            "Wind   {:>2} {:>4} {}  gust {:>3}",
             compass_arrow(data.wind_direction_10m),
-            crate::domain::weather::round_wind_speed(data.wind),
-            crate::domain::weather::round_wind_speed(data.gust)
+            data.measures.wind.round(data.wind),
+            data.measures.wind.symbol(),
+            data.measures.wind.round(data.gust)
         ),
     ]
 }
@@ -60,8 +61,9 @@ pub(super) fn build_right_lines(
         "24-Hour Overview".to_string(),
         format!("Condition {}", scene_name(category, is_day)),
         format!(
-            "Cloud {:>3.0}%   Precip now {:>3.1}mm",
-            data.cloud, data.precip_now
+            "Cloud {:>3.0}%   Precip now {}",
+            data.cloud,
+            data.measures.precip.format(data.precip_now)
         ),
         format!("Sun arc {} -> {}", data.sunrise, data.sunset),
         format!(
@@ -72,14 +74,17 @@ pub(super) fn build_right_lines(
         format!(
             "24h Precip {} {}",
             sparkline_annotated(&data.precip_track, data.right_trend_width, ""),
-            precip_range_label(&data.precip_track)
+            precip_range_label(&data.precip_track, data.measures.precip)
         ),
         format!(
             "24h Gust  {} {}",
             sparkline_annotated(&data.gust_track, data.right_trend_width, ""),
-            gust_range_label(&data.gust_track)
+            gust_range_label(&data.gust_track, data.measures.wind)
+        ),
+        format!(
+            "Visibility {:>6}",
+            data.measures.distance.format(data.vis_km * 1000.0)
         ),
-        format!("Visibility {:>4.1}km", data.vis_km),
         wind_compass_box(data.wind_direction_10m),
     ]
 }
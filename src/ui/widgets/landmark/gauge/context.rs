@@ -16,7 +16,10 @@ pub(super) fn gauge_context_line(data: &GaugeData) -> String {
             return line;
         }
     }
-    format!("All readings nominal · {:.0} hPa", data.pressure)
+    format!(
+        "All readings nominal · {}",
+        data.measures.pressure.format(data.pressure)
+    )
 }
 
 fn critical_uv_line(data: &GaugeData) -> Option<String> {
@@ -40,12 +43,20 @@ fn gusty_line(data: &GaugeData) -> Option<String> {
 }
 
 fn low_visibility_line(data: &GaugeData) -> Option<String> {
-    (data.vis_km < 1.0).then(|| format!("Visibility {:.1}km · reduced visibility", data.vis_km))
+    (data.vis_km < 1.0).then(|| {
+        format!(
+            "Visibility {} · reduced visibility",
+            data.measures.distance.format(data.vis_km * 1000.0)
+        )
+    })
 }
 
 fn active_precip_line(data: &GaugeData) -> Option<String> {
     simple_context_line(data.precip_now > 0.5, || {
-        format!("Active precipitation {:.1}mm", data.precip_now)
+        format!(
+            "Active precipitation {}",
+            data.measures.precip.format(data.precip_now)
+        )
     })
 }
 
@@ -67,9 +78,6 @@ fn simple_context_line(condition: bool, message: impl FnOnce() -> String) -> Opt
 
 fn wind_line(data: &GaugeData, min_gust: f32, prefix: &str, suffix: &str) -> Option<String> {
     simple_context_line(data.gust > min_gust, || {
-        format!(
-            "{prefix} {} m/s {suffix}",
-            crate::domain::weather::round_wind_speed(data.gust)
-        )
+        format!("{prefix} {} {suffix}", data.measures.wind.format(data.gust))
     })
 }
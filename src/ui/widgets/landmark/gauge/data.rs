@@ -1,10 +1,11 @@
-use crate::domain::weather::{ForecastBundle, Units, convert_temp, round_temp};
+use crate::domain::weather::{ForecastBundle, MeasureUnits, Units, convert_temp, round_temp};
 
 #[derive(Debug)]
 pub(super) struct GaugeData {
     pub(super) temp_c: i32,
     pub(super) temp_display: i32,
     pub(super) temp_unit: &'static str,
+    pub(super) measures: MeasureUnits,
     pub(super) humidity: f32,
     pub(super) pressure: f32,
     pub(super) wind: f32,
@@ -24,7 +25,12 @@ pub(super) struct GaugeData {
     pub(super) gust_track: Vec<f32>,
 }
 
-pub(super) fn collect_gauge_data(bundle: &ForecastBundle, units: Units, width: usize) -> GaugeData {
+pub(super) fn collect_gauge_data(
+    bundle: &ForecastBundle,
+    units: Units,
+    measures: MeasureUnits,
+    width: usize,
+) -> GaugeData {
     let current = &bundle.current;
     let left_col_width = left_column_width(width);
     let trend_width = width.saturating_sub(left_col_width + 12).clamp(8, 28);
@@ -39,6 +45,7 @@ pub(super) fn collect_gauge_data(bundle: &ForecastBundle, units: Units, width: u
         } else {
             "F"
         },
+        measures,
         humidity: current.relative_humidity_2m.clamp(0.0, 100.0),
         pressure: current.pressure_msl_hpa,
         wind: current.wind_speed_10m.max(0.0),
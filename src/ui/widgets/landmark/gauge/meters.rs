@@ -1,3 +1,4 @@
+use crate::domain::weather::{PrecipUnit, WindUnit};
 use crate::ui::widgets::shared::sparkline_blocks as shared_sparkline_blocks;
 
 pub(super) fn meter_with_threshold(norm: f32, width: usize, threshold: Option<f32>) -> String {
@@ -36,14 +37,12 @@ pub(super) fn temp_range_label(values: &[f32]) -> String {
     range_label(values, "°")
 }
 
-pub(super) fn precip_range_label(values: &[f32]) -> String {
-    positive_max(values).map_or_else(String::new, |max| format!("{max:.0}mm"))
+pub(super) fn precip_range_label(values: &[f32], unit: PrecipUnit) -> String {
+    positive_max(values).map_or_else(String::new, |max| unit.format(max))
 }
 
-pub(super) fn gust_range_label(values: &[f32]) -> String {
-    positive_max(values).map_or_else(String::new, |max| {
-        format!("{}m/s", crate::domain::weather::round_wind_speed(max))
-    })
+pub(super) fn gust_range_label(values: &[f32], unit: WindUnit) -> String {
+    positive_max(values).map_or_else(String::new, |max| unit.format(max))
 }
 
 pub(super) fn range_label(values: &[f32], suffix: &str) -> String {
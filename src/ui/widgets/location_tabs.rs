@@ -0,0 +1,89 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Tabs,
+};
+
+use crate::{
+    app::{settings::RecentLocation, state::AppState},
+    ui::theme::resolved_theme,
+};
+
+/// The tab bar needs at least two locations to be worth its row.
+#[must_use]
+pub fn is_visible(state: &AppState) -> bool {
+    state.location_tabs.len() >= 2
+}
+
+pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
+    let theme = resolved_theme(state);
+    let titles = state
+        .location_tabs
+        .iter()
+        .enumerate()
+        .map(|(index, tab)| Line::from(tab_title(state, index, tab)));
+    let tabs = Tabs::new(titles)
+        .select(state.active_location_tab())
+        .style(Style::default().fg(theme.muted_text))
+        .highlight_style(
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        )
+        .divider(Span::styled("│", Style::default().fg(theme.border)))
+        .padding(" ", " ");
+    frame.render_widget(tabs, area);
+}
+
+/// `★ alias` or `★ name` for pinned tabs, the bare name for recent ones.
+fn tab_title(state: &AppState, index: usize, tab: &RecentLocation) -> String {
+    let pinned = state
+        .settings
+        .pinned_locations
+        .iter()
+        .find(|pinned| pinned.place.same_place(tab));
+    let label = match pinned {
+        Some(pinned) => format!("★ {}", pinned.alias.as_deref().unwrap_or(&tab.name)),
+        None => tab.name.clone(),
+    };
+    format!("{} {label}", index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{state_test_cli, stockholm_location};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn shows_pinned_alias_and_recent_names() {
+        let mut state = AppState::new(&state_test_cli());
+        let stockholm = stockholm_location();
+        let mut oslo = stockholm.clone();
+        oslo.name = "Oslo".to_string();
+        oslo.latitude = 59.91;
+        state.push_recent_location(&oslo);
+        state
+            .settings
+            .pin_location(&stockholm, Some("home".to_string()));
+        state.sync_location_tabs();
+        state.selected_location = Some(oslo);
+        assert!(is_visible(&state));
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 1)).expect("test terminal");
+        terminal
+            .draw(|frame| render(frame, frame.area(), &state))
+            .expect("draw");
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains("1 ★ home"), "got: {text}");
+        assert!(text.contains("2 Oslo"), "got: {text}");
+    }
+}
@@ -1,10 +1,13 @@
 pub mod alerts;
 pub mod city_picker;
+pub mod compare;
 pub mod daily;
+pub mod dashboard;
 pub mod help;
 pub mod hero;
 pub mod hourly;
 pub mod landmark;
+pub mod location_tabs;
 pub mod selector;
 pub mod settings;
 pub(super) mod shared;
@@ -1,6 +1,6 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout, Rect},
+    layout::{Constraint, Layout, Margin, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Clear, List, ListItem, ListState, Paragraph},
@@ -11,7 +11,7 @@ use crate::{
     ui::theme::{Theme, resolved_theme},
 };
 
-use super::shared::{popup_block, popup_panel_style};
+use super::shared::{list_item_at, popup_block, popup_panel_style};
 
 const SAVE_SETTINGS_ERROR_FRAGMENT: &str = "save settings";
 
@@ -25,18 +25,10 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let chunks = Layout::vertical([
-        Constraint::Min(6),
-        Constraint::Length(1),
-        Constraint::Length(2),
-    ])
-    .split(inner);
+    let chunks = settings_chunks(inner);
 
     let rows = settings_rows(state, theme);
-    let selected_index = rows
-        .iter()
-        .position(|row| row.selection == Some(state.settings_selected))
-        .unwrap_or(0);
+    let selected_index = selected_row_index(&rows, state);
     let items = rows
         .into_iter()
         .map(|row| row.item)
@@ -50,6 +42,31 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
     render_hint(frame, chunks[2], state, theme);
 }
 
+fn settings_chunks(inner: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::vertical([
+        Constraint::Min(6),
+        Constraint::Length(1),
+        Constraint::Length(2),
+    ])
+    .split(inner)
+}
+
+fn selected_row_index(rows: &[SettingsRow], state: &AppState) -> usize {
+    rows.iter()
+        .position(|row| row.selection == Some(state.settings_selected))
+        .unwrap_or(0)
+}
+
+/// The setting under terminal `row` for a panel drawn in `area`; section
+/// headers have none.
+#[must_use]
+pub fn selection_at(area: Rect, state: &AppState, row: u16) -> Option<SettingsSelection> {
+    let list_area = settings_chunks(area.inner(Margin::new(1, 1)))[0];
+    let rows = settings_rows(state, resolved_theme(state));
+    let index = list_item_at(list_area, row, selected_row_index(&rows, state), rows.len())?;
+    rows[index].selection
+}
+
 struct SettingsRow {
     selection: Option<SettingsSelection>,
     item: ListItem<'static>,
@@ -88,10 +105,24 @@ fn settings_rows(state: &AppState, theme: Theme) -> Vec<SettingsRow> {
     push_settings_section(
         &mut rows,
         &entries,
-        "System",
+        "Measurement",
         &[
             SettingsSelection::Units,
+            SettingsSelection::WindUnit,
+            SettingsSelection::PressureUnit,
+            SettingsSelection::PrecipUnit,
+            SettingsSelection::DistanceUnit,
+        ],
+        label_width,
+        theme,
+    );
+    push_settings_section(
+        &mut rows,
+        &entries,
+        "System",
+        &[
             SettingsSelection::RefreshInterval,
+            SettingsSelection::ForecastDays,
             SettingsSelection::RefreshNow,
             SettingsSelection::Close,
         ],
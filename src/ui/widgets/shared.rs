@@ -1,4 +1,5 @@
 use ratatui::{
+    layout::Rect,
     style::Style,
     widgets::{Block, Borders},
 };
@@ -23,6 +24,19 @@ pub(super) fn popup_block(title: &'static str, theme: Theme, panel_style: Style)
         )
 }
 
+/// The item of a one-line-per-item `List` drawn in `area` at terminal `row`.
+/// Mirrors how ratatui scrolls a fresh `ListState`: just far enough that the
+/// `selected` item is on the last visible line.
+pub(super) fn list_item_at(area: Rect, row: u16, selected: usize, len: usize) -> Option<usize> {
+    if row < area.y || row >= area.bottom() {
+        return None;
+    }
+    let visible = usize::from(area.height);
+    let offset = (selected + 1).saturating_sub(visible);
+    let index = offset + usize::from(row - area.y);
+    (index < len).then_some(index)
+}
+
 #[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
 pub(super) fn sparkline_blocks(values: &[f32], width: usize) -> String {
     const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
@@ -4,7 +4,7 @@
 use chrono::{NaiveDate, NaiveDateTime, Utc};
 use terminal_weather::{
     app::state::{AppMode, AppState},
-    cli::{Cli, ColorArg, HeroVisualArg, ThemeArg, UnitsArg},
+    cli::{Cli, ColorArg, FormatArg, HeroVisualArg, SeriesArg, ThemeArg, UnitsArg},
     domain::weather::{
         AirQualityCategory, AirQualityReading, CurrentConditions, DailyForecast, ForecastBundle,
         HourlyForecast, Location,
@@ -27,6 +27,12 @@ pub fn stockholm_cli() -> Cli {
         reduced_motion: false,
         motion: None,
         no_flash: true,
+        no_mouse: false,
+        forecast_days: None,
+        wind_unit: None,
+        pressure_unit: None,
+        precip_unit: None,
+        distance_unit: None,
         ascii_icons: false,
         emoji_icons: false,
         nerd_font: false,
@@ -38,11 +44,25 @@ pub fn stockholm_cli() -> Cli {
         country_code: None,
         lat: None,
         lon: None,
+        location: None,
+        provider: None,
         forecast_url: None,
         air_quality_url: None,
         refresh_interval: 600,
         demo: false,
+        offline: false,
         one_shot: false,
+        format: FormatArg::Text,
+        series: SeriesArg::Hourly,
+        watch: false,
+        metrics_file: None,
+        metrics_listen: None,
+        serve: None,
+        dashboard: false,
+        compare: None,
+        inline: false,
+        inline_seconds: 5,
+        command: None,
     }
 }
 
@@ -70,6 +90,7 @@ pub fn fixture_bundle(profile: FixtureProfile, weather_code: u8) -> ForecastBund
         daily: fixture_daily(profile, base_date, weather_code),
         air_quality: None,
         fetched_at: Utc::now(),
+        utc_offset_seconds: 0,
     }
 }
 
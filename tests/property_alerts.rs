@@ -0,0 +1,235 @@
+mod common;
+
+use chrono::Duration;
+use common::{FixtureProfile, fixture_bundle};
+use proptest::prelude::*;
+use terminal_weather::domain::{
+    alerts::{
+        AlertSeverity, AlertWindow, EXTREME_HEAT_C, HEAVY_PRECIP_HOURS, HEAVY_PRECIP_MM,
+        LOW_VISIBILITY_M, MERGE_GAP_HOURS, WeatherAlert, scan_alerts,
+    },
+    weather::{
+        DistanceUnit, ForecastBundle, HourlyForecast, MeasureUnits, PrecipUnit, PressureUnit,
+        Units, WindUnit, convert_temp, round_temp,
+    },
+};
+
+/// Three days of hourly forecast, Thu 10:00 to Sun 09:00.
+const HORIZON_HOURS: usize = 72;
+
+fn quiet_bundle() -> ForecastBundle {
+    let mut bundle = fixture_bundle(FixtureProfile::Flow, 3);
+    let first = bundle.hourly[0].clone();
+    bundle.hourly = (0..HORIZON_HOURS)
+        .map(|idx| HourlyForecast {
+            time: first.time + Duration::hours(idx as i64),
+            ..first.clone()
+        })
+        .collect();
+    for hour in &mut bundle.hourly {
+        hour.temperature_2m_c = Some(10.0);
+        hour.precipitation_mm = Some(0.0);
+        hour.wind_gusts_10m = Some(10.0);
+        hour.visibility_m = Some(10_000.0);
+    }
+    bundle
+}
+
+fn units() -> impl Strategy<Value = Units> {
+    prop::sample::select(vec![Units::Celsius, Units::Fahrenheit])
+}
+
+fn measures() -> impl Strategy<Value = MeasureUnits> {
+    (
+        prop::sample::select(WindUnit::ALL.to_vec()),
+        prop::sample::select(PressureUnit::ALL.to_vec()),
+        prop::sample::select(PrecipUnit::ALL.to_vec()),
+        prop::sample::select(DistanceUnit::ALL.to_vec()),
+    )
+        .prop_map(|(wind, pressure, precip, distance)| MeasureUnits {
+            wind,
+            pressure,
+            precip,
+            distance,
+        })
+}
+
+fn find<'a>(alerts: &'a [WeatherAlert], prefix: &str) -> Option<&'a WeatherAlert> {
+    alerts
+        .iter()
+        .find(|alert| alert.message.starts_with(prefix))
+}
+
+proptest! {
+    #[test]
+    fn severities_do_not_depend_on_display_units(
+        gust_kmh in 0.0f32..150.0,
+        precip_mm in 0.0f32..3.0,
+        visibility_m in 50.0f32..5_000.0,
+        temp_c in -40.0f32..50.0,
+        hour in 0..HORIZON_HOURS,
+        units in units(),
+        measures in measures(),
+    ) {
+        let mut bundle = quiet_bundle();
+        bundle.hourly[hour].wind_gusts_10m = Some(gust_kmh);
+        bundle.hourly[hour].visibility_m = Some(visibility_m);
+        bundle.hourly[hour].temperature_2m_c = Some(temp_c);
+        for hour in &mut bundle.hourly {
+            hour.precipitation_mm = Some(precip_mm);
+        }
+
+        let summary = |alerts: Vec<WeatherAlert>| {
+            alerts
+                .into_iter()
+                .map(|alert| (alert.icon, alert.severity, alert.eta_hours))
+                .collect::<Vec<_>>()
+        };
+        prop_assert_eq!(
+            summary(scan_alerts(&bundle, units, measures, &[])),
+            summary(scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]))
+        );
+    }
+
+    #[test]
+    fn gust_alerts_fire_at_50_and_80_kmh_in_any_wind_unit(
+        gust_kmh in prop_oneof![
+            Just(49.9f32),
+            Just(50.0f32),
+            Just(79.9f32),
+            Just(80.0f32),
+            0.0f32..150.0,
+        ],
+        hour in 0..HORIZON_HOURS,
+        measures in measures(),
+    ) {
+        let mut bundle = quiet_bundle();
+        bundle.hourly[hour].wind_gusts_10m = Some(gust_kmh);
+        let alerts = scan_alerts(&bundle, Units::Celsius, measures, &[]);
+
+        let expected = if gust_kmh >= 80.0 {
+            Some(AlertSeverity::Danger)
+        } else if gust_kmh >= 50.0 {
+            Some(AlertSeverity::Warning)
+        } else {
+            None
+        };
+        let alert = find(&alerts, "Forecast gusts");
+        prop_assert_eq!(alert.map(|alert| alert.severity), expected);
+        if let Some(alert) = alert {
+            prop_assert_eq!(
+                &alert.message,
+                &format!("Forecast gusts up to {}", measures.wind.format(gust_kmh))
+            );
+            prop_assert!(alert.message.ends_with(measures.wind.symbol()));
+            prop_assert_eq!(alert.eta_hours, Some(hour));
+            let start = bundle.hourly[hour].time;
+            prop_assert_eq!(
+                alert.window,
+                Some(AlertWindow { start, end: start + Duration::hours(1) })
+            );
+        }
+    }
+
+    #[test]
+    fn later_spells_get_their_own_window(
+        first in 0..HORIZON_HOURS / 2,
+        gap in MERGE_GAP_HOURS + 1..HORIZON_HOURS / 3,
+        len in 1usize..6,
+    ) {
+        let mut bundle = quiet_bundle();
+        let second = first + len + gap;
+        for idx in (first..first + len).chain(second..second + len) {
+            bundle.hourly[idx].wind_gusts_10m = Some(100.0);
+        }
+        let alerts = scan_alerts(&bundle, Units::Celsius, MeasureUnits::default(), &[]);
+
+        let windows: Vec<_> = alerts.iter().filter_map(|alert| alert.window).collect();
+        let expected: Vec<_> = [first, second]
+            .into_iter()
+            .map(|idx| AlertWindow {
+                start: bundle.hourly[idx].time,
+                end: bundle.hourly[idx + len - 1].time + Duration::hours(1),
+            })
+            .collect();
+        prop_assert_eq!(windows, expected);
+        prop_assert_eq!(alerts[1].eta_hours, Some(second));
+        prop_assert!(alerts.iter().all(|alert| alert.severity == AlertSeverity::Danger));
+    }
+
+    #[test]
+    fn precipitation_alerts_use_the_precip_unit(
+        hourly_mm in 0.0f32..3.0,
+        measures in measures(),
+    ) {
+        let mut bundle = quiet_bundle();
+        for hour in &mut bundle.hourly {
+            hour.precipitation_mm = Some(hourly_mm);
+        }
+        let total: f32 = bundle.hourly.iter().filter_map(|hour| hour.precipitation_mm).sum();
+        let alerts = scan_alerts(&bundle, Units::Celsius, measures, &[]);
+
+        // Every hour is alike, so every 24 hours hold the same total and the
+        // alert covers the whole forecast or nothing.
+        let day_total: f32 = bundle.hourly[..HEAVY_PRECIP_HOURS]
+            .iter()
+            .filter_map(|hour| hour.precipitation_mm)
+            .sum();
+        let alert = find(&alerts, "Heavy precipitation");
+        prop_assert_eq!(alert.is_some(), day_total >= HEAVY_PRECIP_MM);
+        if let Some(alert) = alert {
+            prop_assert_eq!(
+                &alert.message,
+                &format!(
+                    "Heavy precipitation: {} in {HORIZON_HOURS}h",
+                    measures.precip.format(total)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn visibility_alerts_use_the_distance_unit(
+        visibility_m in 50.0f32..5_000.0,
+        hour in 0..HORIZON_HOURS,
+        measures in measures(),
+    ) {
+        let mut bundle = quiet_bundle();
+        bundle.hourly[hour].visibility_m = Some(visibility_m);
+        let alerts = scan_alerts(&bundle, Units::Celsius, measures, &[]);
+
+        let alert = find(&alerts, "Low visibility");
+        prop_assert_eq!(alert.is_some(), visibility_m < LOW_VISIBILITY_M);
+        if let Some(alert) = alert {
+            prop_assert_eq!(
+                &alert.message,
+                &format!("Low visibility: {}", measures.distance.format(visibility_m))
+            );
+            prop_assert_eq!(alert.eta_hours, Some(hour));
+        }
+    }
+
+    #[test]
+    fn heat_alerts_use_the_temperature_unit(
+        temp_c in 20.0f32..50.0,
+        units in units(),
+        measures in measures(),
+    ) {
+        let mut bundle = quiet_bundle();
+        bundle.hourly[5].temperature_2m_c = Some(temp_c);
+        let alerts = scan_alerts(&bundle, units, measures, &[]);
+
+        let alert = find(&alerts, "Extreme heat");
+        prop_assert_eq!(alert.is_some(), temp_c >= EXTREME_HEAT_C);
+        if let Some(alert) = alert {
+            prop_assert_eq!(
+                &alert.message,
+                &format!(
+                    "Extreme heat: up to {}°{}",
+                    round_temp(convert_temp(temp_c, units)),
+                    units.symbol()
+                )
+            );
+        }
+    }
+}